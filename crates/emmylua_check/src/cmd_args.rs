@@ -1,8 +1,14 @@
-use structopt::StructOpt;
+use emmylua_code_analysis::{DiagnosticCode, DiagnosticSeveritySetting};
+use structopt::{clap::ArgGroup, StructOpt};
 
 #[allow(unused)]
 #[derive(Debug, StructOpt, Clone)]
-#[structopt(name = "emmylua-check", about = "EmmyLua Check")]
+#[structopt(
+    name = "emmylua-check",
+    about = "EmmyLua Check",
+    // `--fix` and `--search` rewrite files, so at most one of them is given
+    group = ArgGroup::with_name("rewrite")
+)]
 pub struct CmdArgs {
     #[structopt(short, long, parse(from_os_str), help = "Specify configuration file")]
     pub config: Option<std::path::PathBuf>,
@@ -20,7 +26,7 @@ pub struct CmdArgs {
 
     #[structopt(
         long,
-        help = "Specify output format (json or text)",
+        help = "Specify output format (json, text, sarif, github or gitlab)",
         default_value = "text",
         possible_values = &OutputFormat::variants(),
         case_insensitive = true
@@ -29,7 +35,7 @@ pub struct CmdArgs {
 
     #[structopt(
         long,
-        help = "Specify output destination (stdout or a file path, only used when output_format is json)",
+        help = "Specify output destination (stdout or a file path, only used when output_format is json, sarif or gitlab)",
         default_value = "stdout",
         parse(try_from_str)
     )]
@@ -37,12 +43,165 @@ pub struct CmdArgs {
 
     #[structopt(long, help = "Treat warnings as errors")]
     pub warnings_as_errors: bool,
+
+    #[structopt(
+        long,
+        parse(from_os_str),
+        help = "Baseline file, diagnostics recorded in it are not reported"
+    )]
+    pub baseline: Option<std::path::PathBuf>,
+
+    #[structopt(
+        long,
+        requires = "baseline",
+        help = "Record all current diagnostics into the baseline file"
+    )]
+    pub update_baseline: bool,
+
+    #[structopt(
+        long,
+        help = "Comma separated list of diagnostic codes to enable",
+        use_delimiter = true,
+        number_of_values = 1,
+        parse(try_from_str = parse_diagnostic_code)
+    )]
+    pub enable: Vec<DiagnosticCode>,
+
+    #[structopt(
+        long,
+        help = "Comma separated list of diagnostic codes to disable, `all` disables every code not enabled by --enable",
+        use_delimiter = true,
+        number_of_values = 1,
+        parse(try_from_str = parse_disable_code)
+    )]
+    pub disable: Vec<DiagnosticCode>,
+
+    #[structopt(
+        long,
+        help = "Override the severity of a diagnostic code, eg: unused=warning",
+        use_delimiter = true,
+        number_of_values = 1,
+        parse(try_from_str = parse_code_severity)
+    )]
+    pub severity: Vec<(DiagnosticCode, DiagnosticSeveritySetting)>,
+
+    #[structopt(
+        long,
+        help = "Only run diagnostics whose severity is at least this level (error, warning, information or hint)",
+        parse(try_from_str = parse_severity)
+    )]
+    pub min_severity: Option<DiagnosticSeveritySetting>,
+
+    #[structopt(
+        long,
+        help = "Only report diagnostics of files matching the glob, the whole workspace is still indexed",
+        number_of_values = 1
+    )]
+    pub files: Vec<String>,
+
+    #[structopt(
+        long,
+        help = "Also check the files of `workspace.library`, they are only indexed by default"
+    )]
+    pub check_library: bool,
+
+    #[structopt(
+        long,
+        help = "Keep running and check the changed files again when the workspace changes"
+    )]
+    pub watch: bool,
+
+    #[structopt(
+        long,
+        conflicts_with_all = &["fix", "watch"],
+        help = "Report the documentation coverage of the workspace instead of the diagnostics"
+    )]
+    pub doc_coverage: bool,
+
+    #[structopt(
+        long,
+        requires = "doc-coverage",
+        help = "Fail when the overall documentation coverage is below this percentage"
+    )]
+    pub min_coverage: Option<f64>,
+
+    #[structopt(
+        long,
+        group = "rewrite",
+        help = "Apply the safe fixes of the diagnostics"
+    )]
+    pub fix: bool,
+
+    #[structopt(
+        long,
+        requires = "fix",
+        help = "Comma separated list of diagnostic codes to fix",
+        use_delimiter = true
+    )]
+    pub fix_code: Option<Vec<String>>,
+
+    #[structopt(
+        long,
+        requires = "rewrite",
+        help = "Print the fixes or the structural replacements as a unified diff instead of writing the files"
+    )]
+    pub dry_run: bool,
+
+    #[structopt(
+        long,
+        group = "rewrite",
+        conflicts_with_all = &["watch", "doc-coverage"],
+        help = "Print the code matching the structural pattern instead of the diagnostics, eg: `table.getn($t: table)`"
+    )]
+    pub search: Option<String>,
+
+    #[structopt(
+        long,
+        requires = "search",
+        help = "Replace the code matching --search with the template, eg: `#$t`"
+    )]
+    pub replace: Option<String>,
+}
+
+fn parse_diagnostic_code(s: &str) -> Result<DiagnosticCode, String> {
+    match s.trim().parse::<DiagnosticCode>() {
+        Ok(code) if code != DiagnosticCode::None => Ok(code),
+        _ => Err(format!("Unknown diagnostic code: {}", s)),
+    }
+}
+
+// `None` stands for `all`
+fn parse_disable_code(s: &str) -> Result<DiagnosticCode, String> {
+    if s.trim() == "all" {
+        return Ok(DiagnosticCode::None);
+    }
+    parse_diagnostic_code(s)
+}
+
+fn parse_severity(s: &str) -> Result<DiagnosticSeveritySetting, String> {
+    match s.trim().to_lowercase().as_str() {
+        "error" => Ok(DiagnosticSeveritySetting::Error),
+        "warning" => Ok(DiagnosticSeveritySetting::Warning),
+        "information" | "info" => Ok(DiagnosticSeveritySetting::Information),
+        "hint" => Ok(DiagnosticSeveritySetting::Hint),
+        _ => Err(format!("Invalid severity: {}", s)),
+    }
+}
+
+fn parse_code_severity(s: &str) -> Result<(DiagnosticCode, DiagnosticSeveritySetting), String> {
+    let (code, severity) = s
+        .split_once('=')
+        .ok_or(format!("Expected code=level, found: {}", s))?;
+    Ok((parse_diagnostic_code(code)?, parse_severity(severity)?))
 }
 
 #[derive(Debug, Clone)]
 pub enum OutputFormat {
     Json,
     Text,
+    Sarif,
+    Github,
+    Gitlab,
 }
 
 impl std::str::FromStr for OutputFormat {
@@ -51,14 +210,17 @@ impl std::str::FromStr for OutputFormat {
         match s.to_lowercase().as_str() {
             "json" => Ok(OutputFormat::Json),
             "text" => Ok(OutputFormat::Text),
+            "sarif" => Ok(OutputFormat::Sarif),
+            "github" => Ok(OutputFormat::Github),
+            "gitlab" => Ok(OutputFormat::Gitlab),
             _ => Err(format!("Invalid output format: {}", s)),
         }
     }
 }
 
 impl OutputFormat {
-    pub fn variants() -> [&'static str; 2] {
-        ["json", "text"]
+    pub fn variants() -> [&'static str; 5] {
+        ["json", "text", "sarif", "github", "gitlab"]
     }
 }
 
@@ -0,0 +1,164 @@
+use std::{io::Write, path::PathBuf};
+
+use emmylua_code_analysis::{
+    get_doc_coverage, DocCoverage, DocCoverageCount, EmmyLuaAnalysis, FileId, UndocumentedKind,
+};
+use serde_json::{json, Value};
+
+use crate::{
+    cmd_args::{CmdArgs, OutputFormat},
+    output::{create_output_file, get_relative_path},
+};
+
+/// Report the documentation coverage of the files, returns the exit code
+pub fn report_doc_coverage(
+    analysis: &EmmyLuaAnalysis,
+    file_ids: &[FileId],
+    workspace: &PathBuf,
+    cmd_args: &CmdArgs,
+) -> i32 {
+    let db = analysis.compilation.get_db();
+    let mut modules = Vec::new();
+    for file_id in file_ids {
+        let Some(semantic_model) = analysis.compilation.get_semantic_model(*file_id) else {
+            continue;
+        };
+        let coverage = get_doc_coverage(&semantic_model);
+        if coverage.total().total == 0 {
+            continue;
+        }
+        modules.push((
+            get_relative_path(db, *file_id, workspace),
+            *file_id,
+            coverage,
+        ));
+    }
+    modules.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut overall = DocCoverage::default();
+    for (_, _, coverage) in &modules {
+        overall.merge(coverage);
+    }
+
+    match cmd_args.output_format {
+        OutputFormat::Json => {
+            let modules = modules
+                .iter()
+                .map(|(path, file_id, coverage)| {
+                    let document = db.get_vfs().get_document(file_id);
+                    let undocumented = coverage
+                        .undocumented
+                        .iter()
+                        .map(|item| {
+                            let line = document
+                                .as_ref()
+                                .and_then(|document| document.get_line_col(item.range.start()))
+                                .map(|(line, _)| line + 1);
+                            json!({
+                                "kind": kind_name(item.kind),
+                                "name": item.name,
+                                "line": line,
+                            })
+                        })
+                        .collect::<Vec<_>>();
+                    let mut value = coverage_json(coverage);
+                    value["file"] = json!(path);
+                    value["module"] = json!(db
+                        .get_module_index()
+                        .get_module(*file_id)
+                        .map(|module| module.full_module_name.clone()));
+                    value["undocumented"] = json!(undocumented);
+                    value
+                })
+                .collect::<Vec<_>>();
+            let report = json!({
+                "overall": coverage_json(&overall),
+                "modules": modules,
+            });
+            let report = serde_json::to_string_pretty(&report).unwrap();
+            match create_output_file(cmd_args.output.clone()) {
+                Some(mut output) => output.write_all(report.as_bytes()).unwrap(),
+                None => println!("{}", report),
+            }
+        }
+        _ => {
+            for (path, file_id, coverage) in &modules {
+                println!("{:<48} {:>6.1}%", path, coverage.total().percent());
+                let document = db.get_vfs().get_document(file_id);
+                for item in &coverage.undocumented {
+                    let line = document
+                        .as_ref()
+                        .and_then(|document| document.get_line_col(item.range.start()))
+                        .map(|(line, _)| line + 1)
+                        .unwrap_or_default();
+                    println!(
+                        "    {}:{} {} {}",
+                        path,
+                        line,
+                        kind_name(item.kind),
+                        item.name
+                    );
+                }
+            }
+            println!();
+            print_count("functions", &overall.functions);
+            print_count("classes", &overall.classes);
+            print_count("fields", &overall.fields);
+            print_count("globals", &overall.globals);
+            print_count("total", &overall.total());
+        }
+    }
+
+    let percent = overall.total().percent();
+    match cmd_args.min_coverage {
+        Some(min_coverage) if percent < min_coverage => {
+            eprintln!(
+                "Doc coverage {:.1}% is below the minimum {:.1}%",
+                percent, min_coverage
+            );
+            1
+        }
+        _ => 0,
+    }
+}
+
+fn coverage_json(coverage: &DocCoverage) -> Value {
+    json!({
+        "percent": round_percent(coverage.total().percent()),
+        "functions": count_json(&coverage.functions),
+        "classes": count_json(&coverage.classes),
+        "fields": count_json(&coverage.fields),
+        "globals": count_json(&coverage.globals),
+    })
+}
+
+fn count_json(count: &DocCoverageCount) -> Value {
+    json!({
+        "documented": count.documented,
+        "total": count.total,
+        "percent": round_percent(count.percent()),
+    })
+}
+
+fn round_percent(percent: f64) -> f64 {
+    (percent * 10.0).round() / 10.0
+}
+
+fn print_count(name: &str, count: &DocCoverageCount) {
+    println!(
+        "{:<10} {:>5}/{:<5} {:>6.1}%",
+        name,
+        count.documented,
+        count.total,
+        count.percent()
+    );
+}
+
+fn kind_name(kind: UndocumentedKind) -> &'static str {
+    match kind {
+        UndocumentedKind::Function => "function",
+        UndocumentedKind::Class => "class",
+        UndocumentedKind::Field => "field",
+        UndocumentedKind::Global => "global",
+    }
+}
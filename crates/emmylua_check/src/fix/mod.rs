@@ -0,0 +1,138 @@
+pub mod unified_diff;
+
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use emmylua_code_analysis::{is_code_fixable, DiagnosticCode, EmmyLuaAnalysis, FileId};
+use unified_diff::unified_diff;
+
+use crate::{cmd_args::CmdArgs, output::to_relative_path};
+
+/// Apply the safe fixes to the files, then write them back or print a diff when `--dry-run`,
+/// in which case the analysis is restored to the original text
+pub fn fix_workspace(
+    analysis: &mut EmmyLuaAnalysis,
+    file_ids: &[FileId],
+    workspace: &PathBuf,
+    cmd_args: &CmdArgs,
+) -> Result<(), String> {
+    let codes = parse_fix_codes(cmd_args.fix_code.as_ref())?;
+
+    let original_texts = get_original_texts(analysis, file_ids);
+    let fixed_files = analysis.fix_files(file_ids, codes.as_deref());
+    if cmd_args.dry_run {
+        // nothing is written, so the diagnostics reported afterwards must describe the original text
+        let restores = fixed_files
+            .keys()
+            .filter_map(|file_id| {
+                let uri = analysis.get_uri(*file_id)?;
+                Some((uri, Some(original_texts.get(file_id)?.clone())))
+            })
+            .collect::<Vec<_>>();
+        analysis.update_files_by_uri(restores);
+    }
+
+    let fixed_paths = write_changed_files(
+        analysis,
+        &original_texts,
+        fixed_files,
+        workspace,
+        cmd_args.dry_run,
+    )?;
+    if cmd_args.dry_run {
+        eprintln!("{} files would be fixed", fixed_paths.len());
+    } else {
+        for path in &fixed_paths {
+            eprintln!("Fixed {}", path);
+        }
+        eprintln!("{} files fixed", fixed_paths.len());
+    }
+
+    Ok(())
+}
+
+/// The texts of the files before they are changed, the diffs are made against them
+pub fn get_original_texts(
+    analysis: &EmmyLuaAnalysis,
+    file_ids: &[FileId],
+) -> HashMap<FileId, String> {
+    let vfs = analysis.compilation.get_db().get_vfs();
+    file_ids
+        .iter()
+        .filter_map(|file_id| {
+            let document = vfs.get_document(file_id)?;
+            Some((*file_id, document.get_text().to_string()))
+        })
+        .collect()
+}
+
+/// Write the changed files back, or print them as a unified diff when `dry_run`.
+/// Returns the relative paths of the changed files.
+pub fn write_changed_files(
+    analysis: &EmmyLuaAnalysis,
+    original_texts: &HashMap<FileId, String>,
+    changed_files: HashMap<FileId, String>,
+    workspace: &Path,
+    dry_run: bool,
+) -> Result<Vec<String>, String> {
+    let vfs = analysis.compilation.get_db().get_vfs();
+    // sorted by path, so the diffs are printed in the same order on every run
+    let mut changed_files = changed_files
+        .into_iter()
+        .filter_map(|(file_id, new_text)| {
+            let file_path = vfs.get_file_path(&file_id)?.clone();
+            let original_text = original_texts.get(&file_id)?;
+            Some((
+                to_relative_path(&file_path, workspace),
+                file_path,
+                original_text,
+                new_text,
+            ))
+        })
+        .collect::<Vec<_>>();
+    changed_files.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut changed_paths = Vec::new();
+    for (relative_path, file_path, original_text, new_text) in changed_files {
+        if dry_run {
+            print!(
+                "{}",
+                unified_diff(
+                    &format!("a/{}", relative_path),
+                    &format!("b/{}", relative_path),
+                    original_text,
+                    &new_text
+                )
+            );
+        } else {
+            std::fs::write(&file_path, new_text)
+                .map_err(|err| format!("Failed to write {}: {}", file_path.display(), err))?;
+        }
+        changed_paths.push(relative_path);
+    }
+
+    Ok(changed_paths)
+}
+
+fn parse_fix_codes(fix_code: Option<&Vec<String>>) -> Result<Option<Vec<DiagnosticCode>>, String> {
+    let Some(fix_code) = fix_code else {
+        return Ok(None);
+    };
+
+    let mut codes = Vec::new();
+    for name in fix_code {
+        let code = DiagnosticCode::from_str(name.trim()).unwrap_or(DiagnosticCode::None);
+        if code == DiagnosticCode::None {
+            return Err(format!("Unknown diagnostic code: {}", name));
+        }
+        if !is_code_fixable(code) {
+            return Err(format!("Diagnostic code has no safe fix: {}", name));
+        }
+        codes.push(code);
+    }
+
+    Ok(Some(codes))
+}
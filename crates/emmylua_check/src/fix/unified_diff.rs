@@ -0,0 +1,255 @@
+const CONTEXT_LINES: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum DiffOp {
+    Equal(usize, usize),
+    Delete(usize),
+    Insert(usize),
+}
+
+/// Build a unified diff of two texts, returns an empty string when they are equal
+pub fn unified_diff(old_name: &str, new_name: &str, old_text: &str, new_text: &str) -> String {
+    let old_lines: Vec<&str> = old_text.split_inclusive('\n').collect();
+    let new_lines: Vec<&str> = new_text.split_inclusive('\n').collect();
+    let ops = diff_lines(&old_lines, &new_lines);
+    if ops.iter().all(|op| matches!(op, DiffOp::Equal(..))) {
+        return String::new();
+    }
+
+    let mut out = format!("--- {}\n+++ {}\n", old_name, new_name);
+    let mut idx = 0;
+    while idx < ops.len() {
+        // find the next change
+        let Some(change_start) = (idx..ops.len()).find(|i| !matches!(ops[*i], DiffOp::Equal(..)))
+        else {
+            break;
+        };
+        let hunk_start = change_start.saturating_sub(CONTEXT_LINES).max(idx);
+
+        // extend the hunk while the gap between changes is small enough
+        let mut hunk_end = change_start;
+        let mut equal_run = 0;
+        let mut i = change_start;
+        while i < ops.len() {
+            if matches!(ops[i], DiffOp::Equal(..)) {
+                equal_run += 1;
+                if equal_run > CONTEXT_LINES * 2 {
+                    break;
+                }
+            } else {
+                equal_run = 0;
+                hunk_end = i;
+            }
+            i += 1;
+        }
+        let hunk_end = (hunk_end + CONTEXT_LINES + 1).min(ops.len());
+
+        write_hunk(&mut out, &ops[hunk_start..hunk_end], &old_lines, &new_lines);
+        idx = hunk_end;
+    }
+
+    out
+}
+
+fn write_hunk(out: &mut String, ops: &[DiffOp], old_lines: &[&str], new_lines: &[&str]) {
+    let (old_start, new_start) = match ops.first() {
+        Some(DiffOp::Equal(old, new)) => (*old, *new),
+        Some(DiffOp::Delete(old)) => (*old, find_new_start(ops)),
+        Some(DiffOp::Insert(new)) => (find_old_start(ops), *new),
+        None => return,
+    };
+    let old_count = ops
+        .iter()
+        .filter(|op| !matches!(op, DiffOp::Insert(_)))
+        .count();
+    let new_count = ops
+        .iter()
+        .filter(|op| !matches!(op, DiffOp::Delete(_)))
+        .count();
+    out.push_str(&format!(
+        "@@ -{} +{} @@\n",
+        format_range(old_start, old_count),
+        format_range(new_start, new_count)
+    ));
+
+    for op in ops {
+        let (prefix, line) = match op {
+            DiffOp::Equal(old, _) => (' ', old_lines[*old]),
+            DiffOp::Delete(old) => ('-', old_lines[*old]),
+            DiffOp::Insert(new) => ('+', new_lines[*new]),
+        };
+        out.push(prefix);
+        out.push_str(line);
+        if !line.ends_with('\n') {
+            out.push_str("\n\\ No newline at end of file\n");
+        }
+    }
+}
+
+fn find_new_start(ops: &[DiffOp]) -> usize {
+    ops.iter()
+        .find_map(|op| match op {
+            DiffOp::Equal(_, new) | DiffOp::Insert(new) => Some(*new),
+            _ => None,
+        })
+        .unwrap_or(0)
+}
+
+fn find_old_start(ops: &[DiffOp]) -> usize {
+    ops.iter()
+        .find_map(|op| match op {
+            DiffOp::Equal(old, _) | DiffOp::Delete(old) => Some(*old),
+            _ => None,
+        })
+        .unwrap_or(0)
+}
+
+fn format_range(start: usize, count: usize) -> String {
+    match count {
+        0 => format!("{},0", start),
+        1 => format!("{}", start + 1),
+        _ => format!("{},{}", start + 1, count),
+    }
+}
+
+// Myers O(ND) diff, fixes only touch a few lines so D stays small
+fn diff_lines(old: &[&str], new: &[&str]) -> Vec<DiffOp> {
+    let n = old.len() as isize;
+    let m = new.len() as isize;
+    let max = (n + m) as usize;
+    let offset = max as isize + 1;
+    let mut v = vec![0isize; 2 * max + 3];
+    let mut trace = Vec::new();
+
+    'outer: for d in 0..=max as isize {
+        trace.push(v.clone());
+        let mut k = -d;
+        while k <= d {
+            let idx = (k + offset) as usize;
+            let mut x = if k == -d || (k != d && v[idx - 1] < v[idx + 1]) {
+                v[idx + 1]
+            } else {
+                v[idx - 1] + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && old[x as usize] == new[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            v[idx] = x;
+            if x >= n && y >= m {
+                break 'outer;
+            }
+            k += 2;
+        }
+    }
+
+    // backtrack from the end to collect the edit script
+    let mut ops = Vec::new();
+    let (mut x, mut y) = (n, m);
+    for d in (1..trace.len() as isize).rev() {
+        let v = &trace[d as usize];
+        let k = x - y;
+        let idx = (k + offset) as usize;
+        let prev_k = if k == -d || (k != d && v[idx - 1] < v[idx + 1]) {
+            k + 1
+        } else {
+            k - 1
+        };
+        let prev_x = v[(prev_k + offset) as usize];
+        let prev_y = prev_x - prev_k;
+        while x > prev_x && y > prev_y {
+            x -= 1;
+            y -= 1;
+            ops.push(DiffOp::Equal(x as usize, y as usize));
+        }
+        if x == prev_x {
+            y -= 1;
+            ops.push(DiffOp::Insert(y as usize));
+        } else {
+            x -= 1;
+            ops.push(DiffOp::Delete(x as usize));
+        }
+    }
+    while x > 0 && y > 0 {
+        x -= 1;
+        y -= 1;
+        ops.push(DiffOp::Equal(x as usize, y as usize));
+    }
+
+    ops.reverse();
+    ops
+}
+
+#[cfg(test)]
+mod test {
+    use super::unified_diff;
+
+    fn diff(old_text: &str, new_text: &str) -> String {
+        unified_diff("a/test.lua", "b/test.lua", old_text, new_text)
+    }
+
+    #[test]
+    fn test_equal() {
+        assert_eq!(diff("a\nb\n", "a\nb\n"), "");
+        assert_eq!(diff("", ""), "");
+    }
+
+    #[test]
+    fn test_insert_only() {
+        assert_eq!(
+            diff("a\nb\n", "a\nx\nb\n"),
+            "--- a/test.lua\n+++ b/test.lua\n@@ -1,2 +1,3 @@\n a\n+x\n b\n"
+        );
+    }
+
+    #[test]
+    fn test_delete_only() {
+        assert_eq!(
+            diff("a\nb\nc\n", "a\nc\n"),
+            "--- a/test.lua\n+++ b/test.lua\n@@ -1,3 +1,2 @@\n a\n-b\n c\n"
+        );
+    }
+
+    #[test]
+    fn test_empty_file() {
+        assert_eq!(
+            diff("", "a\nb\n"),
+            "--- a/test.lua\n+++ b/test.lua\n@@ -0,0 +1,2 @@\n+a\n+b\n"
+        );
+        assert_eq!(
+            diff("a\n", ""),
+            "--- a/test.lua\n+++ b/test.lua\n@@ -1 +0,0 @@\n-a\n"
+        );
+    }
+
+    #[test]
+    fn test_no_trailing_newline() {
+        assert_eq!(
+            diff("a\nb", "a\nc"),
+            "--- a/test.lua\n+++ b/test.lua\n@@ -1,2 +1,2 @@\n a\n-b\n\\ No newline at end of file\n+c\n\\ No newline at end of file\n"
+        );
+        assert_eq!(
+            diff("a\nb", "a\nb\n"),
+            "--- a/test.lua\n+++ b/test.lua\n@@ -1,2 +1,2 @@\n a\n-b\n\\ No newline at end of file\n+b\n"
+        );
+    }
+
+    #[test]
+    fn test_separate_hunks() {
+        let old_text = (1..=20).map(|i| format!("{}\n", i)).collect::<String>();
+        let new_text = (1..=20)
+            .map(|i| match i {
+                2 => "two\n".to_string(),
+                19 => "nineteen\n".to_string(),
+                _ => format!("{}\n", i),
+            })
+            .collect::<String>();
+        assert_eq!(
+            diff(&old_text, &new_text),
+            "--- a/test.lua\n+++ b/test.lua\n\
+             @@ -1,5 +1,5 @@\n 1\n-2\n+two\n 3\n 4\n 5\n\
+             @@ -16,5 +16,5 @@\n 16\n 17\n 18\n-19\n+nineteen\n 20\n"
+        );
+    }
+}
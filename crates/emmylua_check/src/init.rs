@@ -1,25 +1,19 @@
-use std::{path::PathBuf, str::FromStr, sync::Arc};
+use std::{path::PathBuf, sync::Arc};
 
 use emmylua_code_analysis::{
-    load_configs, load_workspace_files, update_code_style, EmmyLuaAnalysis, Emmyrc, LuaFileInfo,
+    collect_workspace_files, get_default_severity, load_configs, load_editorconfig, DiagnosticCode,
+    EmmyLuaAnalysis, Emmyrc,
 };
+use lsp_types::DiagnosticSeverity;
 
-#[allow(unused)]
-pub fn load_workspace(
-    workspace_folder: PathBuf,
-    config_path: Option<PathBuf>,
-    ignore: Option<Vec<String>>,
-) -> Option<EmmyLuaAnalysis> {
+use crate::cmd_args::CmdArgs;
+
+pub fn load_workspace(workspace_folder: PathBuf, cmd_args: &CmdArgs) -> Option<EmmyLuaAnalysis> {
     let mut analysis = EmmyLuaAnalysis::new();
     analysis.init_std_lib(false);
 
-    let mut workspace_folders = vec![workspace_folder];
-    for path in &workspace_folders {
-        analysis.add_main_workspace(path.clone());
-    }
-
-    let main_path = workspace_folders.get(0)?.clone();
-    let config_files = if let Some(config_path) = config_path {
+    let main_path = workspace_folder.clone();
+    let config_files = if let Some(config_path) = cmd_args.config.clone() {
         vec![config_path]
     } else {
         vec![
@@ -30,108 +24,166 @@ pub fn load_workspace(
 
     let mut emmyrc = load_configs(config_files, None);
     emmyrc.pre_process_emmyrc(&main_path);
-
-    for root in &emmyrc.workspace.workspace_roots {
-        analysis.add_main_workspace(PathBuf::from_str(root).unwrap());
+    apply_diagnostic_args(&mut emmyrc, cmd_args);
+    if let Some(ignore) = &cmd_args.ignore {
+        emmyrc.workspace.ignore_globs.extend(ignore.iter().cloned());
     }
-
-    for lib in &emmyrc.workspace.library {
-        analysis.add_main_workspace(PathBuf::from_str(lib).unwrap());
-        workspace_folders.push(PathBuf::from_str(lib).unwrap());
-    }
-
     analysis.update_config(Arc::new(emmyrc));
 
-    let file_infos = collect_files(&workspace_folders, &analysis.emmyrc, ignore);
-    let files = file_infos
+    // same as the language server: `library` is indexed as library workspaces
+    let workspace_folders = analysis.add_workspaces_by_config(vec![workspace_folder]);
+    load_editorconfig(&workspace_folders);
+    let files = collect_workspace_files(&workspace_folders, &analysis.emmyrc)
         .into_iter()
-        .filter_map(|file| {
-            if file.path.ends_with(".editorconfig") {
-                let file_path = PathBuf::from(file.path);
-                let parent_dir = file_path
-                    .parent()
-                    .unwrap()
-                    .to_path_buf()
-                    .to_string_lossy()
-                    .to_string()
-                    .replace("\\", "/");
-                let file_normalized = file_path.to_string_lossy().to_string().replace("\\", "/");
-                update_code_style(&parent_dir, &file_normalized);
-                None
-            } else {
-                Some(file.into_tuple())
-            }
-        })
+        .map(|file| file.into_tuple())
         .collect();
     analysis.update_files_by_path(files);
 
     Some(analysis)
 }
 
-pub fn collect_files(
-    workspaces: &Vec<PathBuf>,
-    emmyrc: &Emmyrc,
-    ignore: Option<Vec<String>>,
-) -> Vec<LuaFileInfo> {
-    let mut files = Vec::new();
-    let (match_pattern, exclude, exclude_dir) = calculate_include_and_exclude(emmyrc, ignore);
-
-    let encoding = &emmyrc.workspace.encoding;
-
-    for workspace in workspaces {
-        let loaded = load_workspace_files(
-            &workspace,
-            &match_pattern,
-            &exclude,
-            &exclude_dir,
-            Some(encoding),
-        )
-        .ok();
-        if let Some(loaded) = loaded {
-            files.extend(loaded);
+/// Merge the diagnostic options of the command line into the config, they take precedence
+fn apply_diagnostic_args(emmyrc: &mut Emmyrc, cmd_args: &CmdArgs) {
+    let diagnostics = &mut emmyrc.diagnostics;
+    for (code, severity) in &cmd_args.severity {
+        diagnostics.severity.insert(*code, *severity);
+    }
+
+    // `DiagnosticCode::None` stands for `all`
+    if cmd_args.disable.contains(&DiagnosticCode::None) {
+        diagnostics.enables.clear();
+        diagnostics.disable = DiagnosticCode::all();
+    } else {
+        diagnostics.disable.extend(cmd_args.disable.iter().copied());
+    }
+
+    for code in &cmd_args.enable {
+        diagnostics.disable.retain(|disabled| disabled != code);
+        if !diagnostics.enables.contains(code) {
+            diagnostics.enables.push(*code);
+        }
+    }
+
+    if let Some(min_severity) = cmd_args.min_severity {
+        let min_rank = severity_rank(min_severity.into());
+        for code in DiagnosticCode::all() {
+            let severity = match diagnostics.severity.get(&code) {
+                Some(severity) => (*severity).into(),
+                None => get_default_severity(code),
+            };
+            if severity_rank(severity) < min_rank && !diagnostics.disable.contains(&code) {
+                diagnostics.disable.push(code);
+            }
         }
     }
+}
 
-    files
+fn severity_rank(severity: DiagnosticSeverity) -> u8 {
+    match severity {
+        DiagnosticSeverity::ERROR => 3,
+        DiagnosticSeverity::WARNING => 2,
+        DiagnosticSeverity::INFORMATION => 1,
+        _ => 0,
+    }
 }
 
-pub fn calculate_include_and_exclude(
-    emmyrc: &Emmyrc,
-    ignore: Option<Vec<String>>,
-) -> (Vec<String>, Vec<String>, Vec<PathBuf>) {
-    let mut include = vec!["**/*.lua".to_string(), "**/.editorconfig".to_string()];
-    let mut exclude = Vec::new();
-    let mut exclude_dirs = Vec::new();
-
-    for extension in &emmyrc.runtime.extensions {
-        if extension.starts_with(".") {
-            include.push(format!("**/*{}", extension));
-        } else if extension.starts_with("*.") {
-            include.push(format!("**/{}", extension));
-        } else {
-            include.push(extension.clone());
-        }
+#[cfg(test)]
+mod test {
+    use emmylua_code_analysis::{DiagnosticCode, DiagnosticSeveritySetting, Emmyrc};
+    use structopt::StructOpt;
+
+    use crate::cmd_args::CmdArgs;
+
+    use super::apply_diagnostic_args;
+
+    fn apply(args: &[&str]) -> Emmyrc {
+        let mut emmyrc = Emmyrc::default();
+        let cmd_args = CmdArgs::from_iter(["emmylua-check", "."].iter().chain(args));
+        apply_diagnostic_args(&mut emmyrc, &cmd_args);
+        emmyrc
     }
 
-    for ignore_glob in &emmyrc.workspace.ignore_globs {
-        exclude.push(ignore_glob.clone());
+    #[test]
+    fn test_enable() {
+        let emmyrc = apply(&["--enable", "iter-variable-reassign,unused"]);
+        let diagnostics = &emmyrc.diagnostics;
+        assert!(diagnostics
+            .enables
+            .contains(&DiagnosticCode::IterVariableReassign));
+        assert!(diagnostics.enables.contains(&DiagnosticCode::Unused));
+        assert!(diagnostics.disable.is_empty());
     }
 
-    if let Some(ignore) = ignore {
-        exclude.extend(ignore);
+    #[test]
+    fn test_disable() {
+        let emmyrc = apply(&["--disable", "unused", "--disable", "deprecated"]);
+        let diagnostics = &emmyrc.diagnostics;
+        assert!(diagnostics.disable.contains(&DiagnosticCode::Unused));
+        assert!(diagnostics.disable.contains(&DiagnosticCode::Deprecated));
+        assert!(!diagnostics
+            .disable
+            .contains(&DiagnosticCode::UndefinedGlobal));
     }
 
-    for dir in &emmyrc.workspace.ignore_dir {
-        exclude_dirs.push(PathBuf::from(dir));
+    #[test]
+    fn test_disable_all() {
+        let emmyrc = apply(&["--disable", "all", "--enable", "undefined-global"]);
+        let diagnostics = &emmyrc.diagnostics;
+        assert!(diagnostics.disable.contains(&DiagnosticCode::Unused));
+        assert!(!diagnostics
+            .disable
+            .contains(&DiagnosticCode::UndefinedGlobal));
+        assert_eq!(diagnostics.enables, vec![DiagnosticCode::UndefinedGlobal]);
     }
 
-    // remove duplicate
-    include.sort();
-    include.dedup();
+    #[test]
+    fn test_severity() {
+        let emmyrc = apply(&["--severity", "unused=error,deprecated=info"]);
+        let severity = &emmyrc.diagnostics.severity;
+        assert!(matches!(
+            severity.get(&DiagnosticCode::Unused),
+            Some(DiagnosticSeveritySetting::Error)
+        ));
+        assert!(matches!(
+            severity.get(&DiagnosticCode::Deprecated),
+            Some(DiagnosticSeveritySetting::Information)
+        ));
+    }
 
-    // remove duplicate
-    exclude.sort();
-    exclude.dedup();
+    #[test]
+    fn test_min_severity() {
+        let emmyrc = apply(&["--min-severity", "warning"]);
+        let diagnostics = &emmyrc.diagnostics;
+        // hint by default
+        assert!(diagnostics.disable.contains(&DiagnosticCode::Unused));
+        assert!(!diagnostics.disable.contains(&DiagnosticCode::MissingReturn));
+        assert!(!diagnostics
+            .disable
+            .contains(&DiagnosticCode::UndefinedGlobal));
+    }
 
-    (include, exclude, exclude_dirs)
+    #[test]
+    fn test_min_severity_uses_overridden_severity() {
+        let emmyrc = apply(&[
+            "--min-severity",
+            "error",
+            "--severity",
+            "unused=error,undefined-global=warning",
+        ]);
+        let diagnostics = &emmyrc.diagnostics;
+        assert!(!diagnostics.disable.contains(&DiagnosticCode::Unused));
+        assert!(diagnostics
+            .disable
+            .contains(&DiagnosticCode::UndefinedGlobal));
+        assert!(diagnostics.disable.contains(&DiagnosticCode::MissingReturn));
+    }
+
+    #[test]
+    fn test_enabled_and_disabled() {
+        // --enable takes precedence over --disable
+        let emmyrc = apply(&["--disable", "unused", "--enable", "unused"]);
+        let diagnostics = &emmyrc.diagnostics;
+        assert!(!diagnostics.disable.contains(&DiagnosticCode::Unused));
+        assert!(diagnostics.enables.contains(&DiagnosticCode::Unused));
+    }
 }
@@ -1,23 +1,45 @@
 mod cmd_args;
+mod doc_coverage;
+mod fix;
 mod init;
 mod output;
+mod structural_replace;
+mod watch;
 
-use cmd_args::CmdArgs;
-use emmylua_code_analysis::{DbIndex, FileId};
+use cmd_args::{CmdArgs, OutputFormat};
+use emmylua_code_analysis::{EmmyLuaAnalysis, FileId};
 use output::output_result;
-use std::{error::Error, path::PathBuf, sync::Arc};
+use std::{
+    error::Error,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 use structopt::StructOpt;
 use tokio_util::sync::CancellationToken;
+use wax::Pattern;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error + Sync + Send>> {
     let cmd_args = CmdArgs::from_args();
-    let mut workspace = cmd_args.workspace;
+    // the coverage report has no diagnostics to put into the machine formats
+    if cmd_args.doc_coverage
+        && !matches!(
+            cmd_args.output_format,
+            OutputFormat::Text | OutputFormat::Json
+        )
+    {
+        return Err("--doc-coverage only supports the text and json output formats".into());
+    }
+    // the diff is printed to stdout ahead of the report
+    if cmd_args.fix && cmd_args.dry_run && !matches!(cmd_args.output_format, OutputFormat::Text) {
+        return Err("--fix --dry-run only supports the text output format".into());
+    }
+    let mut workspace = cmd_args.workspace.clone();
     if !workspace.is_absolute() {
         workspace = std::env::current_dir()?.join(workspace);
     }
 
-    let analysis = match init::load_workspace(workspace.clone(), cmd_args.config, cmd_args.ignore) {
+    let mut analysis = match init::load_workspace(workspace.clone(), &cmd_args) {
         Some(analysis) => analysis,
         None => {
             eprintln!("Failed to load workspace");
@@ -25,14 +47,56 @@ async fn main() -> Result<(), Box<dyn Error + Sync + Send>> {
         }
     };
 
-    let files = analysis.compilation.get_db().get_vfs().get_all_file_ids();
-    let db = analysis.compilation.get_db();
-    let need_check_files = get_need_check_ids(db, files, &workspace);
+    let need_check_files = get_need_check_ids(&analysis, &workspace, &cmd_args)?;
+
+    if cmd_args.doc_coverage {
+        let exit_code =
+            doc_coverage::report_doc_coverage(&analysis, &need_check_files, &workspace, &cmd_args);
+        if exit_code != 0 {
+            return Err(format!("exit code: {}", exit_code).into());
+        }
+        return Ok(());
+    }
+
+    if cmd_args.search.is_some() {
+        structural_replace::structural_replace_workspace(
+            &analysis,
+            &need_check_files,
+            &workspace,
+            &cmd_args,
+        )?;
+        return Ok(());
+    }
+
+    if cmd_args.fix {
+        fix::fix_workspace(&mut analysis, &need_check_files, &workspace, &cmd_args)?;
+    }
+
+    if cmd_args.watch {
+        watch::watch_workspace(analysis, need_check_files, workspace, &cmd_args).await?;
+        return Ok(());
+    }
+
+    let exit_code = check_files(analysis, need_check_files, &workspace, &cmd_args).await;
+
+    if exit_code != 0 {
+        return Err(format!("exit code: {}", exit_code).into());
+    }
 
+    eprintln!("Check finished");
+    Ok(())
+}
+
+/// Diagnose the files in parallel and output the result
+async fn check_files(
+    analysis: EmmyLuaAnalysis,
+    file_ids: Vec<FileId>,
+    workspace: &Path,
+    cmd_args: &CmdArgs,
+) -> i32 {
     let (sender, receiver) = tokio::sync::mpsc::channel(100);
     let analysis = Arc::new(analysis);
-    let db = analysis.compilation.get_db();
-    for file_id in need_check_files.clone() {
+    for file_id in file_ids.clone() {
         let sender = sender.clone();
         let analysis = analysis.clone();
         tokio::spawn(async move {
@@ -41,34 +105,131 @@ async fn main() -> Result<(), Box<dyn Error + Sync + Send>> {
             sender.send((file_id, diagnostics)).await.unwrap();
         });
     }
+    // the receiver ends once every task is done, also when there is no file to check
+    drop(sender);
 
-    let exit_code = output_result(
-        need_check_files.len(),
+    let db = analysis.compilation.get_db();
+    output_result(
+        file_ids.len(),
         db,
-        workspace,
+        workspace.to_path_buf(),
         receiver,
-        cmd_args.output_format,
-        cmd_args.output,
-        cmd_args.warnings_as_errors,
+        cmd_args,
     )
-    .await;
-
-    if exit_code != 0 {
-        return Err(format!("exit code: {}", exit_code).into());
-    }
-
-    eprintln!("Check finished");
-    Ok(())
+    .await
 }
 
-fn get_need_check_ids(db: &DbIndex, files: Vec<FileId>, workspace: &PathBuf) -> Vec<FileId> {
+fn get_need_check_ids(
+    analysis: &EmmyLuaAnalysis,
+    workspace: &PathBuf,
+    cmd_args: &CmdArgs,
+) -> Result<Vec<FileId>, String> {
+    let file_set = if cmd_args.files.is_empty() {
+        None
+    } else {
+        let globs = cmd_args
+            .files
+            .iter()
+            .map(|s| s.as_str())
+            .collect::<Vec<_>>();
+        Some(wax::any(globs).map_err(|err| format!("Invalid files glob: {}", err))?)
+    };
+    let library_roots: Vec<PathBuf> = analysis
+        .emmyrc
+        .workspace
+        .library
+        .iter()
+        .map(PathBuf::from)
+        .collect();
+
+    let db = analysis.compilation.get_db();
+    let module_index = db.get_module_index();
     let mut need_check_files = Vec::new();
-    for file_id in files {
+    for file_id in db.get_vfs().get_all_file_ids() {
         let file_path = db.get_vfs().get_file_path(&file_id).unwrap();
-        if file_path.starts_with(workspace) {
-            need_check_files.push(file_id);
+        let is_library = module_index
+            .get_module(file_id)
+            .is_some_and(|module| module.workspace_id.is_library());
+        // globs are relative to the workspace, or to the library root for library files
+        let relative_path = if is_library {
+            if !cmd_args.check_library {
+                continue;
+            }
+            library_roots
+                .iter()
+                .find_map(|root| file_path.strip_prefix(root).ok())
+        } else {
+            file_path.strip_prefix(workspace).ok()
+        };
+        let Some(relative_path) = relative_path else {
+            continue;
+        };
+        if let Some(file_set) = &file_set {
+            if !file_set.is_match(relative_path) {
+                continue;
+            }
         }
+        need_check_files.push(file_id);
     }
 
-    need_check_files
+    Ok(need_check_files)
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use emmylua_code_analysis::VirtualWorkspace;
+    use structopt::StructOpt;
+
+    use crate::cmd_args::CmdArgs;
+
+    use super::{check_files, get_need_check_ids};
+
+    #[test]
+    fn test_dry_run_requires_fix_or_search() {
+        assert!(CmdArgs::from_iter_safe(["emmylua_check", "--dry-run", "."]).is_err());
+        assert!(CmdArgs::from_iter_safe(["emmylua_check", "--fix", "--dry-run", "."]).is_ok());
+        assert!(CmdArgs::from_iter_safe([
+            "emmylua_check",
+            "--search",
+            "table.getn($t)",
+            "--dry-run",
+            "."
+        ])
+        .is_ok());
+        assert!(CmdArgs::from_iter_safe(["emmylua_check", "--fix", "--search", "x", "."]).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_files_glob_matching_nothing() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def_file("a.lua", "local a = b");
+        let workspace = ws.virtual_url_generator.base.clone();
+        let output = std::env::temp_dir().join(format!(
+            "emmylua_check_empty_files_{}.json",
+            std::process::id()
+        ));
+        let cmd_args = CmdArgs::from_iter([
+            "emmylua_check",
+            "--files",
+            "missing/**/*.lua",
+            "--output-format",
+            "json",
+            "--output",
+            output.to_str().unwrap(),
+            ".",
+        ]);
+
+        let file_ids = get_need_check_ids(&ws.analysis, &workspace, &cmd_args).unwrap();
+        assert!(file_ids.is_empty());
+        let exit_code = tokio::time::timeout(
+            Duration::from_secs(10),
+            check_files(ws.analysis, file_ids, &workspace, &cmd_args),
+        )
+        .await
+        .expect("check_files should finish without files to check");
+        assert_eq!(exit_code, 0);
+        let _ = std::fs::remove_file(output);
+    }
 }
@@ -0,0 +1,386 @@
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    path::PathBuf,
+};
+
+use emmylua_code_analysis::{is_code_default_enable, DbIndex, DiagnosticCode, FileId};
+use lsp_types::Diagnostic;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    fingerprint::{diagnostic_fingerprint, get_code_name},
+    get_relative_path,
+};
+
+const BASELINE_VERSION: u32 = 1;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct BaselineFile {
+    version: u32,
+    entries: Vec<BaselineEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct BaselineEntry {
+    fingerprint: String,
+    code: String,
+    file: String,
+    count: usize,
+}
+
+/// Known diagnostics which are not reported again, so new rules can be enabled on legacy code
+#[derive(Debug)]
+pub struct Baseline {
+    path: PathBuf,
+    workspace: PathBuf,
+    update: bool,
+    // fingerprint -> entry, the count is decreased when a diagnostic is matched
+    known: HashMap<String, BaselineEntry>,
+    suppressed_count: usize,
+    current: BTreeMap<String, BaselineEntry>,
+    // only the entries of the checked files and enabled codes can be fixed or replaced,
+    // so `--files`, `--enable` and `--disable` keep the rest of the baseline
+    checked_files: HashSet<String>,
+    enabled_codes: HashSet<String>,
+}
+
+impl Baseline {
+    pub fn new(db: &DbIndex, path: PathBuf, workspace: PathBuf, update: bool) -> Self {
+        let mut known = HashMap::new();
+        // a missing baseline is created by the update
+        if !update || path.exists() {
+            match load_baseline_file(&path) {
+                Ok(baseline_file) => {
+                    for entry in baseline_file.entries {
+                        known.insert(entry.fingerprint.clone(), entry);
+                    }
+                }
+                Err(err) => {
+                    eprintln!("Failed to load baseline {}: {}", path.display(), err);
+                }
+            }
+        }
+
+        Baseline {
+            path,
+            workspace,
+            update,
+            known,
+            suppressed_count: 0,
+            current: BTreeMap::new(),
+            checked_files: HashSet::new(),
+            enabled_codes: get_enabled_code_names(db),
+        }
+    }
+
+    /// Record the diagnostics of the file and return the ones not in the baseline
+    pub fn filter(
+        &mut self,
+        db: &DbIndex,
+        file_id: FileId,
+        diagnostics: Vec<Diagnostic>,
+    ) -> Vec<Diagnostic> {
+        let Some(document) = db.get_vfs().get_document(&file_id) else {
+            return diagnostics;
+        };
+        let relative_path = get_relative_path(db, file_id, &self.workspace);
+        self.checked_files.insert(relative_path.clone());
+        let mut new_diagnostics = Vec::new();
+        for diagnostic in diagnostics {
+            let fingerprint = diagnostic_fingerprint(&document, &relative_path, &diagnostic);
+            self.current
+                .entry(fingerprint.clone())
+                .or_insert_with(|| BaselineEntry {
+                    fingerprint: fingerprint.clone(),
+                    code: get_code_name(&diagnostic),
+                    file: relative_path.clone(),
+                    count: 0,
+                })
+                .count += 1;
+
+            if self.update {
+                continue;
+            }
+
+            match self.known.get_mut(&fingerprint) {
+                Some(entry) if entry.count > 0 => {
+                    entry.count -= 1;
+                    self.suppressed_count += 1;
+                }
+                _ => new_diagnostics.push(diagnostic),
+            }
+        }
+
+        new_diagnostics
+    }
+
+    fn is_checked(&self, entry: &BaselineEntry) -> bool {
+        self.checked_files.contains(&entry.file) && self.enabled_codes.contains(&entry.code)
+    }
+
+    /// The entries written by `--update-baseline`, the ones outside the checked files and codes are kept
+    fn updated_entries(&self) -> Vec<BaselineEntry> {
+        let mut entries: Vec<BaselineEntry> = self.current.values().cloned().collect();
+        entries.extend(
+            self.known
+                .values()
+                .filter(|entry| {
+                    !self.is_checked(entry) && !self.current.contains_key(&entry.fingerprint)
+                })
+                .cloned(),
+        );
+        entries.sort_by(|a, b| {
+            (&a.file, &a.code, &a.fingerprint).cmp(&(&b.file, &b.code, &b.fingerprint))
+        });
+        entries
+    }
+
+    /// The known entries of the checked files and codes which are no longer reported
+    fn fixed_entries(&self) -> impl Iterator<Item = &BaselineEntry> {
+        self.known
+            .values()
+            .filter(|entry| entry.count > 0 && self.is_checked(entry))
+    }
+
+    pub fn finish(&mut self) {
+        if self.update {
+            let entries = self.updated_entries();
+            let total: usize = entries.iter().map(|entry| entry.count).sum();
+            let baseline_file = BaselineFile {
+                version: BASELINE_VERSION,
+                entries,
+            };
+            if let Err(err) = save_baseline_file(&self.path, &baseline_file) {
+                eprintln!("Failed to write baseline {}: {}", self.path.display(), err);
+                return;
+            }
+
+            eprintln!(
+                "Baseline updated: {} diagnostics recorded in {}",
+                total,
+                self.path.display()
+            );
+            return;
+        }
+
+        let fixed_count: usize = self.fixed_entries().map(|entry| entry.count).sum();
+        eprintln!(
+            "Baseline: {} known diagnostics suppressed, {} fixed",
+            self.suppressed_count, fixed_count
+        );
+        if fixed_count > 0 {
+            let mut fixed: BTreeMap<(&str, &str), usize> = BTreeMap::new();
+            for entry in self.fixed_entries() {
+                *fixed
+                    .entry((entry.file.as_str(), entry.code.as_str()))
+                    .or_insert(0) += entry.count;
+            }
+            for ((file, code), count) in fixed {
+                eprintln!("  fixed {} x {} in {}", count, code, file);
+            }
+            eprintln!("Run with --update-baseline to remove them from the baseline");
+        }
+    }
+}
+
+fn get_enabled_code_names(db: &DbIndex) -> HashSet<String> {
+    let diagnostics = &db.get_emmyrc().diagnostics;
+    DiagnosticCode::all()
+        .into_iter()
+        .filter(|code| {
+            !diagnostics.disable.contains(code)
+                && (diagnostics.enables.contains(code) || is_code_default_enable(code))
+        })
+        .map(|code| code.get_name().to_string())
+        .collect()
+}
+
+fn load_baseline_file(path: &PathBuf) -> Result<BaselineFile, String> {
+    let content = std::fs::read_to_string(path).map_err(|err| err.to_string())?;
+    let baseline_file: BaselineFile =
+        serde_json::from_str(&content).map_err(|err| err.to_string())?;
+    if baseline_file.version != BASELINE_VERSION {
+        return Err(format!(
+            "unsupported baseline version {}",
+            baseline_file.version
+        ));
+    }
+
+    Ok(baseline_file)
+}
+
+fn save_baseline_file(path: &PathBuf, baseline_file: &BaselineFile) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        if !parent.as_os_str().is_empty() && !parent.exists() {
+            std::fs::create_dir_all(parent).map_err(|err| err.to_string())?;
+        }
+    }
+
+    let content = serde_json::to_string_pretty(baseline_file).map_err(|err| err.to_string())?;
+    std::fs::write(path, content).map_err(|err| err.to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::{Path, PathBuf};
+
+    use emmylua_code_analysis::{DiagnosticCode, Emmyrc, FileId, VirtualWorkspace};
+    use lsp_types::{Diagnostic, NumberOrString, Position, Range};
+
+    use super::{load_baseline_file, Baseline};
+
+    fn diagnostic(code: DiagnosticCode, line: u32) -> Diagnostic {
+        Diagnostic {
+            range: Range::new(Position::new(line, 0), Position::new(line, 1)),
+            code: Some(NumberOrString::String(code.get_name().to_string())),
+            message: "test".to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn baseline_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "emmylua_check_baseline_{}_{}.json",
+            name,
+            std::process::id()
+        ))
+    }
+
+    fn new_baseline(ws: &VirtualWorkspace, path: &Path, update: bool) -> Baseline {
+        Baseline::new(
+            ws.analysis.compilation.get_db(),
+            path.to_path_buf(),
+            ws.virtual_url_generator.base.clone(),
+            update,
+        )
+    }
+
+    fn filter(
+        baseline: &mut Baseline,
+        ws: &VirtualWorkspace,
+        file_id: FileId,
+        diagnostics: Vec<Diagnostic>,
+    ) -> usize {
+        baseline
+            .filter(ws.analysis.compilation.get_db(), file_id, diagnostics)
+            .len()
+    }
+
+    #[test]
+    fn test_fingerprint_stable_across_line_shifts() {
+        let path = baseline_path("line_shift");
+        let mut ws = VirtualWorkspace::new();
+        let file_id = ws.def_file("shift.lua", "print(a)\n");
+        let mut baseline = new_baseline(&ws, &path, true);
+        filter(
+            &mut baseline,
+            &ws,
+            file_id,
+            vec![diagnostic(DiagnosticCode::UndefinedGlobal, 0)],
+        );
+        baseline.finish();
+
+        let file_id = ws.def_file("shift.lua", "local b = 1\n\nprint(a)\n");
+        let mut baseline = new_baseline(&ws, &path, false);
+        let reported = filter(
+            &mut baseline,
+            &ws,
+            file_id,
+            vec![diagnostic(DiagnosticCode::UndefinedGlobal, 2)],
+        );
+        assert_eq!(reported, 0);
+        assert_eq!(baseline.suppressed_count, 1);
+        let _ = std::fs::remove_file(path);
+    }
+
+    #[test]
+    fn test_suppressed_and_fixed_count() {
+        let path = baseline_path("fixed_count");
+        let mut ws = VirtualWorkspace::new();
+        let file_id = ws.def_file("fixed.lua", "print(a)\nprint(b)\nprint(c)\n");
+        let mut baseline = new_baseline(&ws, &path, true);
+        filter(
+            &mut baseline,
+            &ws,
+            file_id,
+            vec![
+                diagnostic(DiagnosticCode::UndefinedGlobal, 0),
+                diagnostic(DiagnosticCode::UndefinedGlobal, 1),
+                diagnostic(DiagnosticCode::UndefinedGlobal, 2),
+            ],
+        );
+        baseline.finish();
+
+        let mut baseline = new_baseline(&ws, &path, false);
+        // one known diagnostic is fixed and a new one is reported
+        let reported = filter(
+            &mut baseline,
+            &ws,
+            file_id,
+            vec![
+                diagnostic(DiagnosticCode::UndefinedGlobal, 0),
+                diagnostic(DiagnosticCode::UndefinedGlobal, 1),
+                diagnostic(DiagnosticCode::Unused, 2),
+            ],
+        );
+        assert_eq!(reported, 1);
+        assert_eq!(baseline.suppressed_count, 2);
+        assert_eq!(baseline.fixed_entries().count(), 1);
+        let _ = std::fs::remove_file(path);
+    }
+
+    #[test]
+    fn test_partial_check_keeps_other_entries() {
+        let path = baseline_path("partial");
+        let mut ws = VirtualWorkspace::new();
+        let first_id = ws.def_file("first.lua", "print(a)\nlocal b\n");
+        let second_id = ws.def_file("second.lua", "print(c)\n");
+        let mut baseline = new_baseline(&ws, &path, true);
+        filter(
+            &mut baseline,
+            &ws,
+            first_id,
+            vec![
+                diagnostic(DiagnosticCode::UndefinedGlobal, 0),
+                diagnostic(DiagnosticCode::Unused, 1),
+            ],
+        );
+        filter(
+            &mut baseline,
+            &ws,
+            second_id,
+            vec![diagnostic(DiagnosticCode::UndefinedGlobal, 0)],
+        );
+        baseline.finish();
+
+        // only `first.lua` is checked, with `unused` disabled
+        let mut emmyrc = Emmyrc::default();
+        emmyrc.diagnostics.disable.push(DiagnosticCode::Unused);
+        ws.analysis.update_config(emmyrc.into());
+        let mut baseline = new_baseline(&ws, &path, false);
+        filter(&mut baseline, &ws, first_id, Vec::new());
+        let fixed: Vec<_> = baseline
+            .fixed_entries()
+            .map(|entry| (entry.file.as_str(), entry.code.as_str()))
+            .collect();
+        assert_eq!(fixed, vec![("first.lua", "undefined-global")]);
+
+        let mut baseline = new_baseline(&ws, &path, true);
+        filter(&mut baseline, &ws, first_id, Vec::new());
+        baseline.finish();
+        let entries: Vec<_> = load_baseline_file(&path)
+            .unwrap()
+            .entries
+            .into_iter()
+            .map(|entry| (entry.file, entry.code))
+            .collect();
+        assert_eq!(
+            entries,
+            vec![
+                ("first.lua".to_string(), "unused".to_string()),
+                ("second.lua".to_string(), "undefined-global".to_string()),
+            ]
+        );
+        let _ = std::fs::remove_file(path);
+    }
+}
@@ -0,0 +1,38 @@
+use emmylua_code_analysis::LuaDocument;
+use lsp_types::Diagnostic;
+
+/// A fingerprint which stays the same when unrelated lines are added or removed,
+/// it is built from the code, the relative file path and the trimmed source line.
+pub fn diagnostic_fingerprint(
+    document: &LuaDocument,
+    relative_path: &str,
+    diagnostic: &Diagnostic,
+) -> String {
+    let code = get_code_name(diagnostic);
+    let line_text = document
+        .get_line_range(diagnostic.range.start.line as usize)
+        .map(|range| document.get_text_slice(range).trim())
+        .unwrap_or_default();
+
+    let mut hash = FNV_OFFSET_BASIS;
+    for part in [code.as_str(), relative_path, line_text] {
+        for byte in part.bytes().chain(std::iter::once(0)) {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    format!("{:016x}", hash)
+}
+
+pub fn get_code_name(diagnostic: &Diagnostic) -> String {
+    match &diagnostic.code {
+        Some(lsp_types::NumberOrString::Number(code)) => code.to_string(),
+        Some(lsp_types::NumberOrString::String(code)) => code.clone(),
+        None => "".to_string(),
+    }
+}
+
+// 64-bit FNV-1a, the result must not depend on the rust version
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
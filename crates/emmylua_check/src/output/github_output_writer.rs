@@ -0,0 +1,96 @@
+use std::path::PathBuf;
+
+use emmylua_code_analysis::{DbIndex, FileId};
+use lsp_types::{Diagnostic, DiagnosticSeverity};
+
+use super::{fingerprint::get_code_name, get_relative_path, OutputWriter};
+
+/// Prints GitHub Actions workflow commands, which are shown as annotations on pull requests
+#[derive(Debug)]
+pub struct GithubOutputWriter {
+    workspace: PathBuf,
+}
+
+impl GithubOutputWriter {
+    pub fn new(workspace: PathBuf) -> Self {
+        GithubOutputWriter { workspace }
+    }
+}
+
+impl OutputWriter for GithubOutputWriter {
+    fn write(&mut self, db: &DbIndex, file_id: FileId, diagnostics: Vec<Diagnostic>) {
+        let relative_path = get_relative_path(db, file_id, &self.workspace);
+        for diagnostic in diagnostics {
+            println!("{}", format_annotation(&relative_path, &diagnostic));
+        }
+    }
+
+    fn finish(&mut self) {}
+}
+
+fn format_annotation(relative_path: &str, diagnostic: &Diagnostic) -> String {
+    let command = match diagnostic.severity {
+        Some(DiagnosticSeverity::ERROR) | None => "error",
+        Some(DiagnosticSeverity::WARNING) => "warning",
+        _ => "notice",
+    };
+    let range = diagnostic.range;
+    format!(
+        "::{} file={},line={},col={},endLine={},endColumn={},title={}::{}",
+        command,
+        escape_property(relative_path),
+        range.start.line + 1,
+        range.start.character + 1,
+        range.end.line + 1,
+        range.end.character + 1,
+        escape_property(&get_code_name(diagnostic)),
+        escape_data(&diagnostic.message)
+    )
+}
+
+fn escape_data(s: &str) -> String {
+    s.replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+fn escape_property(s: &str) -> String {
+    escape_data(s).replace(':', "%3A").replace(',', "%2C")
+}
+
+#[cfg(test)]
+mod test {
+    use lsp_types::{Diagnostic, DiagnosticSeverity, NumberOrString, Position, Range};
+
+    use super::format_annotation;
+
+    #[test]
+    fn test_annotation_escaping() {
+        let diagnostic = Diagnostic {
+            range: Range::new(Position::new(0, 0), Position::new(1, 4)),
+            severity: Some(DiagnosticSeverity::ERROR),
+            code: Some(NumberOrString::String("syntax-error".to_string())),
+            message: "100% broken\r\nsee: a, b".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            format_annotation("dir,1/a:b.lua", &diagnostic),
+            "::error file=dir%2C1/a%3Ab.lua,line=1,col=1,endLine=2,endColumn=5,title=syntax-error::100%25 broken%0D%0Asee: a, b"
+        );
+    }
+
+    #[test]
+    fn test_annotation_command() {
+        let mut diagnostic = Diagnostic {
+            range: Range::new(Position::new(2, 3), Position::new(2, 5)),
+            severity: Some(DiagnosticSeverity::WARNING),
+            code: Some(NumberOrString::String("unused".to_string())),
+            message: "unused".to_string(),
+            ..Default::default()
+        };
+        assert!(format_annotation("a.lua", &diagnostic)
+            .starts_with("::warning file=a.lua,line=3,col=4,"));
+        diagnostic.severity = Some(DiagnosticSeverity::HINT);
+        assert!(format_annotation("a.lua", &diagnostic).starts_with("::notice "));
+    }
+}
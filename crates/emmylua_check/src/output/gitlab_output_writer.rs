@@ -0,0 +1,147 @@
+use std::{collections::HashMap, fs::File, io::Write, path::PathBuf};
+
+use emmylua_code_analysis::{DbIndex, FileId};
+use lsp_types::{Diagnostic, DiagnosticSeverity};
+use serde_json::{json, Value};
+
+use crate::cmd_args::OutputDestination;
+
+use super::{
+    create_output_file, fingerprint::diagnostic_fingerprint, fingerprint::get_code_name,
+    get_relative_path, OutputWriter,
+};
+
+/// Writes a GitLab Code Quality report, which is shown in the merge request widget
+#[derive(Debug)]
+pub struct GitlabOutputWriter {
+    workspace: PathBuf,
+    output: Option<File>,
+    issues: Vec<Value>,
+    fingerprint_counts: HashMap<String, usize>,
+}
+
+impl GitlabOutputWriter {
+    pub fn new(workspace: PathBuf, output: OutputDestination) -> Self {
+        GitlabOutputWriter {
+            workspace,
+            output: create_output_file(output),
+            issues: Vec::new(),
+            fingerprint_counts: HashMap::new(),
+        }
+    }
+}
+
+impl OutputWriter for GitlabOutputWriter {
+    fn write(&mut self, db: &DbIndex, file_id: FileId, diagnostics: Vec<Diagnostic>) {
+        let Some(document) = db.get_vfs().get_document(&file_id) else {
+            return;
+        };
+        let relative_path = get_relative_path(db, file_id, &self.workspace);
+        for diagnostic in diagnostics {
+            let mut fingerprint = diagnostic_fingerprint(&document, &relative_path, &diagnostic);
+            // the same line may be reported several times, gitlab requires unique fingerprints
+            let count = self
+                .fingerprint_counts
+                .entry(fingerprint.clone())
+                .or_insert(0);
+            *count += 1;
+            if *count > 1 {
+                fingerprint = format!("{}-{}", fingerprint, count);
+            }
+
+            let severity = match diagnostic.severity {
+                Some(DiagnosticSeverity::ERROR) | None => "critical",
+                Some(DiagnosticSeverity::WARNING) => "major",
+                Some(DiagnosticSeverity::INFORMATION) => "minor",
+                _ => "info",
+            };
+            self.issues.push(json!({
+                "type": "issue",
+                "check_name": get_code_name(&diagnostic),
+                "description": diagnostic.message,
+                "categories": ["Bug Risk"],
+                "severity": severity,
+                "fingerprint": fingerprint,
+                "location": {
+                    "path": relative_path,
+                    "positions": {
+                        "begin": {
+                            "line": diagnostic.range.start.line + 1,
+                            "column": diagnostic.range.start.character + 1,
+                        },
+                        "end": {
+                            "line": diagnostic.range.end.line + 1,
+                            "column": diagnostic.range.end.character + 1,
+                        },
+                    },
+                },
+            }));
+        }
+    }
+
+    fn finish(&mut self) {
+        let pretty_json = serde_json::to_string_pretty(&self.issues).unwrap();
+        match self.output.as_mut() {
+            Some(output) => output.write_all(pretty_json.as_bytes()).unwrap(),
+            None => println!("{}", pretty_json),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use emmylua_code_analysis::{DiagnosticCode, VirtualWorkspace};
+    use lsp_types::{Diagnostic, NumberOrString, Position, Range};
+
+    use crate::cmd_args::OutputDestination;
+
+    use super::{GitlabOutputWriter, OutputWriter};
+
+    fn diagnostic(line: u32) -> Diagnostic {
+        Diagnostic {
+            range: Range::new(Position::new(line, 6), Position::new(line, 7)),
+            code: Some(NumberOrString::String(
+                DiagnosticCode::UndefinedGlobal.get_name().to_string(),
+            )),
+            message: "undefined global".to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn fingerprints(ws: &mut VirtualWorkspace, content: &str, lines: &[u32]) -> Vec<String> {
+        let file_id = ws.def_file("gitlab.lua", content);
+        let mut writer = GitlabOutputWriter::new(
+            ws.virtual_url_generator.base.clone(),
+            OutputDestination::Stdout,
+        );
+        writer.write(
+            ws.analysis.compilation.get_db(),
+            file_id,
+            lines.iter().map(|line| diagnostic(*line)).collect(),
+        );
+        writer
+            .issues
+            .iter()
+            .map(|issue| issue["fingerprint"].as_str().unwrap().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_fingerprint_stable_across_line_shifts() {
+        let mut ws = VirtualWorkspace::new();
+        let before = fingerprints(&mut ws, "print(a)\n", &[0]);
+        let after = fingerprints(&mut ws, "local b = 1\n\nprint(a)\n", &[2]);
+        assert_eq!(before, after);
+
+        let changed = fingerprints(&mut ws, "print(c)\n", &[0]);
+        assert_ne!(before, changed);
+    }
+
+    #[test]
+    fn test_duplicate_fingerprint_is_unique() {
+        let mut ws = VirtualWorkspace::new();
+        let result = fingerprints(&mut ws, "print(a)\nprint(a)\n", &[0, 1]);
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[1], format!("{}-2", result[0]));
+    }
+}
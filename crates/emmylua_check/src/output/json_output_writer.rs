@@ -6,7 +6,7 @@ use serde_json::{json, Value};
 
 use crate::cmd_args::OutputDestination;
 
-use super::OutputWriter;
+use super::{create_output_file, OutputWriter};
 
 #[derive(Debug)]
 pub struct JsonOutputWriter {
@@ -17,20 +17,8 @@ pub struct JsonOutputWriter {
 
 impl JsonOutputWriter {
     pub fn new(output: OutputDestination) -> Self {
-        let output = match output {
-            OutputDestination::Stdout => None,
-            OutputDestination::File(path) => {
-                if let Some(parent) = path.parent() {
-                    if !parent.exists() {
-                        std::fs::create_dir_all(parent).unwrap();
-                    }
-                }
-
-                Some(std::fs::File::create(path).unwrap())
-            }
-        };
         JsonOutputWriter {
-            output,
+            output: create_output_file(output),
             first_write: true,
             json_file_caches: Vec::new(),
         }
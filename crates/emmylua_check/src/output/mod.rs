@@ -1,33 +1,55 @@
+mod baseline;
+mod fingerprint;
+mod github_output_writer;
+mod gitlab_output_writer;
 mod json_output_writer;
+mod sarif_output_writer;
 mod text_output_writer;
 
-use std::path::PathBuf;
+use std::{
+    fs::File,
+    path::{Path, PathBuf},
+};
 
 use emmylua_code_analysis::{DbIndex, FileId};
 use lsp_types::Diagnostic;
 use tokio::sync::mpsc::Receiver;
 
-use crate::cmd_args::{OutputDestination, OutputFormat};
+use crate::cmd_args::{CmdArgs, OutputDestination, OutputFormat};
 
 pub async fn output_result(
     total_count: usize,
     db: &DbIndex,
     workspace: PathBuf,
     mut receiver: Receiver<(FileId, Option<Vec<Diagnostic>>)>,
-    output_format: OutputFormat,
-    output: OutputDestination,
-    warnings_as_errors: bool,
+    cmd_args: &CmdArgs,
 ) -> i32 {
-    let mut writer: Box<dyn OutputWriter> = match output_format {
+    let output = cmd_args.output.clone();
+    let warnings_as_errors = cmd_args.warnings_as_errors;
+    let mut baseline = cmd_args
+        .baseline
+        .clone()
+        .map(|path| baseline::Baseline::new(db, path, workspace.clone(), cmd_args.update_baseline));
+    let mut writer: Box<dyn OutputWriter> = match cmd_args.output_format {
         OutputFormat::Json => Box::new(json_output_writer::JsonOutputWriter::new(output)),
         OutputFormat::Text => Box::new(text_output_writer::TextOutputWriter::new(workspace)),
+        OutputFormat::Sarif => Box::new(sarif_output_writer::SarifOutputWriter::new(
+            workspace, output,
+        )),
+        OutputFormat::Github => Box::new(github_output_writer::GithubOutputWriter::new(workspace)),
+        OutputFormat::Gitlab => Box::new(gitlab_output_writer::GitlabOutputWriter::new(
+            workspace, output,
+        )),
     };
 
     let mut has_error = false;
     let mut count = 0;
     while let Some((file_id, diagnostics)) = receiver.recv().await {
         count += 1;
-        if let Some(diagnostics) = diagnostics {
+        if let Some(mut diagnostics) = diagnostics {
+            if let Some(baseline) = baseline.as_mut() {
+                diagnostics = baseline.filter(db, file_id, diagnostics);
+            }
             for diagnostic in &diagnostics {
                 if diagnostic.severity == Some(lsp_types::DiagnosticSeverity::ERROR) {
                     has_error = true;
@@ -48,6 +70,9 @@ pub async fn output_result(
     }
 
     writer.finish();
+    if let Some(baseline) = baseline.as_mut() {
+        baseline.finish();
+    }
 
     if has_error {
         1
@@ -61,3 +86,33 @@ trait OutputWriter {
 
     fn finish(&mut self);
 }
+
+pub(crate) fn create_output_file(output: OutputDestination) -> Option<File> {
+    match output {
+        OutputDestination::Stdout => None,
+        OutputDestination::File(path) => {
+            if let Some(parent) = path.parent() {
+                if !parent.exists() {
+                    std::fs::create_dir_all(parent).unwrap();
+                }
+            }
+
+            Some(std::fs::File::create(path).unwrap())
+        }
+    }
+}
+
+// path relative to the workspace with `/` separators, reports are shared across platforms
+pub(crate) fn get_relative_path(db: &DbIndex, file_id: FileId, workspace: &Path) -> String {
+    let file_path = db.get_vfs().get_file_path(&file_id).unwrap();
+    to_relative_path(file_path, workspace)
+}
+
+/// The path relative to the workspace with `/` separators, the file path when outside of it
+pub(crate) fn to_relative_path(file_path: &Path, workspace: &Path) -> String {
+    file_path
+        .strip_prefix(workspace)
+        .unwrap_or(file_path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
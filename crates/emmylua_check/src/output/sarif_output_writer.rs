@@ -0,0 +1,258 @@
+use std::{fs::File, io::Write, path::PathBuf};
+
+use emmylua_code_analysis::{
+    file_path_to_uri, get_default_severity, is_code_default_enable, DbIndex, DiagnosticCode, FileId,
+};
+use lsp_types::{Diagnostic, DiagnosticSeverity};
+use serde_json::{json, Value};
+
+use crate::cmd_args::OutputDestination;
+
+use super::{
+    create_output_file, fingerprint::diagnostic_fingerprint, fingerprint::get_code_name,
+    get_relative_path, OutputWriter,
+};
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SRC_ROOT: &str = "%SRCROOT%";
+
+/// Writes a SARIF 2.1.0 log, used by code scanning dashboards
+#[derive(Debug)]
+pub struct SarifOutputWriter {
+    workspace: PathBuf,
+    output: Option<File>,
+    codes: Vec<DiagnosticCode>,
+    results: Vec<Value>,
+}
+
+impl SarifOutputWriter {
+    pub fn new(workspace: PathBuf, output: OutputDestination) -> Self {
+        let codes = DiagnosticCode::all()
+            .into_iter()
+            .filter(|code| *code != DiagnosticCode::None)
+            .collect();
+        SarifOutputWriter {
+            workspace,
+            output: create_output_file(output),
+            codes,
+            results: Vec::new(),
+        }
+    }
+
+    fn build_rules(&self) -> Vec<Value> {
+        self.codes
+            .iter()
+            .map(|code| {
+                let description = match code.get_description() {
+                    "" => code.get_name(),
+                    description => description,
+                };
+                json!({
+                    "id": code.get_name(),
+                    "name": format!("{:?}", code),
+                    "shortDescription": {
+                        "text": description,
+                    },
+                    "defaultConfiguration": {
+                        "enabled": is_code_default_enable(code),
+                        "level": to_sarif_level(Some(get_default_severity(*code))),
+                    },
+                })
+            })
+            .collect()
+    }
+
+    fn build_log(&mut self) -> Value {
+        let workspace = self
+            .workspace
+            .canonicalize()
+            .unwrap_or_else(|_| self.workspace.clone());
+        let mut workspace_uri = file_path_to_uri(&workspace)
+            .map(|uri| uri.as_str().to_string())
+            .unwrap_or_default();
+        // a base uri has to end with a slash, or the relative uris replace its last segment
+        if !workspace_uri.ends_with('/') {
+            workspace_uri.push('/');
+        }
+
+        json!({
+            "$schema": SARIF_SCHEMA,
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "emmylua_check",
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "informationUri": env!("CARGO_PKG_REPOSITORY"),
+                        "rules": self.build_rules(),
+                    },
+                },
+                "originalUriBaseIds": {
+                    SRC_ROOT: {
+                        "uri": workspace_uri,
+                    },
+                },
+                "results": std::mem::take(&mut self.results),
+            }],
+        })
+    }
+}
+
+impl OutputWriter for SarifOutputWriter {
+    fn write(&mut self, db: &DbIndex, file_id: FileId, diagnostics: Vec<Diagnostic>) {
+        let Some(document) = db.get_vfs().get_document(&file_id) else {
+            return;
+        };
+        let relative_path = get_relative_path(db, file_id, &self.workspace);
+        for diagnostic in diagnostics {
+            let code = get_code_name(&diagnostic);
+            let mut result = json!({
+                "ruleId": code,
+                "level": to_sarif_level(diagnostic.severity),
+                "message": {
+                    "text": diagnostic.message,
+                },
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": {
+                            "uri": relative_path,
+                            "uriBaseId": SRC_ROOT,
+                        },
+                        "region": {
+                            "startLine": diagnostic.range.start.line + 1,
+                            "startColumn": diagnostic.range.start.character + 1,
+                            "endLine": diagnostic.range.end.line + 1,
+                            "endColumn": diagnostic.range.end.character + 1,
+                        },
+                    },
+                }],
+                "partialFingerprints": {
+                    "emmyluaFingerprint/v1": diagnostic_fingerprint(&document, &relative_path, &diagnostic),
+                },
+            });
+            if let Some(index) = self.codes.iter().position(|c| c.get_name() == code) {
+                result["ruleIndex"] = json!(index);
+            }
+
+            self.results.push(result);
+        }
+    }
+
+    fn finish(&mut self) {
+        let sarif = self.build_log();
+        let pretty_json = serde_json::to_string_pretty(&sarif).unwrap();
+        match self.output.as_mut() {
+            Some(output) => output.write_all(pretty_json.as_bytes()).unwrap(),
+            None => println!("{}", pretty_json),
+        }
+    }
+}
+
+fn to_sarif_level(severity: Option<DiagnosticSeverity>) -> &'static str {
+    match severity {
+        Some(DiagnosticSeverity::ERROR) | None => "error",
+        Some(DiagnosticSeverity::WARNING) => "warning",
+        _ => "note",
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use emmylua_code_analysis::{DiagnosticCode, VirtualWorkspace};
+    use lsp_types::{Diagnostic, DiagnosticSeverity, NumberOrString, Position, Range};
+
+    use crate::cmd_args::OutputDestination;
+
+    use super::{OutputWriter, SarifOutputWriter};
+
+    #[test]
+    fn test_sarif_log_shape() {
+        let mut ws = VirtualWorkspace::new();
+        let file_id = ws.def_file("sarif.lua", "local a = 1\nprint(b)\n");
+        let mut writer = SarifOutputWriter::new(
+            ws.virtual_url_generator.base.clone(),
+            OutputDestination::Stdout,
+        );
+        let diagnostic = Diagnostic {
+            range: Range::new(Position::new(1, 6), Position::new(1, 7)),
+            severity: Some(DiagnosticSeverity::WARNING),
+            code: Some(NumberOrString::String(
+                DiagnosticCode::UndefinedGlobal.get_name().to_string(),
+            )),
+            message: "undefined global variable: b".to_string(),
+            ..Default::default()
+        };
+        writer.write(ws.analysis.compilation.get_db(), file_id, vec![diagnostic]);
+        let log = writer.build_log();
+
+        assert_eq!(log["version"], "2.1.0");
+        assert_eq!(
+            log["$schema"],
+            "https://json.schemastore.org/sarif-2.1.0.json"
+        );
+        let run = &log["runs"][0];
+        assert_eq!(run["tool"]["driver"]["name"], "emmylua_check");
+        let base_uri = run["originalUriBaseIds"]["%SRCROOT%"]["uri"]
+            .as_str()
+            .unwrap();
+        assert!(base_uri.starts_with("file:///"));
+        assert!(base_uri.ends_with('/'));
+
+        let result = &run["results"][0];
+        assert_eq!(result["ruleId"], "undefined-global");
+        assert_eq!(result["level"], "warning");
+        let location = &result["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "sarif.lua");
+        assert_eq!(location["artifactLocation"]["uriBaseId"], "%SRCROOT%");
+        assert_eq!(location["region"]["startLine"], 2);
+        assert_eq!(location["region"]["startColumn"], 7);
+        assert!(result["partialFingerprints"]["emmyluaFingerprint/v1"].is_string());
+
+        let rule_index = result["ruleIndex"].as_u64().unwrap() as usize;
+        assert_eq!(
+            run["tool"]["driver"]["rules"][rule_index]["id"],
+            "undefined-global"
+        );
+    }
+
+    #[test]
+    fn test_sarif_rule_metadata() {
+        let ws = VirtualWorkspace::new();
+        let writer = SarifOutputWriter::new(
+            ws.virtual_url_generator.base.clone(),
+            OutputDestination::Stdout,
+        );
+        let rules = writer.build_rules();
+        assert!(rules.iter().all(|rule| rule["id"] != "none"));
+
+        let rule = rules
+            .iter()
+            .find(|rule| rule["id"] == "assign-type-mismatch")
+            .unwrap();
+        assert_eq!(rule["name"], "AssignTypeMismatch");
+        assert_eq!(rule["shortDescription"]["text"], "Assign type mismatch");
+        assert_eq!(rule["defaultConfiguration"]["enabled"], true);
+        assert_eq!(rule["defaultConfiguration"]["level"], "warning");
+
+        for rule in &rules {
+            assert!(!rule["shortDescription"]["text"]
+                .as_str()
+                .unwrap()
+                .is_empty());
+        }
+    }
+
+    #[test]
+    fn test_sarif_base_uri_is_encoded() {
+        let workspace = std::env::temp_dir().join(format!("emmylua sarif {}", std::process::id()));
+        std::fs::create_dir_all(&workspace).unwrap();
+        let mut writer = SarifOutputWriter::new(workspace.clone(), OutputDestination::Stdout);
+        let log = writer.build_log();
+        std::fs::remove_dir_all(&workspace).unwrap();
+
+        let base_uri = log["runs"][0]["originalUriBaseIds"]["%SRCROOT%"]["uri"]
+            .as_str()
+            .unwrap();
+        assert!(base_uri.ends_with(&format!("/emmylua%20sarif%20{}/", std::process::id())));
+    }
+}
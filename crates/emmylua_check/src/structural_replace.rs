@@ -0,0 +1,86 @@
+use std::path::Path;
+
+use emmylua_code_analysis::{structural_search, EmmyLuaAnalysis, FileId, StructuralPattern};
+
+use crate::{
+    cmd_args::CmdArgs,
+    fix::{get_original_texts, write_changed_files},
+    output::to_relative_path,
+};
+
+/// Print the code matching `--search`, or replace it with `--replace` and write the files back,
+/// a diff is printed instead when `--dry-run`
+pub fn structural_replace_workspace(
+    analysis: &EmmyLuaAnalysis,
+    file_ids: &[FileId],
+    workspace: &Path,
+    cmd_args: &CmdArgs,
+) -> Result<(), String> {
+    let Some(search) = &cmd_args.search else {
+        return Ok(());
+    };
+    let Some(replace) = &cmd_args.replace else {
+        return print_matches(analysis, file_ids, workspace, search);
+    };
+
+    let original_texts = get_original_texts(analysis, file_ids);
+    let (replaced_files, skipped) = analysis.structural_replace_files(file_ids, search, replace)?;
+    let replaced_paths = write_changed_files(
+        analysis,
+        &original_texts,
+        replaced_files,
+        workspace,
+        cmd_args.dry_run,
+    )?;
+    if skipped > 0 {
+        eprintln!(
+            "{} matches with a comment outside the placeholders were skipped",
+            skipped
+        );
+    }
+    if cmd_args.dry_run {
+        eprintln!("{} files would be replaced", replaced_paths.len());
+    } else {
+        for path in &replaced_paths {
+            eprintln!("Replaced {}", path);
+        }
+        eprintln!("{} files replaced", replaced_paths.len());
+    }
+
+    Ok(())
+}
+
+fn print_matches(
+    analysis: &EmmyLuaAnalysis,
+    file_ids: &[FileId],
+    workspace: &Path,
+    search: &str,
+) -> Result<(), String> {
+    let pattern = StructuralPattern::parse(search, &analysis.emmyrc)?;
+    let mut results = Vec::new();
+    for file_id in file_ids {
+        let Some(semantic_model) = analysis.compilation.get_semantic_model(*file_id) else {
+            continue;
+        };
+        let document = semantic_model.get_document();
+        let relative_path = to_relative_path(document.get_file_path(), workspace);
+        for structural_match in structural_search(&semantic_model, &pattern) {
+            let range = structural_match.node.text_range();
+            let Some((line, col)) = document.get_line_col(range.start()) else {
+                continue;
+            };
+            // only the first line of a multiline match is shown
+            let text = document.get_text_slice(range);
+            let text = text.lines().next().unwrap_or_default();
+            results.push((relative_path.clone(), line + 1, col + 1, text.to_string()));
+        }
+    }
+
+    results.sort();
+    for (path, line, col, text) in &results {
+        println!("{}:{}:{}: {}", path, line, col, text);
+    }
+    eprintln!("{} matches found", results.len());
+
+    Ok(())
+}
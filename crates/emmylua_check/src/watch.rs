@@ -0,0 +1,309 @@
+use std::{
+    collections::HashMap,
+    io::{IsTerminal, Write},
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Duration,
+};
+
+use emmylua_code_analysis::{
+    calculate_include_and_exclude, read_file_with_encoding, EmmyLuaAnalysis, FileId,
+};
+use lsp_types::Diagnostic;
+use notify::{Config, Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver};
+use tokio_util::sync::CancellationToken;
+use wax::Pattern;
+
+use crate::{
+    cmd_args::{CmdArgs, OutputFormat},
+    get_need_check_ids,
+    output::output_result,
+};
+
+type FileResults = HashMap<FileId, Option<Vec<Diagnostic>>>;
+
+// wait for the burst of events of one save to finish
+const DEBOUNCE_DURATION: Duration = Duration::from_millis(200);
+
+/// Keep the analysis alive and check the workspace again when a file changes,
+/// the results of every checked file are kept so each report covers the whole workspace
+pub async fn watch_workspace(
+    analysis: EmmyLuaAnalysis,
+    need_check_ids: Vec<FileId>,
+    workspace: PathBuf,
+    cmd_args: &CmdArgs,
+) -> Result<(), String> {
+    let (mut analysis, mut results) = diagnose_files(analysis, need_check_ids).await?;
+    output_file_results(&analysis, &results, &workspace, cmd_args).await;
+
+    let (tx, mut rx) = unbounded_channel();
+    let mut watcher = RecommendedWatcher::new(
+        move |res: notify::Result<Event>| {
+            if let Ok(event) = res {
+                let _ = tx.send(event);
+            }
+        },
+        Config::default(),
+    )
+    .map_err(|err| format!("Failed to create watcher: {}", err))?;
+    // library files are indexed too, a change of them affects the workspace files using them
+    let mut roots = vec![workspace.clone()];
+    roots.extend(
+        analysis
+            .emmyrc
+            .workspace
+            .library
+            .iter()
+            .map(PathBuf::from)
+            .filter(|root| root.exists()),
+    );
+    for root in &roots {
+        watcher
+            .watch(root, RecursiveMode::Recursive)
+            .map_err(|err| format!("Failed to watch {}: {}", root.display(), err))?;
+    }
+
+    let (include, exclude, exclude_dirs) = calculate_include_and_exclude(&analysis.emmyrc);
+    let include_set = wax::any(include.iter().map(|s| s.as_str()).collect::<Vec<_>>())
+        .map_err(|err| format!("Invalid glob pattern: {}", err))?;
+    let exclude_set = wax::any(exclude.iter().map(|s| s.as_str()).collect::<Vec<_>>())
+        .map_err(|err| format!("Invalid ignore glob pattern: {}", err))?;
+    let is_watched_file = |path: &PathBuf| {
+        let Some(relative_path) = roots.iter().find_map(|root| path.strip_prefix(root).ok()) else {
+            return false;
+        };
+        path.extension().is_some()
+            && !exclude_dirs.iter().any(|dir| path.starts_with(dir))
+            && !exclude_set.is_match(relative_path)
+            && include_set.is_match(relative_path)
+    };
+
+    print_watching();
+    loop {
+        let changed_paths = receive_changed_paths(&mut rx).await;
+        let changed_paths: Vec<PathBuf> = changed_paths
+            .into_iter()
+            .filter(|path| is_watched_file(path))
+            .collect();
+        if changed_paths.is_empty() {
+            continue;
+        }
+
+        let encoding = analysis.emmyrc.workspace.encoding.clone();
+        let files = changed_paths
+            .iter()
+            .map(|path| (path.clone(), read_file_with_encoding(path, &encoding)))
+            .collect::<Vec<_>>();
+        let changed_count = files.len();
+        analysis = check_changed_files(analysis, &mut results, files, &workspace, cmd_args).await?;
+
+        if should_clear_screen(cmd_args) {
+            // clear the screen and move the cursor to the top left
+            print!("\x1B[2J\x1B[1;1H");
+            let _ = std::io::stdout().flush();
+        }
+        let exit_code = output_file_results(&analysis, &results, &workspace, cmd_args).await;
+
+        eprintln!(
+            "{} files changed, {} files checked, {}",
+            changed_count,
+            results.len(),
+            if exit_code == 0 {
+                "no errors"
+            } else {
+                "errors found"
+            }
+        );
+        print_watching();
+    }
+}
+
+/// Update the changed files and diagnose every checked file again, a global or a type declared
+/// in one file affects the diagnostics of files which do not require it
+async fn check_changed_files(
+    mut analysis: EmmyLuaAnalysis,
+    results: &mut FileResults,
+    files: Vec<(PathBuf, Option<String>)>,
+    workspace: &PathBuf,
+    cmd_args: &CmdArgs,
+) -> Result<EmmyLuaAnalysis, String> {
+    analysis.update_files_by_path(files);
+    // doc types are resolved when a file is indexed, files using a changed class need a reindex
+    analysis.reindex();
+    let need_check_ids = get_need_check_ids(&analysis, workspace, cmd_args)?;
+    let (analysis, new_results) = diagnose_files(analysis, need_check_ids).await?;
+    // removed files and files which are no longer checked are dropped from the report
+    *results = new_results;
+    Ok(analysis)
+}
+
+/// Diagnose the files in parallel, the analysis is returned for reuse
+async fn diagnose_files(
+    analysis: EmmyLuaAnalysis,
+    file_ids: Vec<FileId>,
+) -> Result<(EmmyLuaAnalysis, FileResults), String> {
+    let analysis = Arc::new(analysis);
+    let mut handles = Vec::new();
+    for file_id in file_ids {
+        let analysis = analysis.clone();
+        handles.push(tokio::spawn(async move {
+            let cancel_token = CancellationToken::new();
+            (file_id, analysis.diagnose_file(file_id, cancel_token))
+        }));
+    }
+
+    let mut results = FileResults::new();
+    for handle in handles {
+        if let Ok((file_id, diagnostics)) = handle.await {
+            results.insert(file_id, diagnostics);
+        }
+    }
+    let analysis = Arc::try_unwrap(analysis)
+        .map_err(|_| "The analysis is still used by a diagnose task".to_string())?;
+    Ok((analysis, results))
+}
+
+// the escape sequence would corrupt the machine readable formats written to stdout
+fn should_clear_screen(cmd_args: &CmdArgs) -> bool {
+    matches!(cmd_args.output_format, OutputFormat::Text) && std::io::stdout().is_terminal()
+}
+
+// the baseline and the output file are written from all the results, not only the last checked
+async fn output_file_results(
+    analysis: &EmmyLuaAnalysis,
+    results: &FileResults,
+    workspace: &Path,
+    cmd_args: &CmdArgs,
+) -> i32 {
+    let (sender, receiver) = tokio::sync::mpsc::channel(results.len().max(1));
+    for (file_id, diagnostics) in results {
+        let _ = sender.send((*file_id, diagnostics.clone())).await;
+    }
+    drop(sender);
+
+    let db = analysis.compilation.get_db();
+    output_result(
+        results.len(),
+        db,
+        workspace.to_path_buf(),
+        receiver,
+        cmd_args,
+    )
+    .await
+}
+
+async fn receive_changed_paths(rx: &mut UnboundedReceiver<Event>) -> Vec<PathBuf> {
+    let mut paths = Vec::new();
+    let Some(event) = rx.recv().await else {
+        return paths;
+    };
+    push_event_paths(&mut paths, event);
+
+    tokio::time::sleep(DEBOUNCE_DURATION).await;
+    while let Ok(event) = rx.try_recv() {
+        push_event_paths(&mut paths, event);
+    }
+
+    paths.sort();
+    paths.dedup();
+    paths
+}
+
+fn push_event_paths(paths: &mut Vec<PathBuf>, event: Event) {
+    if matches!(
+        event.kind,
+        EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+    ) {
+        paths.extend(event.paths);
+    }
+}
+
+fn print_watching() {
+    eprintln!("Watching for changes...");
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use emmylua_code_analysis::{DiagnosticCode, VirtualWorkspace};
+    use lsp_types::NumberOrString;
+    use notify::{
+        event::{AccessKind, CreateKind, ModifyKind},
+        Event, EventKind,
+    };
+    use structopt::StructOpt;
+
+    use crate::cmd_args::CmdArgs;
+
+    use super::{
+        check_changed_files, diagnose_files, push_event_paths, should_clear_screen, FileResults,
+    };
+
+    #[test]
+    fn test_push_event_paths() {
+        let mut paths = Vec::new();
+        push_event_paths(
+            &mut paths,
+            Event::new(EventKind::Create(CreateKind::File)).add_path(PathBuf::from("a.lua")),
+        );
+        push_event_paths(
+            &mut paths,
+            Event::new(EventKind::Modify(ModifyKind::Any)).add_path(PathBuf::from("b.lua")),
+        );
+        // reading a file does not change it
+        push_event_paths(
+            &mut paths,
+            Event::new(EventKind::Access(AccessKind::Any)).add_path(PathBuf::from("c.lua")),
+        );
+        assert_eq!(paths, vec![PathBuf::from("a.lua"), PathBuf::from("b.lua")]);
+    }
+
+    #[tokio::test]
+    async fn test_check_changed_files() {
+        let mut ws = VirtualWorkspace::new();
+        let workspace = ws.virtual_url_generator.base.clone();
+        let user_id = ws.def_file("user.lua", "local a = value\n---@type Shape\nlocal shape");
+        let global_id = ws.def_file("global.lua", "");
+        let cmd_args = CmdArgs::from_iter(["emmylua_check", "."]);
+        let (analysis, mut results) = diagnose_files(ws.analysis, vec![user_id, global_id])
+            .await
+            .unwrap();
+        let has_code = |results: &FileResults, code: DiagnosticCode| {
+            results[&user_id].iter().flatten().any(|diagnostic| {
+                diagnostic.code == Some(NumberOrString::String(code.get_name().to_string()))
+            })
+        };
+        assert!(has_code(&results, DiagnosticCode::UndefinedGlobal));
+        assert!(has_code(&results, DiagnosticCode::TypeNotFound));
+
+        // user.lua does not require global.lua, but it is checked again
+        let files = vec![(
+            workspace.join("global.lua"),
+            Some("value = 1\n---@class Shape".to_string()),
+        )];
+        check_changed_files(analysis, &mut results, files, &workspace, &cmd_args)
+            .await
+            .unwrap();
+        assert!(!has_code(&results, DiagnosticCode::UndefinedGlobal));
+        assert!(!has_code(&results, DiagnosticCode::TypeNotFound));
+    }
+
+    #[tokio::test]
+    async fn test_diagnose_files_returns_analysis() {
+        let mut ws = VirtualWorkspace::new();
+        let file_id = ws.def("local a = b");
+        let (analysis, results) = diagnose_files(ws.analysis, vec![file_id]).await.unwrap();
+        assert!(results.contains_key(&file_id));
+        assert!(analysis.compilation.get_semantic_model(file_id).is_some());
+    }
+
+    #[test]
+    fn test_no_clear_screen_for_machine_output() {
+        for format in ["json", "sarif", "gitlab"] {
+            let cmd_args = CmdArgs::from_iter(["emmylua_check", "--output-format", format, "."]);
+            assert!(!should_clear_screen(&cmd_args));
+        }
+    }
+}
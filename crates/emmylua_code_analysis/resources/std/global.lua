@@ -34,6 +34,7 @@ function assert(v, ...) end
 ---| "incremental" # Change the collector mode to incremental. This option can be followed by three numbers: the garbage-collector pause, the step multiplier, and the step size.
 ---| "generational" # Change the collector mode to generational. This option can be followed by two numbers: the garbage-collector minor multiplier and the major multiplier.
 ---| "isrunning" # returns a boolean that tells whether the collector is running (i.e., not stopped).
+---| "param" # (Lua 5.5) changes or queries a parameter of the collector. This option must be followed by the parameter name and an optional new value. Returns the previous value of the parameter.
 
 ---
 --- This function is a generic interface to the garbage collector. It performs
@@ -62,6 +63,10 @@ function assert(v, ...) end
 --- the major multiplier.
 --- **"isrunning"**: returns a boolean that tells whether the collector is
 --- running (i.e., not stopped).
+--- **"param"**: (Lua 5.5) changes or queries a parameter of the collector.
+--- This option must be followed by the parameter name (`"minormul"`,
+--- `"majorminor"`, `"minormajor"`, `"pause"`, `"stepmul"` or `"stepsize"`) and
+--- an optional new value. Returns the previous value of the parameter.
 ---@param opt? std.collectgarbage_opt
 ---@param ... any
 ---@return any
@@ -69,6 +69,14 @@ fn walk_node_enter(analyzer: &mut DeclAnalyzer, node: LuaAst) {
             analyzer.create_scope(stat.get_range(), LuaScopeKind::FuncStat);
             stats::analyze_local_func_stat(analyzer, stat);
         }
+        LuaAst::LuaGlobalStat(stat) => {
+            analyzer.create_scope(stat.get_range(), LuaScopeKind::LocalOrAssignStat);
+            stats::analyze_global_stat(analyzer, stat);
+        }
+        LuaAst::LuaGlobalFuncStat(stat) => {
+            analyzer.create_scope(stat.get_range(), LuaScopeKind::FuncStat);
+            stats::analyze_global_func_stat(analyzer, stat);
+        }
         LuaAst::LuaRepeatStat(stat) => {
             analyzer.create_scope(stat.get_range(), LuaScopeKind::Repeat);
         }
@@ -130,6 +138,8 @@ fn is_scope_owner(node: &LuaAst) -> bool {
         | LuaSyntaxKind::LocalStat
         | LuaSyntaxKind::FuncStat
         | LuaSyntaxKind::LocalFuncStat
+        | LuaSyntaxKind::GlobalStat
+        | LuaSyntaxKind::GlobalFuncStat
         | LuaSyntaxKind::AssignStat => true,
         _ => false,
     }
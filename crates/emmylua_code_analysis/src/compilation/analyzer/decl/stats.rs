@@ -1,6 +1,7 @@
 use emmylua_parser::{
     LuaAssignStat, LuaAstNode, LuaAstToken, LuaExpr, LuaForRangeStat, LuaForStat, LuaFuncStat,
-    LuaIndexExpr, LuaLocalFuncStat, LuaLocalStat, LuaSyntaxId, LuaSyntaxKind, LuaVarExpr,
+    LuaGlobalFuncStat, LuaGlobalStat, LuaIndexExpr, LuaLocalFuncStat, LuaLocalStat, LuaSyntaxId,
+    LuaSyntaxKind, LuaVarExpr,
 };
 
 use crate::{
@@ -176,6 +177,63 @@ fn analyze_maybe_global_index_expr(
     Some(())
 }
 
+/// `global x` declares `x` explicitly, later assignments in its scope reference this declaration
+pub fn analyze_global_stat(analyzer: &mut DeclAnalyzer, stat: LuaGlobalStat) -> Option<()> {
+    let value_expr_list = stat.get_value_exprs().collect::<Vec<_>>();
+    for (index, global_name) in stat.get_global_name_list().enumerate() {
+        let Some(name_token) = global_name.get_name_token() else {
+            continue;
+        };
+
+        let file_id = analyzer.get_file_id();
+        let expr_id = value_expr_list.get(index).map(|expr| expr.get_syntax_id());
+        let decl = LuaDecl::new(
+            name_token.get_name_text(),
+            file_id,
+            global_name.get_range(),
+            LuaDeclExtra::Global {
+                kind: global_name.syntax().kind(),
+                decl_type: None,
+            },
+            expr_id,
+        );
+        analyzer.add_decl(decl);
+    }
+
+    Some(())
+}
+
+pub fn analyze_global_func_stat(
+    analyzer: &mut DeclAnalyzer,
+    stat: LuaGlobalFuncStat,
+) -> Option<()> {
+    let global_name = stat.get_global_name()?;
+    let name_token = global_name.get_name_token()?;
+    let file_id = analyzer.get_file_id();
+    let decl = LuaDecl::new(
+        name_token.get_name_text(),
+        file_id,
+        global_name.get_range(),
+        LuaDeclExtra::Global {
+            kind: global_name.syntax().kind(),
+            decl_type: None,
+        },
+        None,
+    );
+
+    let decl_id = analyzer.add_decl(decl);
+    let closure = stat.get_closure()?;
+    let closure_owner_id =
+        LuaSemanticDeclId::Signature(LuaSignatureId::from_closure(file_id, &closure));
+    analyzer.db.get_property_index_mut().add_owner_map(
+        LuaSemanticDeclId::LuaDecl(decl_id),
+        closure_owner_id,
+        file_id,
+    );
+
+    Some(())
+}
+
 pub fn analyze_for_stat(analyzer: &mut DeclAnalyzer, stat: LuaForStat) -> Option<()> {
     let it_var = stat.get_var_name()?;
     let name = it_var.get_name_text();
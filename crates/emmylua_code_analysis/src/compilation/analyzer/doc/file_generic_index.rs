@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use rowan::{TextRange, TextSize};
 
-use crate::GenericTplId;
+use crate::{GenericTplId, LuaType};
 
 #[derive(Debug, Clone)]
 pub struct FileGenericIndex {
@@ -23,7 +23,7 @@ impl FileGenericIndex {
     pub fn add_generic_scope(
         &mut self,
         ranges: Vec<TextRange>,
-        params: HashMap<String, usize>,
+        params: HashMap<String, (usize, Option<LuaType>)>,
         is_func: bool,
     ) {
         let params_id = self.generic_params.len();
@@ -87,17 +87,23 @@ impl FileGenericIndex {
         false
     }
 
-    pub fn find_generic(&self, position: TextSize, name: &str) -> Option<GenericTplId> {
+    /// find the generic tpl id and its constraint type
+    pub fn find_generic(
+        &self,
+        position: TextSize,
+        name: &str,
+    ) -> Option<(GenericTplId, Option<LuaType>)> {
         let params_ids = self.find_generic_params(position)?;
 
         for params_id in params_ids.iter().rev() {
             if let Some(params) = self.generic_params.get(*params_id) {
-                if let Some(id) = params.params.get(name) {
-                    if params.is_func {
-                        return Some(GenericTplId::Func(*id as u32));
+                if let Some((id, constraint)) = params.params.get(name) {
+                    let tpl_id = if params.is_func {
+                        GenericTplId::Func(*id as u32)
                     } else {
-                        return Some(GenericTplId::Type(*id as u32));
-                    }
+                        GenericTplId::Type(*id as u32)
+                    };
+                    return Some((tpl_id, constraint.clone()));
                 }
             }
         }
@@ -172,12 +178,12 @@ impl GenericEffectId {
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GenericParams {
-    params: HashMap<String, usize>,
+    params: HashMap<String, (usize, Option<LuaType>)>,
     is_func: bool,
 }
 
 impl GenericParams {
-    pub fn new(params: HashMap<String, usize>, is_func: bool) -> Self {
+    pub fn new(params: HashMap<String, (usize, Option<LuaType>)>, is_func: bool) -> Self {
         Self { params, is_func }
     }
 }
@@ -10,7 +10,7 @@ use rowan::TextRange;
 
 use crate::{
     db_index::{LuaDeclId, LuaMemberId, LuaSemanticDeclId, LuaSignatureId, LuaType},
-    LuaTypeDeclId,
+    LuaGenericVariance, LuaTypeDeclId,
 };
 
 use super::{
@@ -29,11 +29,27 @@ pub fn analyze_class(analyzer: &mut DocAnalyzer, tag: LuaDocTagClass) -> Option<
     let class_decl_id = class_decl.get_id();
     analyzer.current_type_id = Some(class_decl_id.clone());
     if let Some(generic_params) = tag.get_generic_decl() {
+        let variances = generic_params
+            .get_generic_decl()
+            .map(|param| {
+                if param.is_covariant() {
+                    LuaGenericVariance::Covariant
+                } else if param.is_contravariant() {
+                    LuaGenericVariance::Contravariant
+                } else {
+                    LuaGenericVariance::Invariant
+                }
+            })
+            .collect();
+        analyzer
+            .db
+            .get_type_index_mut()
+            .add_generic_variances(class_decl_id.clone(), variances);
         let params = get_generic_params(analyzer, generic_params);
         let mut params_index = HashMap::new();
         let mut count = 0;
-        for (name, _) in params.iter() {
-            params_index.insert(name.clone(), count);
+        for (name, constraint) in params.iter() {
+            params_index.insert(name.clone(), (count, constraint.clone()));
             count += 1;
         }
 
@@ -157,8 +173,8 @@ pub fn analyze_alias(analyzer: &mut DocAnalyzer, tag: LuaDocTagAlias) -> Option<
         let params = get_generic_params(analyzer, generic_params);
         let mut params_index = HashMap::new();
         let mut count = 0;
-        for (name, _) in params.iter() {
-            params_index.insert(name.clone(), count);
+        for (name, constraint) in params.iter() {
+            params_index.insert(name.clone(), (count, constraint.clone()));
             count += 1;
         }
 
@@ -211,7 +227,10 @@ fn get_generic_params(
     params_result
 }
 
-fn add_generic_index(analyzer: &mut DocAnalyzer, params_index: HashMap<String, usize>) {
+fn add_generic_index(
+    analyzer: &mut DocAnalyzer,
+    params_index: HashMap<String, (usize, Option<LuaType>)>,
+) {
     let mut ranges = Vec::new();
     let range = analyzer.comment.get_range();
     ranges.push(range);
@@ -343,7 +362,7 @@ pub fn analyze_func_generic(analyzer: &mut DocAnalyzer, tag: LuaDocTagGeneric) -
                 None
             };
 
-            params_result.insert(name.clone(), count);
+            params_result.insert(name.clone(), (count, type_ref.clone()));
             param_info.push((name, type_ref));
             count += 1;
         }
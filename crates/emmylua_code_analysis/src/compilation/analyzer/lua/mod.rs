@@ -13,8 +13,8 @@ pub use func_body::LuaReturnPoint;
 use metatable::analyze_setmetatable;
 use module::analyze_chunk_return;
 use stats::{
-    analyze_assign_stat, analyze_for_range_stat, analyze_func_stat, analyze_local_func_stat,
-    analyze_local_stat, analyze_table_field,
+    analyze_assign_stat, analyze_for_range_stat, analyze_func_stat, analyze_global_func_stat,
+    analyze_global_stat, analyze_local_func_stat, analyze_local_stat, analyze_table_field,
 };
 
 use crate::{
@@ -75,6 +75,12 @@ fn analyze_node(analyzer: &mut LuaAnalyzer, node: LuaAst) {
         LuaAst::LuaLocalFuncStat(local_func_stat) => {
             analyze_local_func_stat(analyzer, local_func_stat);
         }
+        LuaAst::LuaGlobalStat(global_stat) => {
+            analyze_global_stat(analyzer, global_stat);
+        }
+        LuaAst::LuaGlobalFuncStat(global_func_stat) => {
+            analyze_global_func_stat(analyzer, global_func_stat);
+        }
         LuaAst::LuaTableField(field) => {
             analyze_table_field(analyzer, field);
         }
@@ -1,6 +1,7 @@
 use emmylua_parser::{
     BinaryOperator, LuaAssignStat, LuaAstNode, LuaAstToken, LuaExpr, LuaForRangeStat, LuaFuncStat,
-    LuaIndexExpr, LuaLocalFuncStat, LuaLocalStat, LuaTableField, LuaVarExpr, PathTrait,
+    LuaGlobalFuncStat, LuaGlobalStat, LuaIndexExpr, LuaLocalFuncStat, LuaLocalStat, LuaTableField,
+    LuaVarExpr, PathTrait,
 };
 
 use crate::{
@@ -206,7 +207,23 @@ fn set_index_expr_owner(analyzer: &mut LuaAnalyzer, var_expr: LuaVarExpr) -> Opt
             let member_id = LuaMemberId::new(index_expr.get_syntax_id(), file_id);
             let member_owner = match prefix_type {
                 LuaType::TableConst(in_file_range) => LuaMemberOwner::Element(in_file_range),
-                LuaType::Def(def_id) => LuaMemberOwner::Type(def_id),
+                LuaType::Def(def_id) => {
+                    let is_closed = analyzer
+                        .db
+                        .get_type_index()
+                        .get_type_decl(&def_id)
+                        .is_some_and(|decl| decl.is_exact_outside_file(file_id));
+                    let member_owner = LuaMemberOwner::Type(def_id);
+                    if is_closed {
+                        analyzer.db.get_member_index_mut().set_member_owner(
+                            member_owner,
+                            member_id.file_id,
+                            member_id,
+                        );
+                        return Some(());
+                    }
+                    member_owner
+                }
                 LuaType::Instance(instance) => {
                     LuaMemberOwner::Element(instance.get_range().clone())
                 }
@@ -577,6 +594,56 @@ pub fn analyze_local_func_stat(
     Some(())
 }
 
+/// The names declared without a value keep the type of their other definitions
+pub fn analyze_global_stat(analyzer: &mut LuaAnalyzer, global_stat: LuaGlobalStat) -> Option<()> {
+    let name_list: Vec<_> = global_stat.get_global_name_list().collect();
+    let expr_list: Vec<_> = global_stat.get_value_exprs().collect();
+    for (name, expr) in name_list.iter().zip(expr_list.iter()) {
+        let decl_id = LuaDeclId::new(analyzer.file_id, name.get_position());
+        match analyzer.infer_expr(expr) {
+            Ok(expr_type) => {
+                let expr_type = match expr_type {
+                    LuaType::MuliReturn(multi) => multi.get_type(0)?.clone(),
+                    _ => expr_type,
+                };
+                merge_decl_expr_type(analyzer.db, &mut analyzer.infer_cache, decl_id, expr_type);
+            }
+            Err(InferFailReason::None) => {
+                let decl = analyzer.db.get_decl_index_mut().get_decl_mut(&decl_id)?;
+                if decl.get_type().is_none() {
+                    decl.set_decl_type(LuaType::Unknown);
+                }
+            }
+            Err(reason) => {
+                let unresolve = UnResolveDecl {
+                    file_id: analyzer.file_id,
+                    decl_id,
+                    expr: expr.clone(),
+                    ret_idx: 0,
+                    reason,
+                };
+                analyzer.add_unresolved(unresolve.into());
+            }
+        }
+    }
+
+    Some(())
+}
+
+pub fn analyze_global_func_stat(
+    analyzer: &mut LuaAnalyzer,
+    global_func_stat: LuaGlobalFuncStat,
+) -> Option<()> {
+    let closure = global_func_stat.get_closure()?;
+    let func_name = global_func_stat.get_global_name()?;
+    let signature_type = analyzer.infer_expr(&closure.clone().into()).ok()?;
+    let decl_id = LuaDeclId::new(analyzer.file_id, func_name.get_position());
+    let decl = analyzer.db.get_decl_index_mut().get_decl_mut(&decl_id)?;
+    decl.set_decl_type(signature_type);
+
+    Some(())
+}
+
 pub fn analyze_table_field(analyzer: &mut LuaAnalyzer, field: LuaTableField) -> Option<()> {
     let _ = field.get_field_key()?;
     let value_expr = field.get_value_expr()?;
@@ -2,6 +2,7 @@ mod decl;
 mod doc;
 mod flow;
 mod lua;
+mod symbol;
 mod unresolve;
 
 use std::{collections::HashMap, sync::Arc};
@@ -25,6 +26,7 @@ pub fn analyze(db: &mut DbIndex, need_analyzed_files: Vec<InFiled<LuaChunk>>, co
         flow::analyze(db, &mut context);
         lua::analyze(db, &mut context);
         unresolve::analyze(db, &mut context);
+        symbol::analyze(db, &mut context);
     }
 }
 
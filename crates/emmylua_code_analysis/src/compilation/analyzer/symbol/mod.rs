@@ -0,0 +1,263 @@
+use std::collections::HashSet;
+
+use emmylua_parser::{
+    LuaAst, LuaAstNode, LuaBlock, LuaChunk, LuaDocTagField, LuaExpr, LuaFuncStat, LuaIndexExpr,
+    LuaLocalFuncStat, LuaLocalStat, LuaVarExpr, PathTrait,
+};
+
+use crate::{
+    db_index::DbIndex, profile::Profile, FileId, LuaDeclId, LuaMemberId, LuaMemberOwner,
+    LuaSemanticDeclId, LuaSymbol, LuaSymbolKind,
+};
+
+use super::AnalyzeContext;
+
+/// Collect the workspace symbols of the files, it runs last so the member owners are resolved
+pub(crate) fn analyze(db: &mut DbIndex, context: &mut AnalyzeContext) {
+    let _p = Profile::cond_new("symbol analyze", context.tree_list.len() > 1);
+    let file_ids = context
+        .tree_list
+        .iter()
+        .map(|in_filed_tree| in_filed_tree.file_id)
+        .collect::<HashSet<_>>();
+
+    let mut symbols = Vec::new();
+    collect_type_symbols(db, &file_ids, &mut symbols);
+    for in_filed_tree in &context.tree_list {
+        let mut analyzer = SymbolAnalyzer {
+            db,
+            file_id: in_filed_tree.file_id,
+            symbols: &mut symbols,
+            function_decls: HashSet::new(),
+        };
+        analyzer.analyze(in_filed_tree.value.clone());
+    }
+
+    let symbol_index = db.get_symbol_index_mut();
+    for symbol in symbols {
+        symbol_index.add_symbol(symbol);
+    }
+}
+
+fn collect_type_symbols(db: &DbIndex, file_ids: &HashSet<FileId>, symbols: &mut Vec<LuaSymbol>) {
+    for type_decl in db.get_type_index().get_all_types() {
+        let kind = if type_decl.is_enum() {
+            LuaSymbolKind::Enum
+        } else if type_decl.is_alias() {
+            LuaSymbolKind::Alias
+        } else {
+            LuaSymbolKind::Class
+        };
+        for location in type_decl.get_locations() {
+            if !file_ids.contains(&location.file_id) {
+                continue;
+            }
+            symbols.push(LuaSymbol::new(
+                type_decl.get_name(),
+                type_decl.get_namespace(),
+                kind,
+                LuaSemanticDeclId::TypeDecl(type_decl.get_id()),
+                location.file_id,
+                location.range,
+            ));
+        }
+    }
+}
+
+struct SymbolAnalyzer<'a> {
+    db: &'a DbIndex,
+    file_id: FileId,
+    symbols: &'a mut Vec<LuaSymbol>,
+    function_decls: HashSet<LuaDeclId>,
+}
+
+impl SymbolAnalyzer<'_> {
+    fn analyze(&mut self, root: LuaChunk) {
+        for node in root.descendants::<LuaAst>() {
+            match node {
+                LuaAst::LuaFuncStat(stat) => {
+                    self.analyze_func_stat(stat);
+                }
+                LuaAst::LuaLocalFuncStat(stat) => {
+                    self.analyze_local_func_stat(stat);
+                }
+                LuaAst::LuaLocalStat(stat) => {
+                    self.analyze_local_stat(stat);
+                }
+                LuaAst::LuaDocTagField(field) => {
+                    self.analyze_doc_field(field);
+                }
+                _ => {}
+            }
+        }
+
+        self.analyze_global_variables();
+    }
+
+    fn analyze_func_stat(&mut self, stat: LuaFuncStat) -> Option<()> {
+        match stat.get_func_name()? {
+            LuaVarExpr::NameExpr(name_expr) => {
+                let decl_id = LuaDeclId::new(self.file_id, name_expr.get_position());
+                let decl = self.db.get_decl_index().get_decl(&decl_id)?;
+                // `function foo() end` of a declared local is not a new symbol
+                if !decl.is_global() {
+                    return None;
+                }
+                self.function_decls.insert(decl_id);
+                self.add_symbol(
+                    decl.get_name(),
+                    None,
+                    LuaSymbolKind::Function,
+                    LuaSemanticDeclId::LuaDecl(decl_id),
+                    decl.get_range(),
+                );
+            }
+            LuaVarExpr::IndexExpr(index_expr) => {
+                let kind = if index_expr.get_index_token()?.is_colon() {
+                    LuaSymbolKind::Method
+                } else {
+                    LuaSymbolKind::Function
+                };
+                let member_id = LuaMemberId::new(index_expr.get_syntax_id(), self.file_id);
+                let name = index_expr.get_index_key()?.get_path_part();
+                let container_name = self.get_member_container_name(member_id, &index_expr);
+                self.add_symbol(
+                    &name,
+                    container_name.as_deref(),
+                    kind,
+                    LuaSemanticDeclId::Member(member_id),
+                    index_expr.get_range(),
+                );
+            }
+        }
+
+        Some(())
+    }
+
+    fn analyze_local_func_stat(&mut self, stat: LuaLocalFuncStat) -> Option<()> {
+        if !is_module_level(stat.get_parent::<LuaBlock>()?) {
+            return None;
+        }
+
+        let local_name = stat.get_local_name()?;
+        let name = local_name.get_name_token()?.get_name_text().to_string();
+        let decl_id = LuaDeclId::new(self.file_id, local_name.get_position());
+        let module_name = self.get_module_name();
+        self.add_symbol(
+            &name,
+            module_name.as_deref(),
+            LuaSymbolKind::Function,
+            LuaSemanticDeclId::LuaDecl(decl_id),
+            local_name.get_range(),
+        );
+        Some(())
+    }
+
+    // `local foo = function() end` at the module level
+    fn analyze_local_stat(&mut self, stat: LuaLocalStat) -> Option<()> {
+        if !is_module_level(stat.get_parent::<LuaBlock>()?) {
+            return None;
+        }
+
+        let module_name = self.get_module_name();
+        for (local_name, value) in stat.get_local_name_list().zip(stat.get_value_exprs()) {
+            if !matches!(value, LuaExpr::ClosureExpr(_)) {
+                continue;
+            }
+            let Some(name_token) = local_name.get_name_token() else {
+                continue;
+            };
+            let decl_id = LuaDeclId::new(self.file_id, local_name.get_position());
+            self.add_symbol(
+                name_token.get_name_text(),
+                module_name.as_deref(),
+                LuaSymbolKind::Function,
+                LuaSemanticDeclId::LuaDecl(decl_id),
+                local_name.get_range(),
+            );
+        }
+        Some(())
+    }
+
+    fn analyze_doc_field(&mut self, field: LuaDocTagField) -> Option<()> {
+        let member_id = LuaMemberId::new(field.get_syntax_id(), self.file_id);
+        let member = self.db.get_member_index().get_member(&member_id)?;
+        let name = member.get_key().get_name()?.to_string();
+        let LuaMemberOwner::Type(type_id) = member.get_owner() else {
+            return None;
+        };
+        self.add_symbol(
+            &name,
+            Some(type_id.get_name()),
+            LuaSymbolKind::Field,
+            LuaSemanticDeclId::Member(member_id),
+            field.get_range(),
+        );
+        Some(())
+    }
+
+    fn analyze_global_variables(&mut self) -> Option<()> {
+        let decl_tree = self.db.get_decl_index().get_decl_tree(&self.file_id)?;
+        let mut globals = decl_tree
+            .get_decls()
+            .values()
+            .filter(|decl| decl.is_global() && !self.function_decls.contains(&decl.get_id()))
+            .map(|decl| (decl.get_name().to_string(), decl.get_id(), decl.get_range()))
+            .collect::<Vec<_>>();
+        globals.sort_by_key(|(_, _, range)| range.start());
+        for (name, decl_id, range) in globals {
+            self.add_symbol(
+                &name,
+                None,
+                LuaSymbolKind::Variable,
+                LuaSemanticDeclId::LuaDecl(decl_id),
+                range,
+            );
+        }
+        Some(())
+    }
+
+    fn get_member_container_name(
+        &self,
+        member_id: LuaMemberId,
+        index_expr: &LuaIndexExpr,
+    ) -> Option<String> {
+        let member = self.db.get_member_index().get_member(&member_id);
+        if let Some(LuaMemberOwner::Type(type_id)) = member.map(|member| member.get_owner()) {
+            return Some(type_id.get_name().to_string());
+        }
+
+        match index_expr.get_prefix_expr()? {
+            LuaExpr::NameExpr(name_expr) => name_expr.get_name_text(),
+            LuaExpr::IndexExpr(prefix_expr) => prefix_expr.get_access_path(),
+            _ => None,
+        }
+    }
+
+    fn get_module_name(&self) -> Option<String> {
+        let module_info = self.db.get_module_index().get_module(self.file_id)?;
+        Some(module_info.full_module_name.clone())
+    }
+
+    fn add_symbol(
+        &mut self,
+        name: &str,
+        container_name: Option<&str>,
+        kind: LuaSymbolKind,
+        semantic_decl: LuaSemanticDeclId,
+        range: rowan::TextRange,
+    ) {
+        self.symbols.push(LuaSymbol::new(
+            name,
+            container_name,
+            kind,
+            semantic_decl,
+            self.file_id,
+            range,
+        ));
+    }
+}
+
+fn is_module_level(block: LuaBlock) -> bool {
+    block.get_parent::<LuaChunk>().is_some()
+}
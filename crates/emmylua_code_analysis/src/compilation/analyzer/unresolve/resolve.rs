@@ -61,14 +61,13 @@ pub fn try_resolve_member(
                 return None;
             }
         };
+        let mut is_closed_owner = false;
         let member_owner = match prefix_type {
             LuaType::TableConst(in_file_range) => LuaMemberOwner::Element(in_file_range),
             LuaType::Def(def_id) => {
                 let type_decl = db.get_type_index().get_type_decl(&def_id)?;
-                // if is exact type, no need to extend field
-                if type_decl.is_exact() {
-                    return None;
-                }
+                // exact type can only be extended in the file declaring it
+                is_closed_owner = type_decl.is_exact_outside_file(cache.get_file_id());
                 LuaMemberOwner::Type(def_id)
             }
             LuaType::Instance(instance) => LuaMemberOwner::Element(instance.get_range().clone()),
@@ -83,8 +82,10 @@ pub fn try_resolve_member(
             cache.get_file_id(),
             member_id,
         );
-        db.get_member_index_mut()
-            .add_member_to_owner(member_owner, member_id);
+        if !is_closed_owner {
+            db.get_member_index_mut()
+                .add_member_to_owner(member_owner, member_id);
+        }
         unresolve_member.prefix = None;
     }
 
@@ -361,4 +361,25 @@ end
         let a_desc = ws.humanize_type(a);
         assert_eq!(a_desc, "integer");
     }
+
+    #[test]
+    fn test_nullable_alias() {
+        let mut ws = VirtualWorkspace::new();
+
+        ws.def(
+            r#"
+            ---@alias MaybeId integer?
+
+            ---@type MaybeId
+            local id
+            if id then
+                a = id
+            end
+        "#,
+        );
+
+        let a = ws.expr_ty("a");
+        let a_desc = ws.humanize_type(a);
+        assert_eq!(a_desc, "integer");
+    }
 }
@@ -1,6 +1,8 @@
 #[cfg(test)]
 mod test {
-    use crate::{DiagnosticCode, VirtualWorkspace};
+    use emmylua_parser::LuaCallExpr;
+
+    use crate::{DiagnosticCode, LuaType, VirtualWorkspace};
 
     #[test]
     fn test_table() {
@@ -25,4 +27,35 @@ mod test {
         "#
         ));
     }
+
+    #[test]
+    fn test_resolve_call_overload() {
+        let mut ws = VirtualWorkspace::new();
+
+        let file_id = ws.def(
+            r#"
+        ---@overload fun(name: string, cb: fun())
+        ---@overload fun(id: integer, force: boolean)
+        function find(key, opts) end
+
+        find(1, true)
+        "#,
+        );
+        let semantic_model = ws.analysis.compilation.get_semantic_model(file_id).unwrap();
+        let call_expr = ws.get_node::<LuaCallExpr>(file_id);
+        let signature_id = match semantic_model
+            .infer_expr(call_expr.get_prefix_expr().unwrap())
+            .unwrap()
+        {
+            LuaType::Signature(signature_id) => signature_id,
+            _ => panic!("expected a signature"),
+        };
+        let signature = semantic_model
+            .get_db()
+            .get_signature_index()
+            .get(&signature_id)
+            .unwrap();
+        let idx = semantic_model.resolve_call_overload(&signature.overloads, call_expr, None);
+        assert_eq!(idx, Some(1));
+    }
 }
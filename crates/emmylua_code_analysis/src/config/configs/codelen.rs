@@ -7,12 +7,32 @@ pub struct EmmyrcCodeLen {
     /// Whether to enable code lens.
     #[serde(default = "default_true")]
     pub enable: bool,
+    /// Whether to show the implementations of base class and interface methods.
+    #[serde(default = "default_true")]
+    pub implementation: bool,
+    /// Whether to show the base method overridden by a method.
+    #[serde(default = "default_true")]
+    pub overrides: bool,
+    /// Whether to show the subclasses of a class.
+    #[serde(default = "default_true")]
+    pub subclass: bool,
+    /// Whether to show a `Run test` lens on test functions, which emits the `emmy.runTest` command.
+    #[serde(default)]
+    pub run_test: bool,
+    /// The functions declaring tests, like busted's `describe` and `it`.
+    #[serde(default = "default_test_functions")]
+    pub test_functions: Vec<String>,
 }
 
 impl Default for EmmyrcCodeLen {
     fn default() -> Self {
         Self {
             enable: default_true(),
+            implementation: default_true(),
+            overrides: default_true(),
+            subclass: default_true(),
+            run_test: false,
+            test_functions: default_test_functions(),
         }
     }
 }
@@ -20,3 +40,7 @@ impl Default for EmmyrcCodeLen {
 fn default_true() -> bool {
     true
 }
+
+fn default_test_functions() -> Vec<String> {
+    vec!["describe".to_string(), "it".to_string()]
+}
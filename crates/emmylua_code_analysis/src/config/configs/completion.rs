@@ -26,6 +26,12 @@ pub struct EmmyrcCompletion {
     /// The postfix trigger used in completions.
     #[serde(default = "default_postfix")]
     pub postfix: String,
+    /// User defined postfix templates, offered after the built-in ones.
+    #[serde(default)]
+    pub postfix_templates: Vec<EmmyrcPostfixTemplate>,
+    /// User defined snippets, offered where a statement or an expression is expected.
+    #[serde(default)]
+    pub snippets: Vec<EmmyrcSnippet>,
 }
 
 impl Default for EmmyrcCompletion {
@@ -37,6 +43,8 @@ impl Default for EmmyrcCompletion {
             auto_require_naming_convention: Default::default(),
             call_snippet: false,
             postfix: default_postfix(),
+            postfix_templates: Vec::new(),
+            snippets: Vec::new(),
         }
     }
 }
@@ -53,6 +61,56 @@ fn default_postfix() -> String {
     "@".to_string()
 }
 
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone)]
+#[serde(rename_all = "camelCase")]
+/// A postfix template, e.g. `{ "trigger": "log", "body": "Log.debug($expr)" }`.
+pub struct EmmyrcPostfixTemplate {
+    /// The label typed after the postfix trigger.
+    pub trigger: String,
+    /// The snippet body, `$expr` is replaced by the expression before the trigger.
+    pub body: String,
+    /// The description shown in the completion item.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Only offer the template when the expression has this type.
+    #[serde(default)]
+    pub expr_type: Option<EmmyrcPostfixExprType>,
+}
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum EmmyrcPostfixExprType {
+    /// An array or a tuple.
+    Array,
+    /// Any table, including arrays and classes.
+    Table,
+    /// A string.
+    String,
+    /// A number or an integer.
+    Number,
+    /// An integer.
+    Integer,
+    /// A boolean.
+    Boolean,
+    /// A function.
+    Function,
+    /// A value which may be nil.
+    Nullable,
+}
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone)]
+#[serde(rename_all = "camelCase")]
+/// A snippet, e.g. `{ "trigger": "pcall", "body": "local ok, err = pcall($0)" }`.
+pub struct EmmyrcSnippet {
+    /// The label of the snippet.
+    pub trigger: String,
+    /// The snippet body, in the LSP snippet syntax.
+    pub body: String,
+    /// The description shown in the completion item.
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
 #[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, Copy)]
 #[serde(rename_all = "kebab-case")]
 pub enum EmmyrcFilenameConvention {
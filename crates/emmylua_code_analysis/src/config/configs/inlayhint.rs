@@ -20,6 +20,18 @@ pub struct EmmyrcInlayHint {
     /// Whether to enable override hints.
     #[serde(default = "default_true")]
     pub override_hint: bool,
+    /// Whether to enable inferred return type hints for functions without `@return`.
+    #[serde(default = "default_false")]
+    pub return_hint: bool,
+    /// Whether to enable type hints at the end of each line of a method chain.
+    #[serde(default = "default_false")]
+    pub chain_hint: bool,
+    /// Whether to enable type hints for the variables of a `for ... in` loop.
+    #[serde(default = "default_false")]
+    pub for_var_hint: bool,
+    /// Whether to enable value hints for enum members.
+    #[serde(default = "default_false")]
+    pub enum_hint: bool,
 }
 
 impl Default for EmmyrcInlayHint {
@@ -30,6 +42,10 @@ impl Default for EmmyrcInlayHint {
             index_hint: default_true(),
             local_hint: default_true(),
             override_hint: default_true(),
+            return_hint: default_false(),
+            chain_hint: default_false(),
+            for_var_hint: default_false(),
+            enum_hint: default_false(),
         }
     }
 }
@@ -37,3 +53,7 @@ impl Default for EmmyrcInlayHint {
 fn default_true() -> bool {
     true
 }
+
+fn default_false() -> bool {
+    false
+}
@@ -13,8 +13,11 @@ mod strict;
 mod workspace;
 
 pub use codelen::EmmyrcCodeLen;
-pub use completion::{EmmyrcCompletion, EmmyrcFilenameConvention};
-pub use diagnostics::EmmyrcDiagnostic;
+pub use completion::{
+    EmmyrcCompletion, EmmyrcFilenameConvention, EmmyrcPostfixExprType, EmmyrcPostfixTemplate,
+    EmmyrcSnippet,
+};
+pub use diagnostics::{DiagnosticSeveritySetting, EmmyrcDiagnostic};
 pub use document_color::EmmyrcDocumentColor;
 pub use hover::EmmyrcHover;
 pub use inlayhint::EmmyrcInlayHint;
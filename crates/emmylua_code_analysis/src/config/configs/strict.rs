@@ -9,6 +9,10 @@ pub struct EmmyrcStrict {
     pub require_path: bool,
     #[serde(default)]
     pub type_call: bool,
+    /// Whether nullable values must be narrowed before being passed to non-nullable params,
+    /// returned from non-nullable returns, assigned to non-nullable fields or indexed.
+    #[serde(default = "default_false")]
+    pub nil_safety: bool,
 }
 
 impl Default for EmmyrcStrict {
@@ -16,6 +20,7 @@ impl Default for EmmyrcStrict {
         Self {
             require_path: false,
             type_call: false,
+            nil_safety: false,
         }
     }
 }
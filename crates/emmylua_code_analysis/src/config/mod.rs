@@ -10,7 +10,9 @@ use std::{
 pub use config_loader::load_configs;
 use configs::EmmyrcDocumentColor;
 pub use configs::EmmyrcFilenameConvention;
+pub use configs::DiagnosticSeveritySetting;
 pub use configs::EmmyrcLuaVersion;
+pub use configs::{EmmyrcPostfixExprType, EmmyrcPostfixTemplate, EmmyrcSnippet};
 use configs::{
     EmmyrcCodeLen, EmmyrcCompletion, EmmyrcDiagnostic, EmmyrcHover, EmmyrcInlayHint,
     EmmyrcReference, EmmyrcResource, EmmyrcRuntime, EmmyrcSemanticToken, EmmyrcSignature,
@@ -57,20 +59,23 @@ pub struct Emmyrc {
 }
 
 impl Emmyrc {
-    pub fn get_parse_config<'cache>(
-        &self,
-        node_cache: &'cache mut NodeCache,
-    ) -> ParserConfig<'cache> {
-        let level = &self.runtime.version;
-
-        let lua_language_level = match level {
+    pub fn get_language_level(&self) -> LuaLanguageLevel {
+        match &self.runtime.version {
             EmmyrcLuaVersion::Lua51 => LuaLanguageLevel::Lua51,
             EmmyrcLuaVersion::Lua52 => LuaLanguageLevel::Lua52,
             EmmyrcLuaVersion::Lua53 => LuaLanguageLevel::Lua53,
             EmmyrcLuaVersion::Lua54 => LuaLanguageLevel::Lua54,
+            EmmyrcLuaVersion::Lua55 => LuaLanguageLevel::Lua55,
             EmmyrcLuaVersion::LuaJIT => LuaLanguageLevel::LuaJIT,
             EmmyrcLuaVersion::LuaLatest => LuaLanguageLevel::Lua54,
-        };
+        }
+    }
+
+    pub fn get_parse_config<'cache>(
+        &self,
+        node_cache: &'cache mut NodeCache,
+    ) -> ParserConfig<'cache> {
+        let lua_language_level = self.get_language_level();
 
         let mut special_like = HashMap::new();
         for name in self.runtime.require_like_function.iter() {
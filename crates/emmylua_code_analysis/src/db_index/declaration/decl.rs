@@ -116,6 +116,14 @@ impl LuaDecl {
     pub fn is_global(&self) -> bool {
         matches!(&self.extra, LuaDeclExtra::Global { .. })
     }
+
+    /// declared by a lua 5.5 `global` statement
+    pub fn is_explicit_global(&self) -> bool {
+        matches!(
+            &self.extra,
+            LuaDeclExtra::Global { kind, .. } if *kind == LuaSyntaxKind::GlobalName.into()
+        )
+    }
 }
 
 #[derive(Eq, PartialEq, Hash, Debug, Clone, Copy)]
@@ -10,6 +10,7 @@ mod property;
 mod reference;
 mod semantic_decl;
 mod signature;
+mod symbol;
 mod traits;
 mod r#type;
 
@@ -31,11 +32,12 @@ pub use operators::{
     LuaOperator, LuaOperatorId, LuaOperatorIndex, LuaOperatorMetaMethod, LuaOperatorOwner,
     OperatorFunction,
 };
-pub use property::{LuaPropertyId, LuaPropertyIndex};
+pub use property::{LuaDeclProperty, LuaPropertyId, LuaPropertyIndex};
 pub use r#type::*;
 pub use reference::{DeclReference, LuaReferenceIndex};
 pub use semantic_decl::LuaSemanticDeclId;
 pub use signature::*;
+pub use symbol::{fuzzy_match, LuaSymbol, LuaSymbolIndex, LuaSymbolKind};
 pub use traits::LuaIndex;
 
 #[derive(Debug)]
@@ -53,6 +55,7 @@ pub struct DbIndex {
     vfs: Vfs,
     file_dependencies_index: LuaDenpendencyIndex,
     metatable_index: LuaMetatableIndex,
+    symbol_index: LuaSymbolIndex,
     emmyrc: Arc<Emmyrc>,
 }
 
@@ -73,6 +76,7 @@ impl DbIndex {
             vfs: Vfs::new(),
             file_dependencies_index: LuaDenpendencyIndex::new(),
             metatable_index: LuaMetatableIndex::new(),
+            symbol_index: LuaSymbolIndex::new(),
             emmyrc: Arc::new(Emmyrc::default()),
         }
     }
@@ -91,6 +95,14 @@ impl DbIndex {
         &self.metatable_index
     }
 
+    pub fn get_symbol_index_mut(&mut self) -> &mut LuaSymbolIndex {
+        &mut self.symbol_index
+    }
+
+    pub fn get_symbol_index(&self) -> &LuaSymbolIndex {
+        &self.symbol_index
+    }
+
     pub fn get_decl_index_mut(&mut self) -> &mut LuaDeclIndex {
         &mut self.decl_index
     }
@@ -212,6 +224,7 @@ impl LuaIndex for DbIndex {
         self.flow_index.remove(file_id);
         self.file_dependencies_index.remove(file_id);
         self.metatable_index.remove(file_id);
+        self.symbol_index.remove(file_id);
     }
 
     fn clear(&mut self) {
@@ -227,5 +240,6 @@ impl LuaIndex for DbIndex {
         self.flow_index.clear();
         self.file_dependencies_index.clear();
         self.metatable_index.clear();
+        self.symbol_index.clear();
     }
 }
@@ -3,8 +3,7 @@ mod property;
 use std::collections::{HashMap, HashSet};
 
 use emmylua_parser::{LuaVersionCondition, VisibilityKind};
-use property::LuaDeclProperty;
-pub use property::LuaPropertyId;
+pub use property::{LuaDeclProperty, LuaPropertyId};
 
 use crate::FileId;
 
@@ -0,0 +1,82 @@
+const MATCH_SCORE: i32 = 16;
+const EXACT_CASE_BONUS: i32 = 1;
+const CONSECUTIVE_BONUS: i32 = 8;
+const START_BONUS: i32 = 10;
+const WORD_START_BONUS: i32 = 8;
+const GAP_PENALTY: i32 = 1;
+const MAX_LEADING_PENALTY: i32 = 8;
+const EXACT_MATCH_BONUS: i32 = 100;
+
+/// Match the query as a case insensitive subsequence of the target, returns the score of the best
+/// alignment. Matches at the start of the target, at word starts after `.`, `:`, `_` and at camel
+/// humps, and consecutive matches score higher, so `td` and `takeDmg` both match `takeDamage`.
+pub fn fuzzy_match(query: &str, target: &str) -> Option<i32> {
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let query: Vec<char> = query.chars().collect();
+    let target: Vec<char> = target.chars().collect();
+    if query.len() > target.len() {
+        return None;
+    }
+
+    let bonuses: Vec<i32> = (0..target.len())
+        .map(|idx| boundary_bonus(&target, idx))
+        .collect();
+    // best score when the previous query char is matched at target[idx]
+    let mut prev_scores: Vec<Option<i32>> = vec![None; target.len()];
+    for (query_idx, query_char) in query.iter().enumerate() {
+        let mut scores = vec![None; target.len()];
+        // best score of a previous match before idx, minus the gap penalty
+        let mut best_before: Option<i32> = None;
+        for (idx, target_char) in target.iter().enumerate() {
+            if query_char.to_lowercase().eq(target_char.to_lowercase()) {
+                let from = if query_idx == 0 {
+                    Some(-(idx as i32).min(MAX_LEADING_PENALTY))
+                } else {
+                    let consecutive = if idx > 0 {
+                        prev_scores[idx - 1].map(|score| score + CONSECUTIVE_BONUS)
+                    } else {
+                        None
+                    };
+                    consecutive.max(best_before)
+                };
+                let mut score = MATCH_SCORE + bonuses[idx];
+                if query_char == target_char {
+                    score += EXACT_CASE_BONUS;
+                }
+                scores[idx] = from.map(|from| from + score);
+            }
+
+            if query_idx > 0 {
+                best_before = best_before
+                    .map(|score| score - GAP_PENALTY)
+                    .max(prev_scores[idx]);
+            }
+        }
+        prev_scores = scores;
+    }
+
+    let score = prev_scores.into_iter().flatten().max()?;
+    if query.len() == target.len() {
+        return Some(score + EXACT_MATCH_BONUS);
+    }
+    Some(score)
+}
+
+fn boundary_bonus(target: &[char], idx: usize) -> i32 {
+    if idx == 0 {
+        return START_BONUS;
+    }
+
+    let prev = target[idx - 1];
+    let cur = target[idx];
+    let is_word_start = matches!(prev, '.' | ':' | '_' | '-' | '/' | ' ')
+        || (cur.is_uppercase() && (prev.is_lowercase() || prev.is_ascii_digit()));
+    if is_word_start {
+        WORD_START_BONUS
+    } else {
+        0
+    }
+}
@@ -0,0 +1,155 @@
+mod fuzzy_match;
+mod test;
+
+use std::collections::HashMap;
+
+pub use fuzzy_match::fuzzy_match;
+use rowan::TextRange;
+use smol_str::SmolStr;
+
+use crate::{FileId, LuaSemanticDeclId};
+
+use super::traits::LuaIndex;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LuaSymbolKind {
+    Class,
+    Enum,
+    Alias,
+    Function,
+    Method,
+    Field,
+    Variable,
+}
+
+/// A named declaration which can be searched across the workspace
+#[derive(Debug, Clone)]
+pub struct LuaSymbol {
+    name: SmolStr,
+    container_name: Option<SmolStr>,
+    full_name: SmolStr,
+    kind: LuaSymbolKind,
+    semantic_decl: LuaSemanticDeclId,
+    file_id: FileId,
+    range: TextRange,
+}
+
+impl LuaSymbol {
+    pub fn new(
+        name: &str,
+        container_name: Option<&str>,
+        kind: LuaSymbolKind,
+        semantic_decl: LuaSemanticDeclId,
+        file_id: FileId,
+        range: TextRange,
+    ) -> Self {
+        let full_name = match container_name {
+            Some(container_name) => {
+                let separator = if kind == LuaSymbolKind::Method {
+                    ":"
+                } else {
+                    "."
+                };
+                SmolStr::new(format!("{}{}{}", container_name, separator, name))
+            }
+            None => SmolStr::new(name),
+        };
+        Self {
+            name: SmolStr::new(name),
+            container_name: container_name.map(SmolStr::new),
+            full_name,
+            kind,
+            semantic_decl,
+            file_id,
+            range,
+        }
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_container_name(&self) -> Option<&str> {
+        self.container_name.as_deref()
+    }
+
+    /// The name qualified by its container, eg: `Player.takeDamage` or `Player:heal`
+    pub fn get_full_name(&self) -> &str {
+        &self.full_name
+    }
+
+    pub fn get_kind(&self) -> LuaSymbolKind {
+        self.kind
+    }
+
+    pub fn get_semantic_decl(&self) -> &LuaSemanticDeclId {
+        &self.semantic_decl
+    }
+
+    pub fn get_file_id(&self) -> FileId {
+        self.file_id
+    }
+
+    pub fn get_range(&self) -> TextRange {
+        self.range
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct LuaSymbolIndex {
+    symbols: HashMap<FileId, Vec<LuaSymbol>>,
+}
+
+impl LuaSymbolIndex {
+    pub fn new() -> Self {
+        Self {
+            symbols: HashMap::new(),
+        }
+    }
+
+    pub fn add_symbol(&mut self, symbol: LuaSymbol) {
+        self.symbols.entry(symbol.file_id).or_default().push(symbol);
+    }
+
+    pub fn get_file_symbols(&self, file_id: &FileId) -> Option<&Vec<LuaSymbol>> {
+        self.symbols.get(file_id)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &LuaSymbol> {
+        self.symbols.values().flatten()
+    }
+
+    /// Find the symbols whose name or full name fuzzy matches the query, the best matches come first
+    pub fn search(&self, query: &str, limit: usize) -> Vec<&LuaSymbol> {
+        let mut matches = self
+            .iter()
+            .filter_map(|symbol| {
+                let name_score = fuzzy_match(query, symbol.get_name());
+                let full_name_score = match symbol.container_name {
+                    Some(_) => fuzzy_match(query, symbol.get_full_name()),
+                    None => None,
+                };
+                let score = name_score.max(full_name_score)?;
+                Some((score, symbol))
+            })
+            .collect::<Vec<_>>();
+        matches.sort_by(|(a_score, a), (b_score, b)| {
+            b_score
+                .cmp(a_score)
+                .then_with(|| a.name.len().cmp(&b.name.len()))
+                .then_with(|| a.name.cmp(&b.name))
+        });
+        matches.truncate(limit);
+        matches.into_iter().map(|(_, symbol)| symbol).collect()
+    }
+}
+
+impl LuaIndex for LuaSymbolIndex {
+    fn remove(&mut self, file_id: FileId) {
+        self.symbols.remove(&file_id);
+    }
+
+    fn clear(&mut self) {
+        self.symbols.clear();
+    }
+}
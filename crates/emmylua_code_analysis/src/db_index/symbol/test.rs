@@ -0,0 +1,67 @@
+#[cfg(test)]
+mod tests {
+    use crate::{fuzzy_match, LuaSymbolKind, VirtualWorkspace};
+
+    #[test]
+    fn test_fuzzy_match() {
+        assert!(fuzzy_match("td", "takeDamage").is_some());
+        assert!(fuzzy_match("takedmg", "takeDamage").is_some());
+        assert!(fuzzy_match("PLH", "Player:heal").is_some());
+        assert!(fuzzy_match("xyz", "takeDamage").is_none());
+        assert!(fuzzy_match("takeDamage", "take").is_none());
+
+        // word starts and prefixes rank higher than scattered matches
+        assert!(fuzzy_match("td", "takeDamage") > fuzzy_match("td", "toad"));
+        assert!(fuzzy_match("heal", "heal") > fuzzy_match("heal", "healAll"));
+        assert!(fuzzy_match("heal", "healAll") > fuzzy_match("heal", "reheal"));
+    }
+
+    #[test]
+    fn test_symbol_index() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def_file(
+            "player.lua",
+            r#"
+            ---@class Player
+            ---@field hp number
+            local Player = {}
+
+            function Player.takeDamage(n)
+            end
+
+            function Player:heal()
+            end
+
+            local function helper()
+            end
+
+            GAME_NAME = "game"
+
+            return Player
+            "#,
+        );
+
+        let symbol_index = ws.analysis.compilation.get_db().get_symbol_index();
+        let symbols = symbol_index.search("pltd", 10);
+        assert_eq!(symbols[0].get_full_name(), "Player.takeDamage");
+        assert_eq!(symbols[0].get_kind(), LuaSymbolKind::Function);
+
+        let symbols = symbol_index.search("heal", 10);
+        assert_eq!(symbols[0].get_full_name(), "Player:heal");
+        assert_eq!(symbols[0].get_kind(), LuaSymbolKind::Method);
+
+        let symbols = symbol_index.search("hp", 10);
+        assert_eq!(symbols[0].get_full_name(), "Player.hp");
+        assert_eq!(symbols[0].get_kind(), LuaSymbolKind::Field);
+
+        let symbols = symbol_index.search("helper", 10);
+        assert_eq!(symbols[0].get_container_name(), Some("player"));
+
+        let symbols = symbol_index.search("Player", 10);
+        assert_eq!(symbols[0].get_kind(), LuaSymbolKind::Class);
+
+        let symbols = symbol_index.search("gamename", 10);
+        assert_eq!(symbols[0].get_name(), "GAME_NAME");
+        assert_eq!(symbols[0].get_kind(), LuaSymbolKind::Variable);
+    }
+}
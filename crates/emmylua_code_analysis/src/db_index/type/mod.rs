@@ -24,7 +24,10 @@ pub struct LuaTypeIndex {
     file_types: HashMap<FileId, Vec<LuaTypeDeclId>>,
     full_name_type_map: HashMap<LuaTypeDeclId, LuaTypeDecl>,
     generic_params: HashMap<LuaTypeDeclId, Vec<(String, Option<LuaType>)>>,
+    generic_variances: HashMap<LuaTypeDeclId, Vec<LuaGenericVariance>>,
     supers: HashMap<LuaTypeDeclId, Vec<InFiled<LuaType>>>,
+    // super type -> the types inheriting it directly, with the file declaring the inheritance
+    sub_types: HashMap<LuaTypeDeclId, Vec<InFiled<LuaTypeDeclId>>>,
     as_force_type: HashMap<InFiled<LuaSyntaxId>, LuaType>,
 }
 
@@ -36,7 +39,9 @@ impl LuaTypeIndex {
             file_types: HashMap::new(),
             full_name_type_map: HashMap::new(),
             generic_params: HashMap::new(),
+            generic_variances: HashMap::new(),
             supers: HashMap::new(),
+            sub_types: HashMap::new(),
             as_force_type: HashMap::new(),
         }
     }
@@ -52,7 +57,7 @@ impl LuaTypeIndex {
     pub fn add_file_using_namespace(&mut self, file_id: FileId, namespace: String) {
         self.file_using_namespace
             .entry(file_id)
-            .or_insert_with(Vec::new)
+            .or_default()
             .push(namespace);
     }
 
@@ -64,7 +69,7 @@ impl LuaTypeIndex {
         let id = type_decl.get_id();
         self.file_types
             .entry(file_id)
-            .or_insert_with(Vec::new)
+            .or_default()
             .push(id.clone());
 
         if let Some(old_decl) = self.full_name_type_map.get_mut(&id) {
@@ -172,10 +177,31 @@ impl LuaTypeIndex {
         self.generic_params.get(decl_id)
     }
 
+    pub fn add_generic_variances(
+        &mut self,
+        decl_id: LuaTypeDeclId,
+        variances: Vec<LuaGenericVariance>,
+    ) {
+        self.generic_variances.insert(decl_id, variances);
+    }
+
+    pub fn get_generic_variance(&self, decl_id: &LuaTypeDeclId, idx: usize) -> LuaGenericVariance {
+        self.generic_variances
+            .get(decl_id)
+            .and_then(|variances| variances.get(idx).copied())
+            .unwrap_or_default()
+    }
+
     pub fn add_super_type(&mut self, decl_id: LuaTypeDeclId, file_id: FileId, super_type: LuaType) {
+        if let Some(super_id) = get_super_type_id(&super_type) {
+            self.sub_types
+                .entry(super_id)
+                .or_default()
+                .push(InFiled::new(file_id, decl_id.clone()));
+        }
         self.supers
             .entry(decl_id)
-            .or_insert_with(Vec::new)
+            .or_default()
             .push(InFiled::new(file_id, super_type));
     }
 
@@ -187,6 +213,20 @@ impl LuaTypeIndex {
         }
     }
 
+    /// The types which directly inherit `decl_id`
+    pub fn get_sub_types(&self, decl_id: &LuaTypeDeclId) -> Vec<LuaTypeDeclId> {
+        let Some(sub_types) = self.sub_types.get(decl_id) else {
+            return Vec::new();
+        };
+        let mut sub_types = sub_types
+            .iter()
+            .map(|sub_type| sub_type.value.clone())
+            .collect::<Vec<_>>();
+        sub_types.sort_by(|a, b| a.get_name().cmp(b.get_name()));
+        sub_types.dedup();
+        sub_types
+    }
+
     pub fn get_type_decl(&self, decl_id: &LuaTypeDeclId) -> Option<&LuaTypeDecl> {
         self.full_name_type_map.get(decl_id)
     }
@@ -208,6 +248,14 @@ impl LuaTypeIndex {
     }
 }
 
+fn get_super_type_id(super_type: &LuaType) -> Option<LuaTypeDeclId> {
+    match super_type {
+        LuaType::Ref(id) => Some(id.clone()),
+        LuaType::Generic(generic) => Some(generic.get_base_type_id()),
+        _ => None,
+    }
+}
+
 impl LuaIndex for LuaTypeIndex {
     fn remove(&mut self, file_id: FileId) {
         self.file_namespace.remove(&file_id);
@@ -225,6 +273,19 @@ impl LuaIndex for LuaTypeIndex {
                 }
 
                 if let Some(supers) = self.supers.get_mut(&id) {
+                    for super_type in supers.iter().filter(|s| s.file_id == file_id) {
+                        let Some(super_id) = get_super_type_id(&super_type.value) else {
+                            continue;
+                        };
+                        if let Some(sub_types) = self.sub_types.get_mut(&super_id) {
+                            sub_types.retain(|sub_type| {
+                                sub_type.file_id != file_id || sub_type.value != id
+                            });
+                            if sub_types.is_empty() {
+                                self.sub_types.remove(&super_id);
+                            }
+                        }
+                    }
                     supers.retain(|s| s.file_id != file_id);
                     if supers.is_empty() {
                         self.supers.remove(&id);
@@ -233,6 +294,7 @@ impl LuaIndex for LuaTypeIndex {
 
                 if remove_type {
                     self.generic_params.remove(&id);
+                    self.generic_variances.remove(&id);
                 }
             }
         }
@@ -246,7 +308,9 @@ impl LuaIndex for LuaTypeIndex {
         self.file_types.clear();
         self.full_name_type_map.clear();
         self.generic_params.clear();
+        self.generic_variances.clear();
         self.supers.clear();
+        self.sub_types.clear();
         self.as_force_type.clear();
     }
 }
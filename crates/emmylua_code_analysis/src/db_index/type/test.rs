@@ -5,7 +5,7 @@ mod test {
     use crate::db_index::r#type::LuaTypeIndex;
     use crate::db_index::traits::LuaIndex;
     use crate::db_index::{LuaDeclTypeKind, LuaTypeAttribute};
-    use crate::{FileId, LuaTypeDecl, LuaTypeDeclId};
+    use crate::{FileId, LuaType, LuaTypeDecl, LuaTypeDeclId};
 
     fn create_type_index() -> LuaTypeIndex {
         LuaTypeIndex::new()
@@ -161,4 +161,49 @@ mod test {
         assert_eq!(decl.get_namespace(), "test".into());
         assert_eq!(decl.get_full_name(), "test.new_type");
     }
+
+    #[test]
+    fn test_sub_types() {
+        let mut index = create_type_index();
+        let file_id = FileId { id: 1 };
+        let base = LuaTypeDeclId::new("Base");
+        index.add_super_type(LuaTypeDeclId::new("B"), file_id, LuaType::Ref(base.clone()));
+        index.add_super_type(LuaTypeDeclId::new("A"), file_id, LuaType::Ref(base.clone()));
+        index.add_super_type(
+            LuaTypeDeclId::new("C"),
+            file_id,
+            LuaType::Ref(LuaTypeDeclId::new("A")),
+        );
+
+        let sub_types = index.get_sub_types(&base);
+        assert_eq!(
+            sub_types,
+            vec![LuaTypeDeclId::new("A"), LuaTypeDeclId::new("B")]
+        );
+
+        // the inheritance is removed with the file declaring it
+        let file_id2 = FileId { id: 2 };
+        index.add_type_decl(
+            file_id2,
+            LuaTypeDecl::new(
+                file_id2,
+                TextRange::new(0.into(), 1.into()),
+                "D".to_string(),
+                LuaDeclTypeKind::Class,
+                LuaTypeAttribute::None.into(),
+                LuaTypeDeclId::new("D"),
+            ),
+        );
+        index.add_super_type(
+            LuaTypeDeclId::new("D"),
+            file_id2,
+            LuaType::Ref(base.clone()),
+        );
+        assert_eq!(index.get_sub_types(&base).len(), 3);
+        index.remove(file_id2);
+        assert_eq!(
+            index.get_sub_types(&base),
+            vec![LuaTypeDeclId::new("A"), LuaTypeDeclId::new("B")]
+        );
+    }
 }
@@ -33,11 +33,17 @@ impl TypeAssertion {
         source: LuaType,
     ) -> Result<LuaType, InferFailReason> {
         match self {
-            TypeAssertion::Exist => Ok(TypeOps::Remove.apply(&source, &LuaType::Nil)),
+            TypeAssertion::Exist => {
+                let source = expand_union_alias(db, source);
+                Ok(TypeOps::Remove.apply(&source, &LuaType::Nil))
+            }
             TypeAssertion::NotExist => Ok(TypeOps::NarrowFalseOrNil.apply_source(&source)),
             TypeAssertion::Narrow(t) => Ok(TypeOps::Narrow.apply(&source, t)),
             TypeAssertion::Add(lua_type) => Ok(TypeOps::Union.apply(&source, lua_type)),
-            TypeAssertion::Remove(lua_type) => Ok(TypeOps::Remove.apply(&source, lua_type)),
+            TypeAssertion::Remove(lua_type) => {
+                let source = expand_union_alias(db, source);
+                Ok(TypeOps::Remove.apply(&source, lua_type))
+            }
             TypeAssertion::Reassign((syntax_id, idx)) => {
                 let expr = LuaExpr::cast(
                     syntax_id
@@ -62,3 +68,18 @@ impl TypeAssertion {
         matches!(self, TypeAssertion::Reassign(_))
     }
 }
+
+/// an alias of a union is replaced by the union so its members can be removed
+fn expand_union_alias(db: &DbIndex, source: LuaType) -> LuaType {
+    let LuaType::Ref(type_decl_id) = &source else {
+        return source;
+    };
+    let origin = db
+        .get_type_index()
+        .get_type_decl(type_decl_id)
+        .and_then(|type_decl| type_decl.get_alias_origin(db, None));
+    match origin {
+        Some(origin @ LuaType::Union(_)) => origin,
+        _ => source,
+    }
+}
@@ -89,6 +89,11 @@ impl LuaTypeDecl {
             .any(|l| l.attrib.contains(LuaTypeAttribute::Exact))
     }
 
+    /// exact classes only accept new members from the files that declare them
+    pub fn is_exact_outside_file(&self, file_id: FileId) -> bool {
+        self.is_exact() && !self.locations.iter().any(|l| l.file_id == file_id)
+    }
+
     pub fn is_partial(&self) -> bool {
         self.locations
             .iter()
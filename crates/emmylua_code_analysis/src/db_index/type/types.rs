@@ -914,15 +914,43 @@ impl GenericTplId {
     }
 }
 
+/// variance of a generic class parameter, `---@class Box<out T>` or `---@class Sink<in T>`
+///
+/// parameters without a modifier are invariant, since the class may both read and write them
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Default)]
+pub enum LuaGenericVariance {
+    #[default]
+    Invariant,
+    Covariant,
+    Contravariant,
+}
+
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub struct GenericTpl {
     tpl_id: GenericTplId,
     name: ArcIntern<SmolStr>,
+    constraint: Option<LuaType>,
 }
 
 impl GenericTpl {
     pub fn new(tpl_id: GenericTplId, name: ArcIntern<SmolStr>) -> Self {
-        Self { tpl_id, name }
+        Self {
+            tpl_id,
+            name,
+            constraint: None,
+        }
+    }
+
+    pub fn with_constraint(
+        tpl_id: GenericTplId,
+        name: ArcIntern<SmolStr>,
+        constraint: Option<LuaType>,
+    ) -> Self {
+        Self {
+            tpl_id,
+            name,
+            constraint,
+        }
     }
 
     pub fn get_tpl_id(&self) -> GenericTplId {
@@ -932,6 +960,11 @@ impl GenericTpl {
     pub fn get_name(&self) -> &str {
         &self.name
     }
+
+    /// the bound declared by `---@generic T : Base`
+    pub fn get_constraint(&self) -> Option<&LuaType> {
+        self.constraint.as_ref()
+    }
 }
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
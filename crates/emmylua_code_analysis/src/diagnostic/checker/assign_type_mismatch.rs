@@ -9,12 +9,18 @@ use crate::{
     TypeCheckFailReason, TypeCheckResult,
 };
 
-use super::{humanize_lint_type, Checker, DiagnosticContext};
+use super::{
+    add_nullable_mismatch_diagnostic, humanize_lint_type, is_nullable_mismatch, Checker,
+    DiagnosticContext,
+};
 
 pub struct AssignTypeMismatchChecker;
 
 impl Checker for AssignTypeMismatchChecker {
-    const CODES: &[DiagnosticCode] = &[DiagnosticCode::AssignTypeMismatch];
+    const CODES: &[DiagnosticCode] = &[
+        DiagnosticCode::AssignTypeMismatch,
+        DiagnosticCode::NullableMismatch,
+    ];
 
     fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
         for node in semantic_model.get_root().descendants::<LuaAst>() {
@@ -109,13 +115,15 @@ fn check_index_expr(
 ) -> Option<()> {
     let member_info =
         semantic_model.get_semantic_info(rowan::NodeOrToken::Node(index_expr.syntax().clone()))?;
+    // nil safety requires non-nullable fields to be assigned with narrowed values
+    let allow_nil = !semantic_model.get_emmyrc().strict.nil_safety;
     check_assign_type_mismatch(
         context,
         semantic_model,
         index_expr.get_range(),
         Some(member_info.typ.clone()),
         value_type,
-        true,
+        allow_nil,
     );
     if let Some(expr) = expr {
         handle_value_is_table_expr(context, semantic_model, Some(member_info.typ), &expr);
@@ -165,6 +173,7 @@ fn handle_value_is_table_expr(
 ) -> Option<()> {
     let table_type = table_type?;
     let member_infos = semantic_model.infer_member_infos(&table_type)?;
+    let exact_class = get_exact_class_name(semantic_model, &table_type);
     let fields = LuaTableExpr::cast(value_expr.syntax().clone())?.get_fields();
     for field in fields {
         if field.is_value_field() {
@@ -178,6 +187,20 @@ fn handle_value_is_table_expr(
                 .iter()
                 .find(|info| info.key.to_path() == field_path_part)
                 .map(|info| info.typ.clone());
+            if let (None, Some(class_name)) = (&source_type, &exact_class) {
+                context.add_diagnostic(
+                    DiagnosticCode::AssignTypeMismatch,
+                    field.get_range(),
+                    t!(
+                        "member %{name} is not declared in exact class %{class}",
+                        name = field_path_part,
+                        class = class_name
+                    )
+                    .to_string(),
+                    None,
+                );
+                continue;
+            }
             let expr = field.get_value_expr();
             if let Some(expr) = expr {
                 let expr_type = semantic_model.infer_expr(expr).unwrap_or(LuaType::Any);
@@ -202,6 +225,21 @@ fn handle_value_is_table_expr(
     Some(())
 }
 
+fn get_exact_class_name(semantic_model: &SemanticModel, typ: &LuaType) -> Option<String> {
+    let LuaType::Ref(type_id) = typ else {
+        return None;
+    };
+    let type_decl = semantic_model
+        .get_db()
+        .get_type_index()
+        .get_type_decl(type_id)?;
+    if type_decl.is_exact() {
+        Some(type_id.get_name().to_string())
+    } else {
+        None
+    }
+}
+
 fn check_assign_type_mismatch(
     context: &mut DiagnosticContext,
     semantic_model: &SemanticModel,
@@ -260,6 +298,11 @@ fn add_type_check_diagnostic(
     value_type: &LuaType,
     result: TypeCheckResult,
 ) {
+    if is_nullable_mismatch(semantic_model, source_type, value_type) {
+        add_nullable_mismatch_diagnostic(context, semantic_model, range, source_type, value_type);
+        return;
+    }
+
     let db = semantic_model.get_db();
     match result {
         Ok(_) => return,
@@ -3,14 +3,22 @@ use std::collections::HashSet;
 use emmylua_parser::{LuaAst, LuaAstNode, LuaExpr, LuaIndexExpr, LuaIndexKey, LuaVarExpr};
 use internment::ArcIntern;
 
-use crate::{DiagnosticCode, InferFailReason, LuaMemberKey, LuaType, SemanticModel};
+use lsp_types::DiagnosticSeverity;
+
+use crate::{
+    DiagnosticCode, InferFailReason, LuaMemberKey, LuaType, LuaTypeDeclId, SemanticModel,
+};
 
 use super::{humanize_lint_type, Checker, DiagnosticContext};
 
 pub struct CheckFieldChecker;
 
 impl Checker for CheckFieldChecker {
-    const CODES: &[DiagnosticCode] = &[DiagnosticCode::InjectField, DiagnosticCode::UndefinedField];
+    const CODES: &[DiagnosticCode] = &[
+        DiagnosticCode::InjectField,
+        DiagnosticCode::InjectFieldFail,
+        DiagnosticCode::UndefinedField,
+    ];
 
     fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
         let root = semantic_model.get_root().clone();
@@ -70,7 +78,30 @@ fn check_index_expr(
     }
 
     let index_name = index_key.get_path_part();
+    let exact_type_id = get_exact_type_id(semantic_model, &prefix_typ);
     match code {
+        DiagnosticCode::InjectField if exact_type_id.is_some() => {
+            context.add_diagnostic(
+                DiagnosticCode::InjectFieldFail,
+                index_key.get_range()?,
+                t!(
+                    "Cannot inject field `%{field}` into exact class `%{class}`",
+                    field = index_name,
+                    class = exact_type_id?.get_name(),
+                )
+                .to_string(),
+                None,
+            );
+        }
+        DiagnosticCode::UndefinedField if exact_type_id.is_some() => {
+            context.add_diagnostic_with_default_severity(
+                DiagnosticCode::UndefinedField,
+                index_key.get_range()?,
+                t!("Undefined field `%{field}`. ", field = index_name,).to_string(),
+                None,
+                DiagnosticSeverity::ERROR,
+            );
+        }
         DiagnosticCode::InjectField => {
             context.add_diagnostic(
                 DiagnosticCode::InjectField,
@@ -98,6 +129,26 @@ fn check_index_expr(
     Some(())
 }
 
+// exact class is closed, so unknown members of it are always reported as errors
+fn get_exact_type_id(semantic_model: &SemanticModel, typ: &LuaType) -> Option<LuaTypeDeclId> {
+    let type_id = match typ {
+        LuaType::Ref(type_id) | LuaType::Def(type_id) => type_id,
+        LuaType::Instance(instance_typ) => {
+            return get_exact_type_id(semantic_model, instance_typ.get_base())
+        }
+        _ => return None,
+    };
+    let type_decl = semantic_model
+        .get_db()
+        .get_type_index()
+        .get_type_decl(type_id)?;
+    if type_decl.is_exact() {
+        Some(type_id.clone())
+    } else {
+        None
+    }
+}
+
 fn is_valid_prefix_type(typ: &LuaType) -> bool {
     let mut current_typ = typ;
     loop {
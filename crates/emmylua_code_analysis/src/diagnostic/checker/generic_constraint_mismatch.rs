@@ -0,0 +1,105 @@
+use emmylua_parser::{LuaAstNode, LuaCallExpr, LuaExpr};
+use rowan::TextRange;
+
+use crate::{
+    humanize_type, instantiate_type_generic, DiagnosticCode, GenericTplId, LuaFunctionType,
+    LuaType, RenderLevel, SemanticModel, SubstitutorValue,
+};
+
+use super::{Checker, DiagnosticContext};
+
+pub struct GenericConstraintMismatchChecker;
+
+impl Checker for GenericConstraintMismatchChecker {
+    const CODES: &[DiagnosticCode] = &[DiagnosticCode::GenericConstraintMismatch];
+
+    fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
+        let root = semantic_model.get_root().clone();
+        for call_expr in root.descendants::<LuaCallExpr>() {
+            check_call_expr(context, semantic_model, call_expr);
+        }
+    }
+}
+
+fn check_call_expr(
+    context: &mut DiagnosticContext,
+    semantic_model: &SemanticModel,
+    call_expr: LuaCallExpr,
+) -> Option<()> {
+    let prefix_expr = call_expr.get_prefix_expr()?;
+    let LuaType::Signature(signature_id) = semantic_model.infer_expr(prefix_expr).ok()? else {
+        return None;
+    };
+    let signature = semantic_model
+        .get_db()
+        .get_signature_index()
+        .get(&signature_id)?;
+    if signature
+        .generic_params
+        .iter()
+        .all(|(_, constraint)| constraint.is_none())
+    {
+        return None;
+    }
+
+    let func = signature.to_doc_func_type();
+    let substitutor = semantic_model.infer_call_generic_substitutor(&func, call_expr.clone())?;
+    let db = semantic_model.get_db();
+    for (idx, (name, constraint)) in signature.generic_params.iter().enumerate() {
+        let Some(constraint) = constraint else {
+            continue;
+        };
+        let tpl_id = GenericTplId::Func(idx as u32);
+        let Some(SubstitutorValue::Type(inferred_type)) = substitutor.get(tpl_id) else {
+            continue;
+        };
+        if inferred_type.is_unknown() || inferred_type.is_any() {
+            continue;
+        }
+
+        let constraint = instantiate_type_generic(db, constraint, &substitutor);
+        if semantic_model
+            .type_check(&constraint, inferred_type)
+            .is_ok()
+        {
+            continue;
+        }
+
+        let range = find_tpl_arg_range(&func, &call_expr, tpl_id).unwrap_or(call_expr.get_range());
+        context.add_diagnostic(
+            DiagnosticCode::GenericConstraintMismatch,
+            range,
+            t!(
+                "generic `%{name}` requires `%{constraint}` but found `%{found}`",
+                name = name,
+                constraint = humanize_type(db, &constraint, RenderLevel::Simple),
+                found = humanize_type(db, inferred_type, RenderLevel::Simple)
+            )
+            .to_string(),
+            None,
+        );
+    }
+
+    Some(())
+}
+
+/// find the argument passed to the first parameter declared directly as the template
+fn find_tpl_arg_range(
+    func: &LuaFunctionType,
+    call_expr: &LuaCallExpr,
+    tpl_id: GenericTplId,
+) -> Option<TextRange> {
+    let param_idx = func.get_params().iter().position(|(_, typ)| match typ {
+        Some(LuaType::TplRef(tpl)) => tpl.get_tpl_id() == tpl_id,
+        _ => false,
+    })?;
+
+    let arg_idx = match (call_expr.is_colon_call(), func.is_colon_define()) {
+        (true, false) => param_idx.checked_sub(1)?,
+        (false, true) => param_idx + 1,
+        _ => param_idx,
+    };
+
+    let args: Vec<LuaExpr> = call_expr.get_args_list()?.get_args().collect();
+    args.get(arg_idx).map(|arg| arg.get_range())
+}
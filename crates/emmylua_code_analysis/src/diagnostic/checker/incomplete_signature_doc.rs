@@ -2,6 +2,8 @@ use std::collections::HashSet;
 
 use emmylua_parser::{LuaAstNode, LuaClosureExpr, LuaDocTagParam, LuaDocTagReturn, LuaStat};
 
+use rowan::TextRange;
+
 use crate::{DiagnosticCode, LuaSemanticDeclId, LuaType, SemanticDeclLevel, SemanticModel};
 
 use super::{get_closure_expr_comment, get_own_return_stats, Checker, DiagnosticContext};
@@ -22,53 +24,55 @@ impl Checker for IncompleteSignatureDocChecker {
     }
 }
 
-fn check_doc(
-    context: &mut DiagnosticContext,
+/// How much of a function signature is documented, shared with the doc coverage report
+pub struct SignatureDocState {
+    pub semantic_decl: LuaSemanticDeclId,
+    pub is_global: bool,
+    pub function_name: String,
+    pub has_comment: bool,
+    /// The comment has at least one `@param` or `@return`
+    pub has_signature_doc: bool,
+    /// The name and range of the params without `@param`
+    pub missing_params: Vec<(String, TextRange)>,
+    /// The 1-based index and range of the returned expressions without `@return`
+    pub missing_returns: Vec<(usize, TextRange)>,
+}
+
+impl SignatureDocState {
+    pub fn is_complete(&self) -> bool {
+        self.has_comment && self.missing_params.is_empty() && self.missing_returns.is_empty()
+    }
+}
+
+pub fn get_signature_doc_state(
     semantic_model: &SemanticModel,
     closure_expr: &LuaClosureExpr,
-) -> Option<()> {
+) -> Option<SignatureDocState> {
     let semantic_decl = semantic_model.find_decl(
         rowan::NodeOrToken::Node(closure_expr.syntax().clone()),
         SemanticDeclLevel::default(),
     )?;
-    let (is_global, function_name) = match semantic_decl {
+    let (is_global, function_name) = match &semantic_decl {
         LuaSemanticDeclId::LuaDecl(decl_id) => {
-            let decl = semantic_model
-                .get_db()
-                .get_decl_index()
-                .get_decl(&decl_id)?;
+            let decl = semantic_model.get_db().get_decl_index().get_decl(decl_id)?;
             (decl.is_global(), decl.get_name().to_string())
         }
         _ => (false, String::new()),
     };
 
-    let comment = get_closure_expr_comment(closure_expr);
-
-    if comment.is_none() && is_global {
-        if let Some(stat) = closure_expr.get_parent::<LuaStat>() {
-            context.add_diagnostic(
-                DiagnosticCode::MissingGlobalDoc,
-                stat.get_range(),
-                t!(
-                    "Missing comment for global function `%{name}`.",
-                    name = function_name
-                )
-                .to_string(),
-                None,
-            );
-        }
-        return Some(());
-    }
-
-    let Some(comment) = comment else {
-        return Some(());
+    let mut state = SignatureDocState {
+        semantic_decl,
+        is_global,
+        function_name,
+        has_comment: false,
+        has_signature_doc: false,
+        missing_params: Vec::new(),
+        missing_returns: Vec::new(),
     };
-
-    let code = if is_global {
-        DiagnosticCode::MissingGlobalDoc
-    } else {
-        DiagnosticCode::IncompleteSignatureDoc
+    let Some(comment) = get_closure_expr_comment(closure_expr) else {
+        return Some(state);
     };
+    state.has_comment = true;
 
     let doc_param_names: HashSet<String> = comment
         .children::<LuaDocTagParam>()
@@ -84,43 +88,94 @@ fn check_doc(
         .map(|return_doc| return_doc.get_types().count())
         .sum();
 
+    state.has_signature_doc = !doc_param_names.is_empty() || doc_return_len != 0;
+    state.missing_params = get_missing_params(closure_expr, &doc_param_names);
+    state.missing_returns = get_missing_returns(semantic_model, closure_expr, doc_return_len);
+    Some(state)
+}
+
+fn check_doc(
+    context: &mut DiagnosticContext,
+    semantic_model: &SemanticModel,
+    closure_expr: &LuaClosureExpr,
+) -> Option<()> {
+    let state = get_signature_doc_state(semantic_model, closure_expr)?;
+    let is_global = state.is_global;
+    let function_name = &state.function_name;
+
+    if !state.has_comment {
+        if is_global {
+            if let Some(stat) = closure_expr.get_parent::<LuaStat>() {
+                context.add_diagnostic(
+                    DiagnosticCode::MissingGlobalDoc,
+                    stat.get_range(),
+                    t!(
+                        "Missing comment for global function `%{name}`.",
+                        name = function_name
+                    )
+                    .to_string(),
+                    None,
+                );
+            }
+        }
+        return Some(());
+    }
+
     // 如果文档中没有参数和返回值注解, 且不是全局函数, 则不检查
-    if doc_param_names.is_empty() && doc_return_len == 0 && !is_global {
+    if !state.has_signature_doc && !is_global {
         return Some(());
     }
 
-    check_params(
-        context,
-        closure_expr,
-        &doc_param_names,
-        code,
-        is_global,
-        &function_name,
-    );
-
-    check_returns(
-        context,
-        semantic_model,
-        closure_expr,
-        doc_return_len,
-        code,
-        is_global,
-        &function_name,
-    );
+    let code = if is_global {
+        DiagnosticCode::MissingGlobalDoc
+    } else {
+        DiagnosticCode::IncompleteSignatureDoc
+    };
+
+    for (name, range) in &state.missing_params {
+        let message = if is_global {
+            t!(
+                "Missing @param annotation for parameter `%{name}` in global function `%{function_name}`.",
+                name = name,
+                function_name = function_name
+            )
+        } else {
+            t!(
+                "Incomplete signature. Missing @param annotation for parameter `%{name}`.",
+                name = name
+            )
+        };
+
+        context.add_diagnostic(code, *range, message.to_string(), None);
+    }
+
+    for (index, range) in &state.missing_returns {
+        let message = if is_global {
+            t!(
+                "Missing @return annotation at index `%{index}` in global function `%{function_name}`.",
+                index = index,
+                function_name = function_name
+            )
+        } else {
+            t!(
+                "Incomplete signature. Missing @return annotation at index `%{index}`.",
+                index = index
+            )
+        };
+
+        context.add_diagnostic(code, *range, message.to_string(), None);
+    }
 
     Some(())
 }
 
-fn check_params(
-    context: &mut DiagnosticContext,
+fn get_missing_params(
     closure_expr: &LuaClosureExpr,
     doc_param_names: &HashSet<String>,
-    code: DiagnosticCode,
-    is_global: bool,
-    function_name: &str,
-) {
+) -> Vec<(String, TextRange)> {
+    let mut missing_params = Vec::new();
     let Some(params_list) = closure_expr.get_params_list() else {
-        return;
+        return missing_params;
     };
 
     for param in params_list.get_params() {
@@ -130,33 +185,19 @@ fn check_params(
 
         let name = name_token.get_name_text();
         if !doc_param_names.contains(name) && name != "_" {
-            let message = if is_global {
-                t!(
-                    "Missing @param annotation for parameter `%{name}` in global function `%{function_name}`.",
-                    name = name,
-                    function_name = function_name
-                )
-            } else {
-                t!(
-                    "Incomplete signature. Missing @param annotation for parameter `%{name}`.",
-                    name = name
-                )
-            };
-
-            context.add_diagnostic(code, param.get_range(), message.to_string(), None);
+            missing_params.push((name.to_string(), param.get_range()));
         }
     }
+
+    missing_params
 }
 
-fn check_returns(
-    context: &mut DiagnosticContext,
+fn get_missing_returns(
     semantic_model: &SemanticModel,
     closure_expr: &LuaClosureExpr,
     doc_return_len: usize,
-    code: DiagnosticCode,
-    is_global: bool,
-    function_name: &str,
-) {
+) -> Vec<(usize, TextRange)> {
+    let mut missing_returns = Vec::new();
     for return_stat in get_own_return_stats(closure_expr) {
         let mut return_stat_len: usize = 0;
 
@@ -173,21 +214,10 @@ fn check_returns(
             return_stat_len += expr_return_count;
 
             if return_stat_len > doc_return_len {
-                let message = if is_global {
-                    t!(
-                        "Missing @return annotation at index `%{index}` in global function `%{function_name}`.",
-                        index = i + 1,
-                        function_name = function_name
-                    )
-                } else {
-                    t!(
-                        "Incomplete signature. Missing @return annotation at index `%{index}`.",
-                        index = i + 1
-                    )
-                };
-
-                context.add_diagnostic(code, expr.get_range(), message.to_string(), None);
+                missing_returns.push((i + 1, expr.get_range()));
             }
         }
     }
+
+    missing_returns
 }
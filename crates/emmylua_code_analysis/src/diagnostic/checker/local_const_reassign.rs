@@ -1,6 +1,11 @@
-use crate::{DiagnosticCode, LocalAttribute, LuaDeclExtra, LuaDeclId, SemanticModel};
+use emmylua_parser::{LuaAstNode, LuaGlobalName, LuaLanguageLevel, LuaSyntaxId};
 
-use super::{Checker, DiagnosticContext};
+use crate::{DiagnosticCode, LocalAttribute, LuaDecl, LuaDeclExtra, LuaDeclId, SemanticModel};
+
+use super::{
+    undeclared_global::{get_global_scope_state, has_global_stat},
+    Checker, DiagnosticContext,
+};
 
 pub struct LocalConstReassignChecker;
 
@@ -19,14 +24,28 @@ impl Checker for LocalConstReassignChecker {
         else {
             return;
         };
+        let has_global_stat = has_global_stat(semantic_model);
         for (decl_id, decl) in decl_tree.get_decls() {
             match &decl.extra {
-                LuaDeclExtra::Local { attrib, .. } => {
-                    if let Some(attrib) = attrib {
-                        if matches!(attrib, LocalAttribute::Const | LocalAttribute::IterConst) {
-                            check_local_const_reassign(context, semantic_model, decl_id, &attrib);
-                        }
-                    }
+                LuaDeclExtra::Local {
+                    attrib: Some(attrib),
+                    ..
+                } if matches!(attrib, LocalAttribute::Const | LocalAttribute::IterConst) => {
+                    check_local_const_reassign(context, semantic_model, decl_id, attrib);
+                }
+                LuaDeclExtra::Global { .. }
+                    if decl.is_explicit_global()
+                        && is_const_global(semantic_model, decl).unwrap_or(false) =>
+                {
+                    check_local_const_reassign(
+                        context,
+                        semantic_model,
+                        decl_id,
+                        &LocalAttribute::Const,
+                    );
+                }
+                LuaDeclExtra::Global { .. } if has_global_stat && !decl.is_explicit_global() => {
+                    check_collective_const_reassign(context, semantic_model, decl_id, decl);
                 }
                 _ => {}
             }
@@ -34,37 +53,86 @@ impl Checker for LocalConstReassignChecker {
     }
 }
 
+fn is_const_global(semantic_model: &SemanticModel, decl: &LuaDecl) -> Option<bool> {
+    let root = semantic_model.get_root();
+    let node = decl.get_syntax_id().to_node_from_root(root.syntax())?;
+    let attrib = LuaGlobalName::cast(node)?.get_attrib()?;
+    Some(attrib.is_const())
+}
+
+/// implicit globals are read-only where a `global <const> *` declaration is in scope
+fn check_collective_const_reassign(
+    context: &mut DiagnosticContext,
+    semantic_model: &SemanticModel,
+    decl_id: &LuaDeclId,
+    decl: &LuaDecl,
+) -> Option<()> {
+    let file_id = semantic_model.get_file_id();
+    let mut write_ranges = vec![decl.get_range()];
+    let refs_index = semantic_model.get_db().get_reference_index();
+    if let Some(decl_refs) = refs_index
+        .get_local_reference(&file_id)
+        .and_then(|local_refs| local_refs.get_decl_references(decl_id))
+    {
+        for decl_ref in decl_refs {
+            if decl_ref.is_write && !write_ranges.contains(&decl_ref.range) {
+                write_ranges.push(decl_ref.range);
+            }
+        }
+    }
+
+    let root = semantic_model.get_root();
+    for range in write_ranges {
+        let Some(node) = LuaSyntaxId::to_node_at_range(root.syntax(), range) else {
+            continue;
+        };
+        if get_global_scope_state(&node, range.start()).collective_const {
+            context.add_diagnostic(
+                DiagnosticCode::LocalConstReassign,
+                range,
+                t!("Cannot reassign to a constant variable").to_string(),
+                None,
+            );
+        }
+    }
+
+    Some(())
+}
+
 fn check_local_const_reassign(
     context: &mut DiagnosticContext,
     semantic_model: &SemanticModel,
     decl_id: &LuaDeclId,
     attrib: &LocalAttribute,
 ) -> Option<()> {
+    let (code, message) = match attrib {
+        LocalAttribute::Const => (
+            DiagnosticCode::LocalConstReassign,
+            t!("Cannot reassign to a constant variable"),
+        ),
+        // the loop variables are read-only since Lua 5.5, before that reassigning them is only discouraged
+        LocalAttribute::IterConst
+            if semantic_model.get_emmyrc().get_language_level() == LuaLanguageLevel::Lua55 =>
+        {
+            (
+                DiagnosticCode::LocalConstReassign,
+                t!("Cannot reassign to a constant variable"),
+            )
+        }
+        LocalAttribute::IterConst => (
+            DiagnosticCode::IterVariableReassign,
+            t!("Should not reassign to iter variable"),
+        ),
+        _ => return None,
+    };
+
     let file_id = semantic_model.get_file_id();
     let refs_index = semantic_model.get_db().get_reference_index();
     let local_refs = refs_index.get_local_reference(&file_id)?;
     let decl_refs = local_refs.get_decl_references(decl_id)?;
     for decl_ref in decl_refs {
         if decl_ref.is_write {
-            match attrib {
-                LocalAttribute::Const => {
-                    context.add_diagnostic(
-                        DiagnosticCode::LocalConstReassign,
-                        decl_ref.range.clone(),
-                        t!("Cannot reassign to a constant variable").to_string(),
-                        None,
-                    );
-                }
-                LocalAttribute::IterConst => {
-                    context.add_diagnostic(
-                        DiagnosticCode::IterVariableReassign,
-                        decl_ref.range.clone(),
-                        t!("Should not reassign to iter variable").to_string(),
-                        None,
-                    );
-                }
-                _ => {}
-            }
+            context.add_diagnostic(code, decl_ref.range, message.to_string(), None);
         }
     }
 
@@ -12,6 +12,7 @@ mod deprecated;
 mod discard_returns;
 mod duplicate_require;
 mod duplicate_type;
+mod generic_constraint_mismatch;
 mod incomplete_signature_doc;
 mod local_const_reassign;
 mod missing_fields;
@@ -21,6 +22,7 @@ mod redefined_local;
 mod return_type_mismatch;
 mod syntax_error;
 mod unbalanced_assignments;
+mod undeclared_global;
 mod undefined_doc_param;
 mod undefined_global;
 mod unnecessary_assert;
@@ -29,12 +31,14 @@ mod unused;
 use emmylua_parser::{
     LuaAstNode, LuaClosureExpr, LuaComment, LuaReturnStat, LuaStat, LuaSyntaxKind,
 };
+pub use incomplete_signature_doc::get_signature_doc_state;
 use lsp_types::{Diagnostic, DiagnosticSeverity, DiagnosticTag, NumberOrString};
 use rowan::TextRange;
 use std::sync::Arc;
 
 use crate::{
     db_index::DbIndex, humanize_type, semantic::SemanticModel, FileId, LuaType, RenderLevel,
+    TypeOps,
 };
 
 use super::{
@@ -64,6 +68,7 @@ pub fn check_file(context: &mut DiagnosticContext, semantic_model: &SemanticMode
     run_check::<unused::UnusedChecker>(context, semantic_model);
     run_check::<deprecated::DeprecatedChecker>(context, semantic_model);
     run_check::<undefined_global::UndefinedGlobalChecker>(context, semantic_model);
+    run_check::<undeclared_global::UndeclaredGlobalChecker>(context, semantic_model);
     run_check::<unnecessary_assert::UnnecessaryAssertChecker>(context, semantic_model);
     run_check::<access_invisible::AccessInvisibleChecker>(context, semantic_model);
     run_check::<local_const_reassign::LocalConstReassignChecker>(context, semantic_model);
@@ -85,6 +90,10 @@ pub fn check_file(context: &mut DiagnosticContext, semantic_model: &SemanticMode
     run_check::<check_return_count::CheckReturnCount>(context, semantic_model);
     run_check::<unbalanced_assignments::UnbalancedAssignmentsChecker>(context, semantic_model);
     run_check::<check_param_count::CheckParamCountChecker>(context, semantic_model);
+    run_check::<generic_constraint_mismatch::GenericConstraintMismatchChecker>(
+        context,
+        semantic_model,
+    );
 
     run_check::<code_style::non_literal_expressions_in_assert::NonLiteralExpressionsInAssertChecker>(
         context,
@@ -124,6 +133,37 @@ impl<'a> DiagnosticContext<'a> {
         range: TextRange,
         message: String,
         data: Option<serde_json::Value>,
+    ) {
+        let severity = self.get_severity(code);
+        self.add_diagnostic_with_severity(code, range, message, data, severity);
+    }
+
+    /// report with a severity which replaces the default one of the code,
+    /// the severity configured by user still takes precedence
+    pub fn add_diagnostic_with_default_severity(
+        &mut self,
+        code: DiagnosticCode,
+        range: TextRange,
+        message: String,
+        data: Option<serde_json::Value>,
+        default_severity: DiagnosticSeverity,
+    ) {
+        let severity = self
+            .config
+            .severity
+            .get(&code)
+            .copied()
+            .unwrap_or(default_severity);
+        self.add_diagnostic_with_severity(code, range, message, data, Some(severity));
+    }
+
+    fn add_diagnostic_with_severity(
+        &mut self,
+        code: DiagnosticCode,
+        range: TextRange,
+        message: String,
+        data: Option<serde_json::Value>,
+        severity: Option<DiagnosticSeverity>,
     ) {
         if !self.is_checker_enable_by_code(&code) {
             return;
@@ -145,7 +185,7 @@ impl<'a> DiagnosticContext<'a> {
                     character: 0,
                 },
             }),
-            severity: self.get_severity(code),
+            severity,
             code: Some(NumberOrString::String(code.get_name().to_string())),
             source: Some("EmmyLua".into()),
             tags: self.get_tags(code),
@@ -281,3 +321,45 @@ pub fn humanize_lint_type(db: &DbIndex, typ: &LuaType) -> String {
         _ => humanize_type(db, typ, RenderLevel::Simple),
     }
 }
+
+/// with `strict.nilSafety`, a value that only fails the type check because it may be nil
+/// is reported as `NullableMismatch` instead of a plain type mismatch
+pub fn is_nullable_mismatch(
+    semantic_model: &SemanticModel,
+    source_type: &LuaType,
+    value_type: &LuaType,
+) -> bool {
+    if !semantic_model.get_emmyrc().strict.nil_safety {
+        return false;
+    }
+
+    if source_type.is_optional() || value_type.is_nil() || !value_type.is_nullable() {
+        return false;
+    }
+
+    let non_nil_type = TypeOps::Remove.apply(value_type, &LuaType::Nil);
+    semantic_model
+        .type_check(source_type, &non_nil_type)
+        .is_ok()
+}
+
+pub fn add_nullable_mismatch_diagnostic(
+    context: &mut DiagnosticContext,
+    semantic_model: &SemanticModel,
+    range: TextRange,
+    source_type: &LuaType,
+    value_type: &LuaType,
+) {
+    let db = semantic_model.get_db();
+    context.add_diagnostic(
+        DiagnosticCode::NullableMismatch,
+        range,
+        t!(
+            "`%{found}` may be nil, but `%{source}` is not nullable. narrow it before use",
+            found = humanize_type(db, value_type, RenderLevel::Simple),
+            source = humanize_type(db, source_type, RenderLevel::Simple)
+        )
+        .to_string(),
+        None,
+    );
+}
@@ -9,7 +9,10 @@ use super::{Checker, DiagnosticContext};
 pub struct NeedCheckNilChecker;
 
 impl Checker for NeedCheckNilChecker {
-    const CODES: &[DiagnosticCode] = &[DiagnosticCode::NeedCheckNil];
+    const CODES: &[DiagnosticCode] = &[
+        DiagnosticCode::NeedCheckNil,
+        DiagnosticCode::NullableMismatch,
+    ];
 
     fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
         let root = semantic_model.get_root().clone();
@@ -57,6 +60,21 @@ fn check_index_expr(
     let prefix = index_expr.get_prefix_expr()?;
     let prefix_type = semantic_model.infer_expr(prefix.clone()).ok()?;
     if prefix_type.is_nullable() {
+        // with `strict.nilSafety` indexing a nullable value gets its own code
+        if semantic_model.get_emmyrc().strict.nil_safety {
+            context.add_diagnostic(
+                DiagnosticCode::NullableMismatch,
+                prefix.get_range(),
+                t!(
+                    "`%{name}` may be nil, narrow it before indexing",
+                    name = prefix.syntax().text()
+                )
+                .to_string(),
+                None,
+            );
+            return Some(());
+        }
+
         context.add_diagnostic(
             DiagnosticCode::NeedCheckNil,
             prefix.get_range(),
@@ -6,12 +6,17 @@ use crate::{
     TypeCheckResult,
 };
 
-use super::{Checker, DiagnosticContext};
+use super::{
+    add_nullable_mismatch_diagnostic, is_nullable_mismatch, Checker, DiagnosticContext,
+};
 
 pub struct ParamTypeCheckChecker;
 
 impl Checker for ParamTypeCheckChecker {
-    const CODES: &[DiagnosticCode] = &[DiagnosticCode::ParamTypeNotMatch];
+    const CODES: &[DiagnosticCode] = &[
+        DiagnosticCode::ParamTypeNotMatch,
+        DiagnosticCode::NullableMismatch,
+    ];
 
     /// a simple implementation of param type check, later we will do better
     fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
@@ -135,6 +140,11 @@ fn add_type_check_diagnostic(
     expr_type: &LuaType,
     result: TypeCheckResult,
 ) {
+    if is_nullable_mismatch(semantic_model, param_type, expr_type) {
+        add_nullable_mismatch_diagnostic(context, semantic_model, range, param_type, expr_type);
+        return;
+    }
+
     let db = semantic_model.get_db();
     match result {
         Ok(_) => return,
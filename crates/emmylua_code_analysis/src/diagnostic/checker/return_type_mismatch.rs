@@ -6,12 +6,18 @@ use crate::{
     SemanticModel, SignatureReturnStatus, TypeCheckFailReason, TypeCheckResult,
 };
 
-use super::{get_own_return_stats, Checker, DiagnosticContext};
+use super::{
+    add_nullable_mismatch_diagnostic, get_own_return_stats, is_nullable_mismatch, Checker,
+    DiagnosticContext,
+};
 
 pub struct ReturnTypeMismatch;
 
 impl Checker for ReturnTypeMismatch {
-    const CODES: &[DiagnosticCode] = &[DiagnosticCode::ReturnTypeMismatch];
+    const CODES: &[DiagnosticCode] = &[
+        DiagnosticCode::ReturnTypeMismatch,
+        DiagnosticCode::NullableMismatch,
+    ];
 
     fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
         let root = semantic_model.get_root().clone();
@@ -135,6 +141,11 @@ fn add_type_check_diagnostic(
     expr_type: &LuaType,
     result: TypeCheckResult,
 ) {
+    if is_nullable_mismatch(semantic_model, param_type, expr_type) {
+        add_nullable_mismatch_diagnostic(context, semantic_model, range, param_type, expr_type);
+        return;
+    }
+
     let db = semantic_model.get_db();
     match result {
         Ok(_) => return,
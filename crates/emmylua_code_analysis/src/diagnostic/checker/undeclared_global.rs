@@ -0,0 +1,128 @@
+use emmylua_parser::{LuaAstNode, LuaBlock, LuaNameExpr, LuaStat, LuaSyntaxNode};
+use rowan::TextSize;
+
+use crate::{DiagnosticCode, LuaDeclarationTree, SemanticModel};
+
+use super::{undefined_global::check_self_name, Checker, DiagnosticContext};
+
+pub struct UndeclaredGlobalChecker;
+
+impl Checker for UndeclaredGlobalChecker {
+    const CODES: &[DiagnosticCode] = &[DiagnosticCode::UndeclaredGlobal];
+
+    fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
+        if !has_global_stat(semantic_model) {
+            return;
+        }
+
+        let file_id = semantic_model.get_file_id();
+        let Some(decl_tree) = semantic_model
+            .get_db()
+            .get_decl_index()
+            .get_decl_tree(&file_id)
+        else {
+            return;
+        };
+        let root = semantic_model.get_root().clone();
+        for name_expr in root.descendants::<LuaNameExpr>() {
+            check_name_expr(context, semantic_model, decl_tree, name_expr);
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub(super) struct GlobalScopeState {
+    pub has_declaration: bool,
+    pub collective: bool,
+    pub collective_const: bool,
+}
+
+pub(super) fn has_global_stat(semantic_model: &SemanticModel) -> bool {
+    semantic_model
+        .get_root()
+        .descendants::<LuaStat>()
+        .any(|stat| matches!(stat, LuaStat::GlobalStat(_) | LuaStat::GlobalFuncStat(_)))
+}
+
+fn check_name_expr(
+    context: &mut DiagnosticContext,
+    semantic_model: &SemanticModel,
+    decl_tree: &LuaDeclarationTree,
+    name_expr: LuaNameExpr,
+) -> Option<()> {
+    let name_text = name_expr.get_name_text()?;
+    if name_text == "_" {
+        return Some(());
+    }
+
+    let position = name_expr.get_position();
+    let state = get_global_scope_state(name_expr.syntax(), position);
+    // writes under `global <const> *` are reported by the const reassign checker
+    if !state.has_declaration || state.collective {
+        return Some(());
+    }
+
+    if let Some(decl) = decl_tree.find_local_decl(&name_text, position) {
+        if decl.is_local() || decl.is_explicit_global() {
+            return Some(());
+        }
+    }
+
+    if name_text == "self" && check_self_name(semantic_model, name_expr.clone()).is_some() {
+        return Some(());
+    }
+
+    context.add_diagnostic(
+        DiagnosticCode::UndeclaredGlobal,
+        name_expr.get_range(),
+        t!("undeclared global variable: %{name}", name = name_text).to_string(),
+        None,
+    );
+
+    Some(())
+}
+
+/// collects the `global` statements of the enclosing blocks which are before the position,
+/// the innermost collective declaration wins
+pub(super) fn get_global_scope_state(node: &LuaSyntaxNode, position: TextSize) -> GlobalScopeState {
+    let mut state = GlobalScopeState::default();
+    let mut collective_found = false;
+    for block in node.ancestors().filter_map(LuaBlock::cast) {
+        let mut block_collective = None;
+        for stat in block.get_stats() {
+            if stat.get_position() >= position {
+                break;
+            }
+
+            match stat {
+                LuaStat::GlobalStat(global_stat) => {
+                    if global_stat.get_range().end() > position {
+                        continue;
+                    }
+
+                    state.has_declaration = true;
+                    if global_stat.is_collective() {
+                        let is_const = global_stat
+                            .get_attrib()
+                            .is_some_and(|attrib| attrib.is_const());
+                        block_collective = Some(is_const);
+                    }
+                }
+                LuaStat::GlobalFuncStat(_) => {
+                    state.has_declaration = true;
+                }
+                _ => {}
+            }
+        }
+
+        if !collective_found {
+            if let Some(is_const) = block_collective {
+                collective_found = true;
+                state.collective = true;
+                state.collective_const = is_const;
+            }
+        }
+    }
+
+    state
+}
@@ -93,7 +93,10 @@ fn check_name_expr(
     Some(())
 }
 
-fn check_self_name(semantic_model: &SemanticModel, name_expr: LuaNameExpr) -> Option<()> {
+pub(super) fn check_self_name(
+    semantic_model: &SemanticModel,
+    name_expr: LuaNameExpr,
+) -> Option<()> {
     let closure_expr = name_expr.ancestors::<LuaClosureExpr>();
     for closure_expr in closure_expr {
         let signature_id =
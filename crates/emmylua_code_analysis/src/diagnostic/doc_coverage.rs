@@ -0,0 +1,226 @@
+use emmylua_parser::{LuaAstNode, LuaClosureExpr, LuaStat, VisibilityKind};
+use rowan::TextRange;
+
+use crate::{DbIndex, LuaMemberOwner, LuaSemanticDeclId, LuaSignatureId, LuaType, SemanticModel};
+
+use super::checker::get_signature_doc_state;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DocCoverageCount {
+    pub documented: usize,
+    pub total: usize,
+}
+
+impl DocCoverageCount {
+    fn add(&mut self, documented: bool) {
+        self.total += 1;
+        if documented {
+            self.documented += 1;
+        }
+    }
+
+    pub fn merge(&mut self, other: &DocCoverageCount) {
+        self.documented += other.documented;
+        self.total += other.total;
+    }
+
+    /// Nothing to document counts as fully documented
+    pub fn percent(&self) -> f64 {
+        if self.total == 0 {
+            100.0
+        } else {
+            self.documented as f64 * 100.0 / self.total as f64
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UndocumentedKind {
+    Function,
+    Class,
+    Field,
+    Global,
+}
+
+#[derive(Debug, Clone)]
+pub struct UndocumentedItem {
+    pub kind: UndocumentedKind,
+    pub name: String,
+    pub range: TextRange,
+}
+
+/// The documentation coverage of a file
+#[derive(Debug, Clone, Default)]
+pub struct DocCoverage {
+    /// Global functions and public member functions with a comment and every `@param`/`@return`
+    pub functions: DocCoverageCount,
+    /// Classes and enums with a description
+    pub classes: DocCoverageCount,
+    /// Public fields of the classes and enums with a description
+    pub fields: DocCoverageCount,
+    /// Global variables which are not functions, classes or enums with a description
+    pub globals: DocCoverageCount,
+    pub undocumented: Vec<UndocumentedItem>,
+}
+
+impl DocCoverage {
+    pub fn total(&self) -> DocCoverageCount {
+        let mut total = DocCoverageCount::default();
+        total.merge(&self.functions);
+        total.merge(&self.classes);
+        total.merge(&self.fields);
+        total.merge(&self.globals);
+        total
+    }
+
+    pub fn merge(&mut self, other: &DocCoverage) {
+        self.functions.merge(&other.functions);
+        self.classes.merge(&other.classes);
+        self.fields.merge(&other.fields);
+        self.globals.merge(&other.globals);
+        self.undocumented.extend(other.undocumented.iter().cloned());
+    }
+
+    fn add(&mut self, kind: UndocumentedKind, name: String, range: TextRange, documented: bool) {
+        match kind {
+            UndocumentedKind::Function => self.functions.add(documented),
+            UndocumentedKind::Class => self.classes.add(documented),
+            UndocumentedKind::Field => self.fields.add(documented),
+            UndocumentedKind::Global => self.globals.add(documented),
+        }
+        if !documented {
+            self.undocumented
+                .push(UndocumentedItem { kind, name, range });
+        }
+    }
+}
+
+pub fn get_doc_coverage(semantic_model: &SemanticModel) -> DocCoverage {
+    let mut coverage = DocCoverage::default();
+    let db = semantic_model.get_db();
+    let file_id = semantic_model.get_file_id();
+
+    for closure_expr in semantic_model.get_root().descendants::<LuaClosureExpr>() {
+        let Some(state) = get_signature_doc_state(semantic_model, &closure_expr) else {
+            continue;
+        };
+        let name = match &state.semantic_decl {
+            LuaSemanticDeclId::LuaDecl(_) if state.is_global => state.function_name.clone(),
+            LuaSemanticDeclId::Member(member_id) => {
+                let Some(member) = db.get_member_index().get_member(member_id) else {
+                    continue;
+                };
+                let signature_decl = LuaSemanticDeclId::Signature(LuaSignatureId::from_closure(
+                    file_id,
+                    &closure_expr,
+                ));
+                if !is_public(db, &state.semantic_decl) || !is_public(db, &signature_decl) {
+                    continue;
+                }
+                get_member_name(db, &member.get_owner(), &member.get_key().to_path())
+            }
+            // local functions and callbacks are not part of the api
+            _ => continue,
+        };
+
+        let range = closure_expr
+            .get_parent::<LuaStat>()
+            .map(|stat| stat.get_range())
+            .unwrap_or(closure_expr.get_range());
+        coverage.add(UndocumentedKind::Function, name, range, state.is_complete());
+    }
+
+    let mut types = db
+        .get_type_index()
+        .get_all_types()
+        .into_iter()
+        .filter(|type_decl| type_decl.is_class() || type_decl.is_enum())
+        .filter_map(|type_decl| {
+            let location = type_decl.get_locations().first()?;
+            (location.file_id == file_id).then_some((type_decl, location.range))
+        })
+        .collect::<Vec<_>>();
+    types.sort_by_key(|(_, range)| range.start());
+    for (type_decl, range) in types {
+        let type_id = type_decl.get_id();
+        let documented = has_description(db, &LuaSemanticDeclId::TypeDecl(type_id.clone()));
+        coverage.add(
+            UndocumentedKind::Class,
+            type_decl.get_full_name().to_string(),
+            range,
+            documented,
+        );
+
+        let owner = LuaMemberOwner::Type(type_id);
+        let members = db
+            .get_member_index()
+            .get_sorted_members(&owner)
+            .unwrap_or_default();
+        for member in members {
+            if member.get_file_id() != file_id || member.get_decl_type().is_function() {
+                continue;
+            }
+            let member_decl = LuaSemanticDeclId::Member(member.get_id());
+            if !is_public(db, &member_decl) {
+                continue;
+            }
+            coverage.add(
+                UndocumentedKind::Field,
+                get_member_name(db, &owner, &member.get_key().to_path()),
+                member.get_range(),
+                has_description(db, &member_decl),
+            );
+        }
+    }
+
+    if let Some(decl_tree) = db.get_decl_index().get_decl_tree(&file_id) {
+        let mut globals = decl_tree
+            .get_decls()
+            .values()
+            .filter(|decl| decl.is_global())
+            .collect::<Vec<_>>();
+        globals.sort_by_key(|decl| decl.get_position());
+        for decl in globals {
+            match decl.get_type() {
+                Some(LuaType::Def(_) | LuaType::Ref(_)) => continue,
+                Some(typ) if typ.is_function() => continue,
+                _ => {}
+            }
+            coverage.add(
+                UndocumentedKind::Global,
+                decl.get_name().to_string(),
+                decl.get_range(),
+                has_description(db, &LuaSemanticDeclId::LuaDecl(decl.get_id())),
+            );
+        }
+    }
+
+    coverage
+}
+
+fn get_member_name(db: &DbIndex, owner: &LuaMemberOwner, key: &str) -> String {
+    match owner {
+        LuaMemberOwner::Type(type_id) => match db.get_type_index().get_type_decl(type_id) {
+            Some(type_decl) => format!("{}.{}", type_decl.get_name(), key),
+            None => key.to_string(),
+        },
+        _ => key.to_string(),
+    }
+}
+
+fn is_public(db: &DbIndex, semantic_decl: &LuaSemanticDeclId) -> bool {
+    match db.get_property_index().get_property(semantic_decl) {
+        Some(property) => matches!(
+            property.visibility,
+            None | Some(VisibilityKind::Public) | Some(VisibilityKind::None)
+        ),
+        None => true,
+    }
+}
+
+fn has_description(db: &DbIndex, semantic_decl: &LuaSemanticDeclId) -> bool {
+    db.get_property_index()
+        .get_property(semantic_decl)
+        .and_then(|property| property.description.as_ref())
+        .is_some_and(|description| !description.trim().is_empty())
+}
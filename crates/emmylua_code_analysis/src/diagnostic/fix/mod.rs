@@ -0,0 +1,74 @@
+mod undefined_doc_param;
+mod unused;
+
+use std::str::FromStr;
+
+use lsp_types::{Diagnostic, NumberOrString, TextEdit};
+
+use crate::{LuaDocument, SemanticModel};
+
+use super::DiagnosticCode;
+
+/// A fix which can be applied without user interaction
+#[derive(Debug, Clone)]
+pub struct DiagnosticFix {
+    pub code: DiagnosticCode,
+    pub title: String,
+    pub edits: Vec<TextEdit>,
+}
+
+/// Codes whose fix never changes the behavior of the code
+pub fn is_code_fixable(code: DiagnosticCode) -> bool {
+    matches!(
+        code,
+        DiagnosticCode::Unused | DiagnosticCode::UndefinedDocParam
+    )
+}
+
+pub fn get_diagnostic_fix(
+    semantic_model: &SemanticModel,
+    diagnostic: &Diagnostic,
+) -> Option<DiagnosticFix> {
+    let Some(NumberOrString::String(code)) = &diagnostic.code else {
+        return None;
+    };
+    let code = DiagnosticCode::from_str(code).ok()?;
+    let (title, edits) = match code {
+        DiagnosticCode::Unused => unused::get_fix(semantic_model, diagnostic)?,
+        DiagnosticCode::UndefinedDocParam => {
+            undefined_doc_param::get_fix(semantic_model, diagnostic)?
+        }
+        _ => return None,
+    };
+
+    Some(DiagnosticFix { code, title, edits })
+}
+
+/// Apply the edits to the text of the document, an edit which overlaps with
+/// an edit applied before it is skipped. Returns the new text and the number of applied edits.
+pub fn apply_text_edits(document: &LuaDocument, edits: Vec<TextEdit>) -> (String, usize) {
+    let mut ranged_edits = edits
+        .into_iter()
+        .filter_map(|edit| Some((document.to_rowan_range(edit.range)?, edit.new_text)))
+        .collect::<Vec<_>>();
+    ranged_edits.sort_by_key(|(range, _)| (range.start(), range.end()));
+
+    let text = document.get_text();
+    let mut new_text = String::with_capacity(text.len());
+    let mut last_end = 0;
+    let mut applied = 0;
+    for (range, replace) in ranged_edits {
+        let start: usize = range.start().into();
+        let end: usize = range.end().into();
+        if start < last_end {
+            continue;
+        }
+        new_text.push_str(&text[last_end..start]);
+        new_text.push_str(&replace);
+        last_end = end;
+        applied += 1;
+    }
+    new_text.push_str(&text[last_end..]);
+
+    (new_text, applied)
+}
@@ -0,0 +1,51 @@
+use emmylua_parser::{LuaAstNode, LuaDocTagParam, LuaTokenKind};
+use lsp_types::{Diagnostic, TextEdit};
+
+use crate::SemanticModel;
+
+// remove the line of the `---@param` tag, it is only a comment
+pub fn get_fix(
+    semantic_model: &SemanticModel,
+    diagnostic: &Diagnostic,
+) -> Option<(String, Vec<TextEdit>)> {
+    let document = semantic_model.get_document();
+    let range = document.to_rowan_range(diagnostic.range)?;
+    let token = semantic_model
+        .get_root()
+        .syntax()
+        .token_at_offset(range.start())
+        .right_biased()?;
+    if token.kind() != LuaTokenKind::TkName.into() {
+        return None;
+    }
+    let tag = token.parent_ancestors().find_map(LuaDocTagParam::cast)?;
+
+    // only remove the whole line when the tag is the only content of it
+    let tag_range = tag.get_range();
+    let line = document.get_line(tag_range.start())?;
+    if document.get_line(tag_range.end())? != line {
+        return None;
+    }
+    let line_range = document.get_line_range(line)?;
+    let line_text = document.get_text_slice(line_range).trim();
+    if !line_text.starts_with("---") {
+        return None;
+    }
+    let tag_start: usize = (tag_range.start() - line_range.start()).into();
+    let prefix = &document.get_text_slice(line_range)[..tag_start];
+    if prefix.trim() != "---@" && prefix.trim() != "---" {
+        return None;
+    }
+
+    Some((
+        t!(
+            "Remove doc param `%{name}`",
+            name = document.get_text_slice(range)
+        )
+        .to_string(),
+        vec![TextEdit {
+            range: document.to_lsp_range(line_range)?,
+            new_text: String::new(),
+        }],
+    ))
+}
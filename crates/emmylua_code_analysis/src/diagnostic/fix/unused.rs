@@ -0,0 +1,71 @@
+use emmylua_parser::{LuaAstNode, LuaBlock, LuaNameExpr};
+use lsp_types::{Diagnostic, TextEdit};
+use rowan::TextRange;
+
+use crate::SemanticModel;
+
+// prefix the unused name with `_`, nothing refers to it so the behavior is unchanged
+pub fn get_fix(
+    semantic_model: &SemanticModel,
+    diagnostic: &Diagnostic,
+) -> Option<(String, Vec<TextEdit>)> {
+    let document = semantic_model.get_document();
+    let range = document.to_rowan_range(diagnostic.range)?;
+    let name = document.get_text_slice(range);
+    if name.is_empty() || name.starts_with('_') || !is_identifier(name) {
+        return None;
+    }
+
+    let new_name = format!("_{}", name);
+    if is_shadowing(semantic_model, range, &new_name) {
+        return None;
+    }
+
+    let start = diagnostic.range.start;
+    Some((
+        t!("Rename `%{name}` to `_%{name}`", name = name).to_string(),
+        vec![TextEdit {
+            range: lsp_types::Range::new(start, start),
+            new_text: "_".to_string(),
+        }],
+    ))
+}
+
+fn is_identifier(name: &str) -> bool {
+    name.chars().all(|c| c.is_alphanumeric() || c == '_')
+}
+
+/// whether a `new_name` used after the declaration would resolve to the renamed declaration,
+/// the enclosing block is used as the scope so the check may be stricter than needed
+fn is_shadowing(semantic_model: &SemanticModel, decl_range: TextRange, new_name: &str) -> bool {
+    let root = semantic_model.get_root();
+    let Some(block) = root
+        .syntax()
+        .covering_element(decl_range)
+        .ancestors()
+        .find_map(LuaBlock::cast)
+    else {
+        return true;
+    };
+    let Some(decl_tree) = semantic_model
+        .get_db()
+        .get_decl_index()
+        .get_decl_tree(&semantic_model.get_file_id())
+    else {
+        return true;
+    };
+
+    let decl_position = decl_range.start();
+    let shadowing = block
+        .descendants::<LuaNameExpr>()
+        .filter(|name_expr| name_expr.get_position() > decl_position)
+        .filter(|name_expr| name_expr.get_name_text().as_deref() == Some(new_name))
+        .any(|name_expr| {
+            // a declaration of the new name after the renamed one still wins
+            match decl_tree.find_local_decl(new_name, name_expr.get_position()) {
+                Some(decl) => decl.is_global() || decl.get_position() < decl_position,
+                None => true,
+            }
+        });
+    shadowing
+}
@@ -90,6 +90,12 @@ pub enum DiagnosticCode {
     UnbalancedAssignments,
     /// unnecessary-assert
     UnnecessaryAssert,
+    /// Generic constraint mismatch
+    GenericConstraintMismatch,
+    /// Nullable mismatch
+    NullableMismatch,
+    /// Undeclared global
+    UndeclaredGlobal,
 
     #[serde(other)]
     None,
@@ -117,13 +123,13 @@ pub fn get_default_severity(code: DiagnosticCode) -> DiagnosticSeverity {
         DiagnosticCode::DuplicateType => DiagnosticSeverity::WARNING,
         DiagnosticCode::AnnotationUsageError => DiagnosticSeverity::ERROR,
         DiagnosticCode::RedefinedLocal => DiagnosticSeverity::HINT,
+        DiagnosticCode::UndeclaredGlobal => DiagnosticSeverity::ERROR,
         _ => DiagnosticSeverity::WARNING,
     }
 }
 
 pub fn is_code_default_enable(code: &DiagnosticCode) -> bool {
     match code {
-        DiagnosticCode::InjectFieldFail => false,
         DiagnosticCode::DisableGlobalDefine => false,
         // DiagnosticCode::UndefinedField => false,
         DiagnosticCode::IterVariableReassign => false,
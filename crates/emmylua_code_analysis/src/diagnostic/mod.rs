@@ -1,8 +1,14 @@
 mod checker;
+mod doc_coverage;
+mod fix;
 mod lua_diagnostic;
 mod lua_diagnostic_code;
 mod lua_diagnostic_config;
 mod test;
 
+pub use doc_coverage::{
+    get_doc_coverage, DocCoverage, DocCoverageCount, UndocumentedItem, UndocumentedKind,
+};
+pub use fix::{apply_text_edits, get_diagnostic_fix, is_code_fixable, DiagnosticFix};
 pub use lua_diagnostic::LuaDiagnostic;
-pub use lua_diagnostic_code::DiagnosticCode;
+pub use lua_diagnostic_code::{get_default_severity, is_code_default_enable, DiagnosticCode};
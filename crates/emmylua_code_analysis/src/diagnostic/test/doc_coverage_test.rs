@@ -0,0 +1,88 @@
+#[cfg(test)]
+mod test {
+    use crate::{get_doc_coverage, DocCoverageCount, UndocumentedKind, VirtualWorkspace};
+
+    #[test]
+    fn test_doc_coverage() {
+        let mut ws = VirtualWorkspace::new();
+        let file_id = ws.def(
+            r#"
+            --- A point
+            ---@class Point
+            ---@field x number the x coordinate
+            ---@field y number
+            local Point = {}
+
+            --- Create a point
+            ---@param x number
+            ---@param y number
+            ---@return Point
+            function Point.new(x, y)
+                return setmetatable({ x = x, y = y }, Point)
+            end
+
+            ---@private
+            function Point:reset()
+            end
+
+            function Point:len()
+                return 0
+            end
+
+            local function helper(a)
+                return a
+            end
+
+            --- The version
+            VERSION = "1.0"
+            DEBUG = false
+            "#,
+        );
+
+        let semantic_model = ws.analysis.compilation.get_semantic_model(file_id).unwrap();
+        let coverage = get_doc_coverage(&semantic_model);
+        assert_eq!(
+            coverage.functions,
+            DocCoverageCount {
+                documented: 1,
+                total: 2
+            }
+        );
+        assert_eq!(
+            coverage.classes,
+            DocCoverageCount {
+                documented: 1,
+                total: 1
+            }
+        );
+        assert_eq!(
+            coverage.fields,
+            DocCoverageCount {
+                documented: 1,
+                total: 2
+            }
+        );
+        assert_eq!(
+            coverage.globals,
+            DocCoverageCount {
+                documented: 1,
+                total: 2
+            }
+        );
+
+        let undocumented = coverage
+            .undocumented
+            .iter()
+            .map(|item| (item.kind, item.name.as_str()))
+            .collect::<Vec<_>>();
+        assert!(undocumented.contains(&(UndocumentedKind::Function, "Point.len")));
+        assert!(undocumented.contains(&(UndocumentedKind::Field, "Point.y")));
+        assert!(undocumented.contains(&(UndocumentedKind::Global, "DEBUG")));
+    }
+
+    #[test]
+    fn test_empty_coverage() {
+        let coverage = DocCoverageCount::default();
+        assert_eq!(coverage.percent(), 100.0);
+    }
+}
@@ -0,0 +1,129 @@
+#[cfg(test)]
+mod test {
+    use crate::{DiagnosticCode, VirtualWorkspace};
+
+    #[test]
+    fn test_fix_unused() {
+        let mut ws = VirtualWorkspace::new();
+        let file_id = ws.def(
+            r#"
+            local a = 1
+            local function f(x, _y)
+                return 1
+            end
+            print(f)
+            "#,
+        );
+
+        let fixed = ws.analysis.fix_files(&[file_id], None);
+        assert_eq!(
+            fixed.get(&file_id).map(|text| text.as_str()),
+            Some(
+                r#"
+            local _a = 1
+            local function f(_x, _y)
+                return 1
+            end
+            print(f)
+            "#
+            )
+        );
+    }
+
+    #[test]
+    fn test_fix_unused_shadowing() {
+        let mut ws = VirtualWorkspace::new();
+        let file_id = ws.def(
+            r#"
+            local _x = 1
+            local x = 2
+            local y = 3
+            print(_x)
+            "#,
+        );
+
+        let fixed = ws.analysis.fix_files(&[file_id], None);
+        assert_eq!(
+            fixed.get(&file_id).map(|text| text.as_str()),
+            Some(
+                r#"
+            local _x = 1
+            local x = 2
+            local _y = 3
+            print(_x)
+            "#
+            )
+        );
+    }
+
+    #[test]
+    fn test_fix_undefined_doc_param() {
+        let mut ws = VirtualWorkspace::new();
+        let file_id = ws.def(
+            r#"
+            ---@param a number
+            ---@param b number
+            local function f(a)
+                return a
+            end
+            print(f)
+            "#,
+        );
+
+        let fixed = ws.analysis.fix_files(&[file_id], None);
+        assert_eq!(
+            fixed.get(&file_id).map(|text| text.as_str()),
+            Some(
+                r#"
+            ---@param a number
+            local function f(a)
+                return a
+            end
+            print(f)
+            "#
+            )
+        );
+    }
+
+    #[test]
+    fn test_fix_only_selected_codes() {
+        let mut ws = VirtualWorkspace::new();
+        let file_id = ws.def(
+            r#"
+            ---@param b number
+            local function f()
+                local a = 1
+            end
+            print(f)
+            "#,
+        );
+
+        let fixed = ws
+            .analysis
+            .fix_files(&[file_id], Some(&[DiagnosticCode::UndefinedDocParam]));
+        assert_eq!(
+            fixed.get(&file_id).map(|text| text.as_str()),
+            Some(
+                r#"
+            local function f()
+                local a = 1
+            end
+            print(f)
+            "#
+            )
+        );
+    }
+
+    #[test]
+    fn test_fix_nothing() {
+        let mut ws = VirtualWorkspace::new();
+        let file_id = ws.def(
+            r#"
+            local a = 1
+            print(a)
+            "#,
+        );
+
+        assert!(ws.analysis.fix_files(&[file_id], None).is_empty());
+    }
+}
@@ -0,0 +1,59 @@
+#[cfg(test)]
+mod test {
+    use crate::{DiagnosticCode, VirtualWorkspace};
+
+    #[test]
+    fn test_generic_constraint_mismatch() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@class Animal
+            ---@field name string
+
+            ---@class Dog: Animal
+
+            ---@generic T: Animal
+            ---@param a T
+            ---@return T
+            function identity(a)
+                return a
+            end
+            "#,
+        );
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::GenericConstraintMismatch,
+            r#"
+            identity(1)
+            "#
+        ));
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::GenericConstraintMismatch,
+            r#"
+            ---@type Dog
+            local dog
+            identity(dog)
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_generic_without_constraint() {
+        let mut ws = VirtualWorkspace::new();
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::GenericConstraintMismatch,
+            r#"
+            ---@generic T
+            ---@param a T
+            ---@return T
+            function identity(a)
+                return a
+            end
+
+            identity(1)
+            "#
+        ));
+    }
+}
@@ -0,0 +1,128 @@
+#[cfg(test)]
+mod test {
+    use crate::{DiagnosticCode, VirtualWorkspace};
+
+    #[test]
+    fn test_exact_class_inject_outside_file() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@class (exact) ExactPoint
+            ---@field x number
+            ExactPoint = {}
+
+            ExactPoint.y = 1
+            "#,
+        );
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::InjectFieldFail,
+            r#"
+            ExactPoint.z = 1
+            "#
+        ));
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::InjectFieldFail,
+            r#"
+            ExactPoint.x = 2
+            ExactPoint.y = 2
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_exact_class_inject_in_declaring_file() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(ws.check_code_for(
+            DiagnosticCode::InjectFieldFail,
+            r#"
+            ---@class (exact) ExactLocal
+            local M = {}
+
+            M.value = 1
+
+            function M.get()
+                return M.value
+            end
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_exact_class_inject_by_reference() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::InjectFieldFail,
+            r#"
+            ---@class (exact) ExactRef
+            ---@field a number
+
+            ---@type ExactRef
+            local t
+            t.b = 1
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_exact_class_undefined_read() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UndefinedField,
+            r#"
+            ---@class (exact) ExactRead
+            ---@field a number
+
+            ---@type ExactRead
+            local t
+            local b = t.b
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_exact_class_table_extra_keys() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@class (exact) ExactOption
+            ---@field name string
+            ---@field size? integer
+
+            ---@param opt ExactOption
+            function use_exact_option(opt) end
+            "#,
+        );
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::AssignTypeMismatch,
+            r#"
+            ---@type ExactOption
+            local opt = { name = "a", sise = 1 }
+            "#
+        ));
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::AssignTypeMismatch,
+            r#"
+            ---@type ExactOption
+            local opt = { name = "a", size = 1 }
+            "#
+        ));
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::ParamTypeNotMatch,
+            r#"
+            use_exact_option({ name = "a", sise = 1 })
+            "#
+        ));
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::ParamTypeNotMatch,
+            r#"
+            use_exact_option({ name = "a", size = 1 })
+            "#
+        ));
+    }
+}
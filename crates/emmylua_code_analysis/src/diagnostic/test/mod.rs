@@ -4,19 +4,25 @@ mod await_in_sync_test;
 mod check_return_count_test;
 mod code_style;
 mod disable_line_test;
+mod doc_coverage_test;
 mod duplicate_doc_field_test;
 mod duplicate_require_test;
+mod fix_test;
+mod generic_constraint_mismatch_test;
 mod incomplete_signature_doc_test;
+mod inject_field_fail_test;
 mod inject_field_test;
 mod missing_fields_test;
 mod missing_parameter_test;
 mod need_check_nil_test;
+mod nullable_mismatch_test;
 mod param_type_check_test;
 mod redefined_local_test;
 mod redundant_parameter_test;
 mod return_type_mismatch_test;
 mod syntax_error_test;
 mod unbalanced_assignments_test;
+mod undeclared_global_test;
 mod undefined_doc_param_test;
 mod undefined_field_test;
 mod undefined_global_test;
@@ -0,0 +1,163 @@
+#[cfg(test)]
+mod test {
+    use crate::{DiagnosticCode, Emmyrc, VirtualWorkspace};
+
+    fn nil_safety_workspace() -> VirtualWorkspace {
+        let mut ws = VirtualWorkspace::new();
+        let mut emmyrc = Emmyrc::default();
+        emmyrc.strict.nil_safety = true;
+        ws.analysis.update_config(emmyrc.into());
+        ws
+    }
+
+    #[test]
+    fn test_param() {
+        let mut ws = nil_safety_workspace();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::NullableMismatch,
+            r#"
+            ---@param a string
+            local function f(a) end
+
+            ---@type string?
+            local s
+            f(s)
+            "#
+        ));
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::NullableMismatch,
+            r#"
+            ---@param a string
+            local function f(a) end
+
+            ---@type string?
+            local s
+            if s then
+                f(s)
+            end
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_return() {
+        let mut ws = nil_safety_workspace();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::NullableMismatch,
+            r#"
+            ---@type string?
+            local s
+
+            ---@return string
+            local function f()
+                return s
+            end
+            "#
+        ));
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::NullableMismatch,
+            r#"
+            ---@type string?
+            local s
+
+            ---@return string?
+            local function f()
+                return s
+            end
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_assign_field() {
+        let mut ws = nil_safety_workspace();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::NullableMismatch,
+            r#"
+            ---@class NilSafetyA
+            ---@field x string
+
+            ---@type string?
+            local s
+            ---@type NilSafetyA
+            local a
+            a.x = s
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_index() {
+        let mut ws = nil_safety_workspace();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::NullableMismatch,
+            r#"
+            ---@class NilSafetyB
+            ---@field x string
+
+            ---@type NilSafetyB?
+            local b
+            local _ = b.x
+            "#
+        ));
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::NullableMismatch,
+            r#"
+            ---@type NilSafetyB?
+            local b
+            if b then
+                local _ = b.x
+            end
+            "#
+        ));
+
+        // reported once, by the nil safety code
+        assert!(ws.check_code_for(
+            DiagnosticCode::NeedCheckNil,
+            r#"
+            ---@type NilSafetyB?
+            local b
+            local _ = b.x
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_disabled_by_default() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(ws.check_code_for(
+            DiagnosticCode::NullableMismatch,
+            r#"
+            ---@param a string
+            local function f(a) end
+
+            ---@type string?
+            local s
+            f(s)
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_nullable_alias() {
+        let mut ws = nil_safety_workspace();
+        assert!(ws.check_code_for(
+            DiagnosticCode::NullableMismatch,
+            r#"
+            ---@alias MaybeName string?
+
+            ---@param a string
+            local function f(a) end
+
+            ---@type MaybeName
+            local s
+            if s then
+                f(s)
+            end
+            "#
+        ));
+    }
+}
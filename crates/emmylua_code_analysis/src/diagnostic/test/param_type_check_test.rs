@@ -511,4 +511,93 @@ mod test {
         "#
         ));
     }
+
+    #[test]
+    fn test_callback_missing_return() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@param f fun(): number
+            function take_number_callback(f)
+            end
+            "#,
+        );
+        assert!(ws.check_code_for(
+            DiagnosticCode::ParamTypeNotMatch,
+            r#"
+            take_number_callback(function() end)
+            "#
+        ));
+        assert!(ws.check_code_for(
+            DiagnosticCode::ParamTypeNotMatch,
+            r#"
+            take_number_callback(function() return 1 end)
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_unannotated_generic_class_param() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@class List<T>
+
+            ---@param list List<number>
+            function take_number_list(list)
+            end
+            "#,
+        );
+        assert!(!ws.check_code_for(
+            DiagnosticCode::ParamTypeNotMatch,
+            r#"
+            ---@type List<integer>
+            local list
+            take_number_list(list)
+            "#
+        ));
+        assert!(ws.check_code_for(
+            DiagnosticCode::ParamTypeNotMatch,
+            r#"
+            ---@type List<number>
+            local list
+            take_number_list(list)
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_narrowed_nullable_alias() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(ws.check_code_for(
+            DiagnosticCode::ParamTypeNotMatch,
+            r#"
+            ---@alias MaybeCount integer?
+
+            ---@param n integer
+            local function take_integer(n) end
+
+            ---@type MaybeCount
+            local count
+            if count then
+                take_integer(count)
+            end
+            if type(count) ~= "nil" then
+                take_integer(count)
+            end
+            "#
+        ));
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::ParamTypeNotMatch,
+            r#"
+            ---@param n integer
+            local function take_integer(n) end
+
+            ---@type MaybeCount
+            local other
+            take_integer(other)
+            "#
+        ));
+    }
 }
@@ -0,0 +1,182 @@
+#[cfg(test)]
+mod test {
+    use lsp_types::NumberOrString;
+    use tokio_util::sync::CancellationToken;
+
+    use crate::{DiagnosticCode, Emmyrc, EmmyrcLuaVersion, VirtualWorkspace};
+
+    fn new_lua55_workspace() -> VirtualWorkspace {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        let mut config = Emmyrc::default();
+        config.runtime.version = EmmyrcLuaVersion::Lua55;
+        ws.analysis.update_config(config.into());
+        ws
+    }
+
+    #[test]
+    fn test_undeclared_global() {
+        let mut ws = new_lua55_workspace();
+        assert!(ws.check_code_for(
+            DiagnosticCode::UndeclaredGlobal,
+            r#"
+            x = 1
+            print(x)
+            "#
+        ));
+        assert!(ws.check_code_for(
+            DiagnosticCode::UndeclaredGlobal,
+            r#"
+            global x, print
+            local y = 1
+            x = y
+            print(x)
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UndeclaredGlobal,
+            r#"
+            global x
+            x = 1
+            print(x)
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UndeclaredGlobal,
+            r#"
+            global function f()
+                return z
+            end
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_global_scope() {
+        let mut ws = new_lua55_workspace();
+        assert!(ws.check_code_for(
+            DiagnosticCode::UndeclaredGlobal,
+            r#"
+            print(1)
+            do
+                global x
+                x = 1
+            end
+            print(2)
+            "#
+        ));
+        assert!(ws.check_code_for(
+            DiagnosticCode::UndeclaredGlobal,
+            r#"
+            global x
+            global *
+            print(x, y)
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_const_global() {
+        let mut ws = new_lua55_workspace();
+        assert!(ws.check_code_for(
+            DiagnosticCode::UndeclaredGlobal,
+            r#"
+            global <const> *
+            print(string.format("%d", 1))
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::LocalConstReassign,
+            r#"
+            global <const> *
+            print = nil
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::LocalConstReassign,
+            r#"
+            global <const> x = 1
+            x = 2
+            "#
+        ));
+        assert!(ws.check_code_for(
+            DiagnosticCode::LocalConstReassign,
+            r#"
+            y = 1
+            do
+                global *
+                y = 2
+            end
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_collective_const_reported_once() {
+        let mut ws = new_lua55_workspace();
+        let file_id = ws.def(
+            r#"
+            z = 1
+            global <const> *
+            z = 2
+            do
+                w = 3
+            end
+            "#,
+        );
+        let diagnostics = ws
+            .analysis
+            .diagnose_file(file_id, CancellationToken::new())
+            .unwrap_or_default();
+        let code = Some(NumberOrString::String(
+            DiagnosticCode::LocalConstReassign.get_name().to_string(),
+        ));
+        let mut lines = diagnostics
+            .iter()
+            .filter(|diagnostic| diagnostic.code == code)
+            .map(|diagnostic| diagnostic.range.start.line)
+            .collect::<Vec<_>>();
+        lines.sort();
+        assert_eq!(lines, vec![3, 5]);
+    }
+
+    #[test]
+    fn test_for_variable_reassign() {
+        let mut ws = new_lua55_workspace();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::LocalConstReassign,
+            r#"
+            for i = 1, 10 do
+                i = i + 1
+            end
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::LocalConstReassign,
+            r#"
+            for k, v in pairs({}) do
+                v = 1
+            end
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_latest_defaults_to_lua54() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        assert!(ws.check_code_for(
+            DiagnosticCode::LocalConstReassign,
+            r#"
+            for i = 1, 3 do
+                i = i + 1
+            end
+            "#
+        ));
+        assert!(ws.check_code_for(
+            DiagnosticCode::UndeclaredGlobal,
+            r#"
+            global x
+            print(y)
+            "#
+        ));
+    }
+}
@@ -6,6 +6,7 @@ mod locale;
 mod profile;
 mod resources;
 mod semantic;
+mod structural_search;
 mod test_lib;
 mod vfs;
 
@@ -19,7 +20,13 @@ use lsp_types::Uri;
 pub use profile::Profile;
 use resources::load_resource_std;
 pub use semantic::*;
-use std::{collections::HashSet, path::PathBuf, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    path::PathBuf,
+    str::FromStr,
+    sync::Arc,
+};
+pub use structural_search::*;
 pub use test_lib::VirtualWorkspace;
 use tokio_util::sync::CancellationToken;
 pub use vfs::*;
@@ -99,6 +106,30 @@ impl EmmyLuaAnalysis {
             .add_workspace_root(root, id);
     }
 
+    /// Add the workspace folders as main workspaces, together with the `workspaceRoots`
+    /// and `library` of the config. Returns all the folders whose files should be loaded.
+    pub fn add_workspaces_by_config(&mut self, workspace_folders: Vec<PathBuf>) -> Vec<PathBuf> {
+        let emmyrc = self.emmyrc.clone();
+        let mut workspace_folders = workspace_folders;
+        for workspace_root in &workspace_folders {
+            log::info!("add workspace root: {:?}", workspace_root);
+            self.add_main_workspace(workspace_root.clone());
+        }
+
+        for workspace_root in &emmyrc.workspace.workspace_roots {
+            log::info!("add workspace root: {:?}", workspace_root);
+            self.add_main_workspace(PathBuf::from(workspace_root));
+        }
+
+        for lib in &emmyrc.workspace.library {
+            log::info!("add library: {:?}", lib);
+            self.add_library_workspace(PathBuf::from(lib));
+            workspace_folders.push(PathBuf::from(lib));
+        }
+
+        workspace_folders
+    }
+
     pub fn update_file_by_uri(&mut self, uri: &Uri, text: Option<String>) -> Option<FileId> {
         let is_removed = text.is_none();
         let file_id = self
@@ -175,6 +206,96 @@ impl EmmyLuaAnalysis {
             .diagnose_file(&self.compilation, file_id, cancel_token)
     }
 
+    /// Apply the fixes of the fixable diagnostics and reanalyze until nothing changes.
+    /// `codes` restricts the fixed diagnostics. Returns the fixed text of the changed files.
+    pub fn fix_files(
+        &mut self,
+        file_ids: &[FileId],
+        codes: Option<&[DiagnosticCode]>,
+    ) -> HashMap<FileId, String> {
+        const MAX_FIX_ROUNDS: usize = 10;
+
+        let mut fixed_files = HashMap::new();
+        for _ in 0..MAX_FIX_ROUNDS {
+            let mut updates = Vec::new();
+            for file_id in file_ids {
+                let Some(diagnostics) = self.diagnose_file(*file_id, CancellationToken::new())
+                else {
+                    continue;
+                };
+                let Some(semantic_model) = self.compilation.get_semantic_model(*file_id) else {
+                    continue;
+                };
+
+                let edits = diagnostics
+                    .iter()
+                    .filter(|diagnostic| {
+                        let Some(lsp_types::NumberOrString::String(code)) = &diagnostic.code else {
+                            return false;
+                        };
+                        let code = DiagnosticCode::from_str(code).unwrap_or(DiagnosticCode::None);
+                        is_code_fixable(code) && codes.is_none_or(|codes| codes.contains(&code))
+                    })
+                    .filter_map(|diagnostic| get_diagnostic_fix(&semantic_model, diagnostic))
+                    .flat_map(|fix| fix.edits)
+                    .collect::<Vec<_>>();
+                if edits.is_empty() {
+                    continue;
+                }
+
+                let document = semantic_model.get_document();
+                let (new_text, applied) = apply_text_edits(&document, edits);
+                if applied == 0 || new_text == document.get_text() {
+                    continue;
+                }
+
+                updates.push((document.get_uri(), Some(new_text.clone())));
+                fixed_files.insert(*file_id, new_text);
+            }
+
+            if updates.is_empty() {
+                break;
+            }
+            self.update_files_by_uri(updates);
+        }
+
+        fixed_files
+    }
+
+    /// Replace the code matching `search` with `replace` in the files, see `StructuralPattern`.
+    /// Returns the new text of the changed files, and how many matches were skipped because of
+    /// a comment.
+    pub fn structural_replace_files(
+        &self,
+        file_ids: &[FileId],
+        search: &str,
+        replace: &str,
+    ) -> Result<(HashMap<FileId, String>, usize), String> {
+        let pattern = StructuralPattern::parse(search, &self.emmyrc)?;
+        let template = StructuralTemplate::parse(replace, &pattern, &self.emmyrc)?;
+
+        let mut replaced_files = HashMap::new();
+        let mut skipped = 0;
+        for file_id in file_ids {
+            let Some(semantic_model) = self.compilation.get_semantic_model(*file_id) else {
+                continue;
+            };
+            let result = structural_replace(&semantic_model, &pattern, &template);
+            skipped += result.skipped;
+            if result.edits.is_empty() {
+                continue;
+            }
+
+            let document = semantic_model.get_document();
+            let (new_text, _) = apply_text_edits(&document, result.edits);
+            if new_text != document.get_text() {
+                replaced_files.insert(*file_id, new_text);
+            }
+        }
+
+        Ok((replaced_files, skipped))
+    }
+
     pub fn reindex(&mut self) {
         let module = self.compilation.get_db().get_module_index();
         let std_file_ids = module.get_std_file_ids();
@@ -20,6 +20,21 @@ pub fn instantiate_func_generic(
     func: &LuaFunctionType,
     call_expr: LuaCallExpr,
 ) -> Result<LuaFunctionType, InferFailReason> {
+    let substitutor = infer_func_generic_substitutor(db, cache, func, call_expr)?;
+    if let LuaType::DocFunction(f) = instantiate_doc_function(db, func, &substitutor) {
+        Ok(f.deref().clone())
+    } else {
+        Ok(func.clone())
+    }
+}
+
+/// infer the generic arguments of `func` from the arguments of `call_expr`
+pub fn infer_func_generic_substitutor(
+    db: &DbIndex,
+    cache: &mut LuaInferCache,
+    func: &LuaFunctionType,
+    call_expr: LuaCallExpr,
+) -> Result<TypeSubstitutor, InferFailReason> {
     let origin_params = func.get_params();
     let func_param_types: Vec<_> = origin_params
         .iter()
@@ -56,11 +71,7 @@ pub fn instantiate_func_generic(
         infer_self_type(db, cache, &call_expr, &mut substitutor)?;
     }
 
-    if let LuaType::DocFunction(f) = instantiate_doc_function(db, func, &substitutor) {
-        Ok(f.deref().clone())
-    } else {
-        Ok(func.clone())
-    }
+    Ok(substitutor)
 }
 
 fn collect_arg_types(
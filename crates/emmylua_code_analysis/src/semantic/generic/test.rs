@@ -93,4 +93,25 @@ mod test {
         assert_eq!(b, expected_b);
         assert_eq!(c, expected_c);
     }
+
+    #[test]
+    fn test_generic_constraint_as_fallback() {
+        let mut ws = crate::VirtualWorkspace::new();
+        ws.def(
+            r#"
+        ---@class Animal
+        ---@field name string
+
+        ---@generic T: Animal
+        ---@param a T
+        function speak(a)
+            name = a.name
+        end
+        "#,
+        );
+
+        let ty = ws.expr_ty("name");
+        let expected = ws.ty("string");
+        assert_eq!(ty, expected);
+    }
 }
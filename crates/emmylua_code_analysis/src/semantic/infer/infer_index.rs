@@ -148,6 +148,12 @@ pub fn infer_member_by_member_key(
         LuaType::Instance(inst) => infer_instance_member(db, cache, inst, index_expr, infer_guard),
         LuaType::Namespace(ns) => infer_namespace_member(db, cache, ns, index_expr),
         LuaType::Array(array_type) => infer_array_member(db, cache, array_type, index_expr),
+        LuaType::TplRef(tpl) => match tpl.get_constraint() {
+            Some(constraint) => {
+                infer_member_by_member_key(db, cache, constraint, index_expr, infer_guard)
+            }
+            None => Err(InferFailReason::FieldDotFound),
+        },
         _ => Err(InferFailReason::FieldDotFound),
     }
 }
@@ -443,6 +449,12 @@ pub fn infer_member_by_operator(
             let base = inst.get_base();
             infer_member_by_operator(db, cache, &base, index_expr, infer_guard)
         }
+        LuaType::TplRef(tpl) => match tpl.get_constraint() {
+            Some(constraint) => {
+                infer_member_by_operator(db, cache, constraint, index_expr, infer_guard)
+            }
+            None => Err(InferFailReason::FieldDotFound),
+        },
         _ => Err(InferFailReason::FieldDotFound),
     }
 }
@@ -45,6 +45,7 @@ pub fn infer_members_guard(
         LuaType::Global => infer_global_members(db),
         LuaType::Instance(inst) => infer_instance_members(db, inst, infer_guard),
         LuaType::Namespace(ns) => infer_namespace_members(db, ns),
+        LuaType::TplRef(tpl) => infer_members_guard(db, tpl.get_constraint()?, infer_guard),
         _ => None,
     }
 }
@@ -38,10 +38,11 @@ use crate::{
     FileId,
 };
 use crate::{LuaFunctionType, LuaMemberKey};
-pub use generic::{instantiate_type_generic, TypeSubstitutor};
+use generic::{infer_func_generic_substitutor, instantiate_func_generic};
+pub use generic::{instantiate_type_generic, SubstitutorValue, TypeSubstitutor};
 pub use infer::InferFailReason;
 pub(crate) use infer::{infer_call_expr_func, infer_expr};
-use overload_resolve::resolve_signature;
+use overload_resolve::{resolve_signature, resolve_signature_index};
 pub use semantic_info::SemanticDeclLevel;
 pub use type_check::{TypeCheckFailReason, TypeCheckResult};
 
@@ -141,6 +142,41 @@ impl<'a> SemanticModel<'a> {
         .ok()
     }
 
+    /// infer the generic arguments used when `call_expr` calls `func`
+    pub fn infer_call_generic_substitutor(
+        &self,
+        func: &LuaFunctionType,
+        call_expr: LuaCallExpr,
+    ) -> Option<TypeSubstitutor> {
+        infer_func_generic_substitutor(self.db, &mut self.infer_cache.borrow_mut(), func, call_expr)
+            .ok()
+    }
+
+    /// instantiate the generic parameters of `func` from the arguments of `call_expr`
+    pub fn instantiate_call_func(
+        &self,
+        func: &LuaFunctionType,
+        call_expr: LuaCallExpr,
+    ) -> Option<LuaFunctionType> {
+        instantiate_func_generic(self.db, &mut self.infer_cache.borrow_mut(), func, call_expr).ok()
+    }
+
+    /// the index of the overload in `overloads` which best matches the arguments of `call_expr`
+    pub fn resolve_call_overload(
+        &self,
+        overloads: &[Arc<LuaFunctionType>],
+        call_expr: LuaCallExpr,
+        arg_count: Option<usize>,
+    ) -> Option<usize> {
+        resolve_signature_index(
+            self.db,
+            &mut self.infer_cache.borrow_mut(),
+            overloads,
+            call_expr,
+            arg_count,
+        )
+    }
+
     /// 获取赋值时所有右值类型或调用时所有参数类型或返回时所有返回值类型
     pub fn infer_multi_value_adjusted_expression_types(
         &self,
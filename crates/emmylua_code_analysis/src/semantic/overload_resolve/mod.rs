@@ -67,10 +67,59 @@ fn resolve_signature_by_args(
     is_colon_call: bool,
     arg_count: Option<usize>,
 ) -> InferCallFuncResult {
+    // 返回权重最高的签名，若无则取最后一个重载作为默认
+    resolve_signature_index_by_args(db, overloads, expr_types, is_colon_call, arg_count)
+        .and_then(|idx| overloads.get(idx).cloned())
+        .or_else(|| overloads.last().cloned())
+        .ok_or(InferFailReason::None)
+}
+
+/// The index of the overload which best matches the arguments of `call_expr`, arguments which
+/// cannot be inferred yet match any parameter
+pub fn resolve_signature_index(
+    db: &DbIndex,
+    cache: &mut LuaInferCache,
+    overloads: &[Arc<LuaFunctionType>],
+    call_expr: LuaCallExpr,
+    arg_count: Option<usize>,
+) -> Option<usize> {
+    let args = call_expr.get_args_list()?;
+    let expr_types = args
+        .get_args()
+        .map(|arg| infer_expr(db, cache, arg).unwrap_or(LuaType::Unknown))
+        .collect::<Vec<_>>();
+    let overloads = overloads
+        .iter()
+        .map(|func| {
+            if !func.contain_tpl() {
+                return func.clone();
+            }
+
+            instantiate_func_generic(db, cache, func, call_expr.clone())
+                .map(Arc::new)
+                .unwrap_or_else(|_| func.clone())
+        })
+        .collect::<Vec<_>>();
+    resolve_signature_index_by_args(
+        db,
+        &overloads,
+        &expr_types,
+        call_expr.is_colon_call(),
+        arg_count,
+    )
+}
+
+fn resolve_signature_index_by_args(
+    db: &DbIndex,
+    overloads: &[Arc<LuaFunctionType>],
+    expr_types: &[LuaType],
+    is_colon_call: bool,
+    arg_count: Option<usize>,
+) -> Option<usize> {
     let arg_count = arg_count.unwrap_or(0);
     let mut opt_funcs = Vec::with_capacity(overloads.len());
 
-    for func in overloads {
+    for (idx, func) in overloads.iter().enumerate() {
         let params = func.get_params();
         if params.len() < arg_count {
             continue;
@@ -112,19 +161,16 @@ fn resolve_signature_by_args(
             total_weight += 50000;
         }
 
-        opt_funcs.push((func, total_weight));
+        opt_funcs.push((idx, total_weight));
     }
 
     // 按权重降序排序
     opt_funcs.sort_by(|a, b| b.1.cmp(&a.1));
 
-    // 返回权重最高的签名，若无则取最后一个重载作为默认
     opt_funcs
         .first()
         .filter(|(_, weight)| *weight > i32::MIN) // 确保不是无效签名
-        .map(|(func, _)| Arc::clone(func))
-        .or_else(|| overloads.last().cloned())
-        .ok_or(InferFailReason::None)
+        .map(|(idx, _)| *idx)
 }
 
 // fn resolve_signature_by_args(
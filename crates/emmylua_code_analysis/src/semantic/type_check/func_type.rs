@@ -1,5 +1,6 @@
 use crate::db_index::{
-    DbIndex, LuaFunctionType, LuaOperatorMetaMethod, LuaSignatureId, LuaType, LuaTypeDeclId,
+    DbIndex, LuaFunctionType, LuaOperatorMetaMethod, LuaSignature, LuaSignatureId, LuaType,
+    LuaTypeDeclId, SignatureReturnStatus,
 };
 
 use super::{
@@ -90,11 +91,12 @@ fn check_doc_func_type_compact_for_params(
         let compact_param_type = &compact_param.1;
 
         match (source_param_type, compact_param_type) {
+            // parameters are contravariant, the compact function must accept every source argument
             (Some(source_type), Some(compact_type)) => {
                 if check_general_type_compact(
                     db,
-                    source_type,
                     compact_type,
+                    source_type,
                     check_guard.next_level()?,
                 )
                 .is_err()
@@ -107,7 +109,16 @@ fn check_doc_func_type_compact_for_params(
         }
     }
 
-    // todo check return type
+    // return types are covariant
+    let source_returns = source_func.get_ret();
+    let compact_returns = compact_func.get_ret();
+    for (source_return, compact_return) in source_returns.iter().zip(compact_returns.iter()) {
+        if check_general_type_compact(db, source_return, compact_return, check_guard.next_level()?)
+            .is_err()
+        {
+            return Err(TypeCheckFailReason::TypeNotMatch);
+        }
+    }
 
     Ok(())
 }
@@ -124,7 +135,7 @@ fn check_doc_func_type_compact_for_varargs(
             let compact_param = &compact_params[i];
             let compact_param_type = &compact_param.1;
             if let Some(compact_param_type) = compact_param_type {
-                if check_type_compact(db, varargs_type, compact_param_type).is_err() {
+                if check_type_compact(db, compact_param_type, varargs_type).is_err() {
                     return false;
                 }
             }
@@ -164,11 +175,12 @@ fn check_doc_func_type_compact_for_signature(
         }
     }
 
+    let return_types = get_signature_return_types(signature);
     let fake_doc_func = LuaFunctionType::new(
         signature.is_async,
         signature.is_colon_define,
         signature_params.iter().cloned().collect(),
-        Vec::new(),
+        return_types,
     );
 
     check_doc_func_type_compact_for_params(
@@ -257,12 +269,26 @@ pub fn check_sig_type_compact(
     }
 
     let signature_params = signature.get_type_params();
+    let return_types = get_signature_return_types(signature);
     let fake_doc_func = LuaFunctionType::new(
         signature.is_async,
         signature.is_colon_define,
         signature_params.iter().cloned().collect(),
-        Vec::new(),
+        return_types,
     );
 
     check_doc_func_type_compact(db, &fake_doc_func, compact_type, check_guard.next_level()?)
 }
+
+// returns which are not resolved yet are not checked, and a function without a return statement
+// is treated as returning nothing rather than `nil`
+fn get_signature_return_types(signature: &LuaSignature) -> Vec<LuaType> {
+    let return_types = signature.get_return_types();
+    match signature.resolve_return {
+        SignatureReturnStatus::UnResolve => Vec::new(),
+        SignatureReturnStatus::InferResolve if return_types.iter().all(|typ| typ.is_nil()) => {
+            Vec::new()
+        }
+        _ => return_types,
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{DbIndex, LuaGenericType, LuaType, TypeSubstitutor};
+use crate::{DbIndex, LuaGenericType, LuaGenericVariance, LuaType, TypeSubstitutor};
 
 use super::{
     check_general_type_compact, type_check_fail_reason::TypeCheckFailReason,
@@ -69,12 +69,39 @@ fn check_generic_type_compact_generic(
         return Err(TypeCheckFailReason::TypeNotMatch);
     }
 
+    let type_index = db.get_type_index();
     for i in 0..source_params.len() {
         let source_param = &source_params[i];
         let compact_param = &compact_params[i];
-        if check_general_type_compact(db, source_param, compact_param, check_guard.next_level()?)
-            .is_err()
-        {
+        let result = match type_index.get_generic_variance(&source_base_id, i) {
+            LuaGenericVariance::Invariant => check_general_type_compact(
+                db,
+                source_param,
+                compact_param,
+                check_guard.next_level()?,
+            )
+            .and_then(|_| {
+                check_general_type_compact(
+                    db,
+                    compact_param,
+                    source_param,
+                    check_guard.next_level()?,
+                )
+            }),
+            LuaGenericVariance::Covariant => check_general_type_compact(
+                db,
+                source_param,
+                compact_param,
+                check_guard.next_level()?,
+            ),
+            LuaGenericVariance::Contravariant => check_general_type_compact(
+                db,
+                compact_param,
+                source_param,
+                check_guard.next_level()?,
+            ),
+        };
+        if result.is_err() {
             return Err(TypeCheckFailReason::TypeNotMatch);
         }
     }
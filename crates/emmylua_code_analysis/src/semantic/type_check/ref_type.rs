@@ -1,8 +1,8 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use crate::{
-    humanize_type, DbIndex, LuaMemberKey, LuaMemberOwner, LuaType, LuaTypeDeclId, LuaUnionType,
-    RenderLevel,
+    humanize_type, semantic::member::infer_members, DbIndex, LuaMemberId, LuaMemberKey,
+    LuaMemberOwner, LuaType, LuaTypeDeclId, LuaUnionType, RenderLevel,
 };
 
 use super::{
@@ -156,6 +156,14 @@ fn check_ref_type_compact_table(
         None => HashMap::new(),
     };
 
+    let is_exact = db
+        .get_type_index()
+        .get_type_decl(source_type_id)
+        .is_some_and(|decl| decl.is_exact());
+    if is_exact {
+        check_exact_table_keys(db, source_type_id, &table_member_map)?;
+    }
+
     let source_type_owner = LuaMemberOwner::Type(source_type_id.clone());
     let source_type_members = match member_index.get_members(&source_type_owner) {
         Some(members) => members,
@@ -218,3 +226,37 @@ fn check_ref_type_compact_table(
 
     Ok(())
 }
+
+// exact class does not accept keys which are not declared by itself or its supers
+fn check_exact_table_keys(
+    db: &DbIndex,
+    source_type_id: &LuaTypeDeclId,
+    table_member_map: &HashMap<LuaMemberKey, LuaMemberId>,
+) -> TypeCheckResult {
+    let declared_keys: HashSet<LuaMemberKey> =
+        infer_members(db, &LuaType::Ref(source_type_id.clone()))
+            .unwrap_or_default()
+            .into_iter()
+            .map(|info| info.key)
+            .collect();
+
+    let mut extra_keys: Vec<String> = table_member_map
+        .keys()
+        .filter(|key| matches!(key, LuaMemberKey::Name(_) | LuaMemberKey::Integer(_)))
+        .filter(|key| !declared_keys.contains(key))
+        .map(|key| key.to_path())
+        .collect();
+    if extra_keys.is_empty() {
+        return Ok(());
+    }
+
+    extra_keys.sort();
+    Err(TypeCheckFailReason::TypeNotMatchWithReason(
+        t!(
+            "member %{name} is not declared in exact class %{class}",
+            name = extra_keys.join(", "),
+            class = source_type_id.get_name()
+        )
+        .to_string(),
+    ))
+}
@@ -150,4 +150,55 @@ mod test {
         let ty2 = ws.expr_ty("(\"hello\"):match(\".*\")");
         assert!(ws.check_type(&ty, &ty2));
     }
+
+    #[test]
+    fn test_generic_class_variance() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@class Box<out T>
+            ---@class Sink<in T>
+            ---@class List<T>
+            "#,
+        );
+
+        // (expected, actual): a subtype, a supertype and an equal type argument
+        let cases = [
+            ("Box", [true, false, true]),
+            ("Sink", [false, true, true]),
+            ("List", [false, false, true]),
+        ];
+        for (name, [sub, sup, equal]) in cases {
+            let number = ws.ty(&format!("{}<number>", name));
+            let integer = ws.ty(&format!("{}<integer>", name));
+            let other_number = ws.ty(&format!("{}<number>", name));
+            assert_eq!(ws.check_type(&number, &integer), sub, "{}: sub", name);
+            assert_eq!(ws.check_type(&integer, &number), sup, "{}: super", name);
+            assert_eq!(
+                ws.check_type(&number, &other_number),
+                equal,
+                "{}: equal",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn test_func_type_variance() {
+        let mut ws = VirtualWorkspace::new();
+
+        let takes_number = ws.ty("fun(a: number)");
+        let takes_integer = ws.ty("fun(a: integer)");
+        assert!(ws.check_type(&takes_integer, &takes_number));
+        assert!(!ws.check_type(&takes_number, &takes_integer));
+
+        let returns_number = ws.ty("fun(): number");
+        let returns_integer = ws.ty("fun(): integer");
+        assert!(ws.check_type(&returns_number, &returns_integer));
+        assert!(!ws.check_type(&returns_integer, &returns_number));
+
+        // missing returns are not checked
+        let returns_nothing = ws.ty("fun()");
+        assert!(ws.check_type(&returns_number, &returns_nothing));
+    }
 }
@@ -0,0 +1,332 @@
+mod pattern;
+mod test;
+
+use std::{cell::Cell, collections::HashMap};
+
+use emmylua_parser::{
+    LuaAstNode, LuaExpr, LuaSyntaxElement, LuaSyntaxKind, LuaSyntaxNode, LuaSyntaxToken,
+    LuaTokenKind,
+};
+use lsp_types::TextEdit;
+use pattern::{get_placeholder_name, get_placeholder_name_of_expr, need_parens};
+pub use pattern::{StructuralPattern, StructuralTemplate};
+use rowan::{TextRange, WalkEvent};
+
+use crate::{LuaType, LuaTypeDeclId, SemanticModel};
+
+/// The code bound to a placeholder
+#[derive(Debug, Clone)]
+pub enum StructuralBinding {
+    Node(LuaSyntaxNode),
+    Token(LuaSyntaxToken),
+}
+
+impl StructuralBinding {
+    pub fn get_range(&self) -> TextRange {
+        match self {
+            StructuralBinding::Node(node) => node.text_range(),
+            StructuralBinding::Token(token) => token.text_range(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct StructuralMatch {
+    pub node: LuaSyntaxNode,
+    pub bindings: HashMap<String, StructuralBinding>,
+}
+
+/// Find the code matching the pattern, ignoring whitespace, comments and parentheses.
+/// A match never overlaps with another one.
+pub fn structural_search(
+    semantic_model: &SemanticModel,
+    pattern: &StructuralPattern,
+) -> Vec<StructuralMatch> {
+    let matcher = StructuralMatcher {
+        semantic_model,
+        pattern,
+        skipped: Cell::new(0),
+    };
+    matcher.find_matches(semantic_model.get_root().syntax(), false)
+}
+
+#[derive(Debug, Default)]
+pub struct StructuralReplaceResult {
+    pub edits: Vec<TextEdit>,
+    /// The matches left as is because of a comment outside the bound code
+    pub skipped: usize,
+}
+
+/// Replace the code matching the pattern with the template.
+/// A match with a comment outside the bound code is left as is, the comment would be lost.
+pub fn structural_replace(
+    semantic_model: &SemanticModel,
+    pattern: &StructuralPattern,
+    template: &StructuralTemplate,
+) -> StructuralReplaceResult {
+    let matcher = StructuralMatcher {
+        semantic_model,
+        pattern,
+        skipped: Cell::new(0),
+    };
+    let document = semantic_model.get_document();
+    let edits = matcher
+        .find_matches(semantic_model.get_root().syntax(), true)
+        .into_iter()
+        .filter_map(|structural_match| {
+            Some(TextEdit {
+                range: document.to_lsp_range(structural_match.node.text_range())?,
+                new_text: matcher.render(&structural_match, template),
+            })
+        })
+        .collect();
+
+    StructuralReplaceResult {
+        edits,
+        skipped: matcher.skipped.get(),
+    }
+}
+
+struct StructuralMatcher<'a> {
+    semantic_model: &'a SemanticModel<'a>,
+    pattern: &'a StructuralPattern,
+    /// counts the matches skipped when replacing, the nested ones are found while rendering
+    skipped: Cell<usize>,
+}
+
+impl StructuralMatcher<'_> {
+    fn find_matches(&self, root: &LuaSyntaxNode, replace: bool) -> Vec<StructuralMatch> {
+        let pattern_root = self.pattern.get_root();
+        let mut matches = Vec::new();
+        let mut preorder = root.preorder();
+        while let Some(event) = preorder.next() {
+            let WalkEvent::Enter(node) = event else {
+                continue;
+            };
+            if node.kind() != pattern_root.kind() {
+                continue;
+            }
+
+            let mut bindings = HashMap::new();
+            if !self.match_node(pattern_root, &node, &mut bindings) {
+                continue;
+            }
+
+            let structural_match = StructuralMatch { node, bindings };
+            // the code inside a match which can not be replaced is still searched
+            if replace && has_unbound_comment(&structural_match) {
+                self.skipped.set(self.skipped.get() + 1);
+                continue;
+            }
+            matches.push(structural_match);
+            preorder.skip_subtree();
+        }
+
+        matches
+    }
+
+    fn match_node(
+        &self,
+        pattern: &LuaSyntaxNode,
+        target: &LuaSyntaxNode,
+        bindings: &mut HashMap<String, StructuralBinding>,
+    ) -> bool {
+        let pattern = strip_parens(pattern);
+        if let Some(name) = get_placeholder_name_of_expr(&pattern) {
+            // the parentheses are bound too, `(f())` keeps only the first value of `f()`
+            return LuaExpr::can_cast(target.kind().into())
+                && self.bind(name, StructuralBinding::Node(target.clone()), bindings);
+        }
+        let target = strip_parens(target);
+        if pattern.kind() != target.kind() {
+            return false;
+        }
+
+        let pattern_children = get_significant_children(&pattern);
+        let target_children = get_significant_children(&target);
+        if pattern_children.len() != target_children.len() {
+            return false;
+        }
+
+        pattern_children
+            .into_iter()
+            .zip(target_children)
+            .all(|pair| match pair {
+                (LuaSyntaxElement::Node(pattern), LuaSyntaxElement::Node(target)) => {
+                    self.match_node(&pattern, &target, bindings)
+                }
+                (LuaSyntaxElement::Token(pattern), LuaSyntaxElement::Token(target)) => {
+                    match get_placeholder_name(pattern.kind().into(), pattern.text()) {
+                        Some(name) => {
+                            target.kind() == pattern.kind()
+                                && self.bind(name, StructuralBinding::Token(target), bindings)
+                        }
+                        None => pattern.kind() == target.kind() && pattern.text() == target.text(),
+                    }
+                }
+                _ => false,
+            })
+    }
+
+    /// A placeholder used more than once must bind the same code each time
+    fn bind(
+        &self,
+        name: String,
+        binding: StructuralBinding,
+        bindings: &mut HashMap<String, StructuralBinding>,
+    ) -> bool {
+        if let Some(bound) = bindings.get(&name) {
+            return match (bound, &binding) {
+                (StructuralBinding::Node(bound), StructuralBinding::Node(node)) => {
+                    self.match_node(&bound.clone(), node, &mut HashMap::new())
+                }
+                (StructuralBinding::Token(bound), StructuralBinding::Token(token)) => {
+                    bound.text() == token.text()
+                }
+                _ => false,
+            };
+        }
+
+        if let (Some(type_name), StructuralBinding::Node(node)) =
+            (self.pattern.get_constraint(&name), &binding)
+        {
+            if !self.check_constraint(node, type_name) {
+                return false;
+            }
+        }
+
+        bindings.insert(name, binding);
+        true
+    }
+
+    fn check_constraint(&self, node: &LuaSyntaxNode, type_name: &str) -> bool {
+        let Some(expr) = LuaExpr::cast(node.clone()) else {
+            return false;
+        };
+        let Ok(typ) = self.semantic_model.infer_expr(expr) else {
+            return false;
+        };
+        // an unknown type can not prove the constraint
+        if typ.is_unknown() {
+            return false;
+        }
+
+        let expected = match type_name {
+            "any" => return true,
+            "nil" => LuaType::Nil,
+            "boolean" => LuaType::Boolean,
+            "string" => LuaType::String,
+            "number" => LuaType::Number,
+            "integer" => LuaType::Integer,
+            "table" => LuaType::Table,
+            "function" => LuaType::Function,
+            "thread" => LuaType::Thread,
+            "userdata" => LuaType::Userdata,
+            _ => LuaType::Ref(LuaTypeDeclId::new(type_name)),
+        };
+        self.semantic_model.type_check(&expected, &typ).is_ok()
+    }
+
+    fn render(&self, structural_match: &StructuralMatch, template: &StructuralTemplate) -> String {
+        let template_root = template.get_root();
+        let mut text = String::new();
+        for token in template_root
+            .descendants_with_tokens()
+            .filter_map(|element| element.into_token())
+        {
+            let binding = get_placeholder_name(token.kind().into(), token.text())
+                .and_then(|name| structural_match.bindings.get(&name));
+            let Some(binding) = binding else {
+                text.push_str(token.text());
+                continue;
+            };
+
+            match binding {
+                StructuralBinding::Node(node) => {
+                    // nested matches inside the bound code are replaced too
+                    let replaced = self.replace_in_node(node, template);
+                    let need_parens = token
+                        .parent()
+                        .filter(|parent| parent.kind() == LuaSyntaxKind::NameExpr.into())
+                        .is_some_and(|parent| need_parens(node.kind().into(), &parent));
+                    if need_parens {
+                        text.push_str(&format!("({})", replaced));
+                    } else {
+                        text.push_str(&replaced);
+                    }
+                }
+                StructuralBinding::Token(bound) => text.push_str(bound.text()),
+            }
+        }
+
+        if need_parens(template_root.kind().into(), &structural_match.node) {
+            format!("({})", text)
+        } else {
+            text
+        }
+    }
+
+    fn replace_in_node(&self, node: &LuaSyntaxNode, template: &StructuralTemplate) -> String {
+        let mut text = node.text().to_string();
+        let start = node.text_range().start();
+        let mut matches = self.find_matches(node, true);
+        matches.sort_by_key(|structural_match| structural_match.node.text_range().start());
+        for structural_match in matches.iter().rev() {
+            let range = structural_match.node.text_range() - start;
+            text.replace_range(
+                usize::from(range.start())..usize::from(range.end()),
+                &self.render(structural_match, template),
+            );
+        }
+        text
+    }
+}
+
+fn strip_parens(node: &LuaSyntaxNode) -> LuaSyntaxNode {
+    let mut node = node.clone();
+    while node.kind() == LuaSyntaxKind::ParenExpr.into() {
+        match node
+            .children()
+            .find(|child| LuaExpr::can_cast(child.kind().into()))
+        {
+            Some(inner) => node = inner,
+            None => break,
+        }
+    }
+    node
+}
+
+fn has_unbound_comment(structural_match: &StructuralMatch) -> bool {
+    structural_match
+        .node
+        .descendants_with_tokens()
+        .filter(|element| match element {
+            LuaSyntaxElement::Node(node) => node.kind() == LuaSyntaxKind::Comment.into(),
+            LuaSyntaxElement::Token(token) => matches!(
+                token.kind().into(),
+                LuaTokenKind::TkShortComment | LuaTokenKind::TkLongComment
+            ),
+        })
+        .any(|comment| {
+            let range = comment.text_range();
+            !structural_match
+                .bindings
+                .values()
+                .any(|binding| binding.get_range().contains_range(range))
+        })
+}
+
+fn get_significant_children(node: &LuaSyntaxNode) -> Vec<LuaSyntaxElement> {
+    node.children_with_tokens()
+        .filter(|element| match element {
+            LuaSyntaxElement::Node(node) => node.kind() != LuaSyntaxKind::Comment.into(),
+            LuaSyntaxElement::Token(token) => !matches!(
+                token.kind().into(),
+                LuaTokenKind::TkWhitespace
+                    | LuaTokenKind::TkEndOfLine
+                    | LuaTokenKind::TkShortComment
+                    | LuaTokenKind::TkLongComment
+            ),
+        })
+        .collect()
+}
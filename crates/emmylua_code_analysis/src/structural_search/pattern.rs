@@ -0,0 +1,297 @@
+use std::collections::{HashMap, HashSet};
+
+use emmylua_parser::{
+    LuaAstNode, LuaExpr, LuaNameExpr, LuaParser, LuaStat, LuaSyntaxKind, LuaSyntaxNode,
+    LuaTokenKind,
+};
+use rowan::NodeCache;
+
+use crate::Emmyrc;
+
+/// `$name` is rewritten to this identifier before the code is parsed
+const PLACEHOLDER_PREFIX: &str = "__emmy_placeholder_";
+
+/// A search pattern such as `Log.print($a, $b)` or `table.getn($t: table)`
+#[derive(Debug)]
+pub struct StructuralPattern {
+    root: LuaSyntaxNode,
+    /// placeholder name -> type name, eg: `$t: table`
+    constraints: HashMap<String, String>,
+}
+
+impl StructuralPattern {
+    pub fn parse(text: &str, emmyrc: &Emmyrc) -> Result<Self, String> {
+        let (code, constraints) = replace_placeholders(text, true)?;
+        let root = parse_code(text, &code, emmyrc)?;
+        if get_placeholder_name_of_expr(&root).is_some() {
+            return Err("The pattern can not be a single placeholder".to_string());
+        }
+
+        Ok(Self { root, constraints })
+    }
+
+    pub fn get_root(&self) -> &LuaSyntaxNode {
+        &self.root
+    }
+
+    pub fn get_constraint(&self, name: &str) -> Option<&str> {
+        self.constraints.get(name).map(|s| s.as_str())
+    }
+
+    pub fn get_placeholders(&self) -> HashSet<String> {
+        collect_placeholders(&self.root)
+    }
+}
+
+/// The replacement of a `StructuralPattern`, eg: `Log.info($b, $a)` or `#$t`
+#[derive(Debug)]
+pub struct StructuralTemplate {
+    root: LuaSyntaxNode,
+}
+
+impl StructuralTemplate {
+    pub fn parse(text: &str, pattern: &StructuralPattern, emmyrc: &Emmyrc) -> Result<Self, String> {
+        let (code, _) = replace_placeholders(text, false)?;
+        let root = parse_code(text, &code, emmyrc)?;
+        // an expression is only replaced by an expression, a statement also by a call
+        let is_pattern_expr = LuaExpr::can_cast(pattern.root.kind().into());
+        let is_template_expr = LuaExpr::can_cast(root.kind().into());
+        if is_pattern_expr && !is_template_expr {
+            return Err(format!("`{}` must be an expression like the pattern", text));
+        }
+        if !is_pattern_expr && is_template_expr && root.kind() != LuaSyntaxKind::CallExpr.into() {
+            return Err(format!("`{}` must be a statement like the pattern", text));
+        }
+
+        let pattern_placeholders = pattern.get_placeholders();
+        for name in collect_placeholders(&root) {
+            if !pattern_placeholders.contains(&name) {
+                return Err(format!(
+                    "Placeholder `${}` is not defined in the pattern",
+                    name
+                ));
+            }
+        }
+
+        Ok(Self { root })
+    }
+
+    pub fn get_root(&self) -> &LuaSyntaxNode {
+        &self.root
+    }
+}
+
+/// Rewrite `$name` to a valid identifier, and collect the `$name: type` constraints,
+/// strings and comments are copied as they are
+fn replace_placeholders(
+    text: &str,
+    allow_constraint: bool,
+) -> Result<(String, HashMap<String, String>), String> {
+    let mut code = String::with_capacity(text.len());
+    let mut constraints = HashMap::new();
+    let chars = text.chars().collect::<Vec<_>>();
+    let mut i = 0;
+    while i < chars.len() {
+        if let Some(end) = get_string_or_comment_end(&chars, i) {
+            code.extend(&chars[i..end]);
+            i = end;
+            continue;
+        }
+        if chars[i] != '$' {
+            code.push(chars[i]);
+            i += 1;
+            continue;
+        }
+
+        let start = i + 1;
+        let mut end = start;
+        while end < chars.len() && (chars[end].is_alphanumeric() || chars[end] == '_') {
+            end += 1;
+        }
+        if end == start {
+            return Err(format!("Expected a placeholder name after `$` at {}", i));
+        }
+        let name = chars[start..end].iter().collect::<String>();
+        code.push_str(PLACEHOLDER_PREFIX);
+        code.push_str(&name);
+        i = end;
+
+        // `$t: table`, the space after `:` distinguishes it from a method call `$t:len()`
+        if allow_constraint
+            && i + 1 < chars.len()
+            && chars[i] == ':'
+            && chars[i + 1].is_whitespace()
+        {
+            let mut type_start = i + 1;
+            while type_start < chars.len() && chars[type_start].is_whitespace() {
+                type_start += 1;
+            }
+            let mut type_end = type_start;
+            while type_end < chars.len()
+                && (chars[type_end].is_alphanumeric()
+                    || chars[type_end] == '_'
+                    || chars[type_end] == '.')
+            {
+                type_end += 1;
+            }
+            if type_end == type_start {
+                return Err(format!("Expected a type name after `${}:`", name));
+            }
+            constraints.insert(name, chars[type_start..type_end].iter().collect());
+            i = type_end;
+        }
+    }
+
+    Ok((code, constraints))
+}
+
+/// The end of the string or comment starting at `start`, it is the end of the text when unclosed
+fn get_string_or_comment_end(chars: &[char], start: usize) -> Option<usize> {
+    match chars[start] {
+        quote @ ('"' | '\'') => {
+            let mut i = start + 1;
+            while i < chars.len() && chars[i] != quote && chars[i] != '\n' {
+                if chars[i] == '\\' {
+                    i += 1;
+                }
+                i += 1;
+            }
+            Some((i + 1).min(chars.len()))
+        }
+        '[' => get_long_bracket_end(chars, start),
+        '-' if chars.get(start + 1) == Some(&'-') => {
+            if let Some(end) = get_long_bracket_end(chars, start + 2) {
+                return Some(end);
+            }
+            let mut i = start + 2;
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+            Some(i)
+        }
+        _ => None,
+    }
+}
+
+/// The end of `[[...]]` or `[==[...]==]` starting at `start`
+fn get_long_bracket_end(chars: &[char], start: usize) -> Option<usize> {
+    if chars.get(start) != Some(&'[') {
+        return None;
+    }
+    let mut i = start + 1;
+    while chars.get(i) == Some(&'=') {
+        i += 1;
+    }
+    if chars.get(i) != Some(&'[') {
+        return None;
+    }
+    let level = i - start - 1;
+    let mut close = vec![']'];
+    close.extend(std::iter::repeat_n('=', level));
+    close.push(']');
+    let end = chars[i + 1..]
+        .windows(close.len())
+        .position(|window| window == close.as_slice())
+        .map(|pos| i + 1 + pos + close.len())
+        .unwrap_or(chars.len());
+    Some(end)
+}
+
+/// Parse the code as an expression first, then as a single statement,
+/// the errors show the `text` the code was rewritten from
+fn parse_code(text: &str, code: &str, emmyrc: &Emmyrc) -> Result<LuaSyntaxNode, String> {
+    let mut node_cache = NodeCache::default();
+    let tree = LuaParser::parse(
+        &format!("return {}", code),
+        emmyrc.get_parse_config(&mut node_cache),
+    );
+    if tree.get_errors().is_empty() {
+        let block = tree.get_chunk_node().get_block();
+        let stats = block
+            .iter()
+            .flat_map(|block| block.get_stats())
+            .collect::<Vec<_>>();
+        if let [LuaStat::ReturnStat(return_stat)] = stats.as_slice() {
+            let exprs = return_stat.get_expr_list().collect::<Vec<_>>();
+            if let [expr] = exprs.as_slice() {
+                return Ok(expr.syntax().clone());
+            }
+        }
+    }
+
+    let tree = LuaParser::parse(code, emmyrc.get_parse_config(&mut node_cache));
+    if let Some(error) = tree.get_errors().first() {
+        // the parser reports the rewritten placeholders, eg: `unexpected __emmy_placeholder_t`
+        let message = error.message.replace(PLACEHOLDER_PREFIX, "$");
+        return Err(format!("Failed to parse `{}`: {}", text, message));
+    }
+    let block = tree.get_chunk_node().get_block();
+    let stats = block
+        .iter()
+        .flat_map(|block| block.get_stats())
+        .collect::<Vec<_>>();
+    match stats.as_slice() {
+        [stat] => Ok(stat.syntax().clone()),
+        _ => Err(format!(
+            "`{}` must be a single expression or statement",
+            text
+        )),
+    }
+}
+
+fn collect_placeholders(root: &LuaSyntaxNode) -> HashSet<String> {
+    root.descendants_with_tokens()
+        .filter_map(|element| element.into_token())
+        .filter_map(|token| get_placeholder_name(token.kind().into(), token.text()))
+        .collect()
+}
+
+pub fn get_placeholder_name(kind: LuaTokenKind, text: &str) -> Option<String> {
+    if kind != LuaTokenKind::TkName {
+        return None;
+    }
+    text.strip_prefix(PLACEHOLDER_PREFIX).map(|s| s.to_string())
+}
+
+/// The placeholder name when the node is a name expression made of a placeholder
+pub fn get_placeholder_name_of_expr(node: &LuaSyntaxNode) -> Option<String> {
+    if node.kind() != LuaSyntaxKind::NameExpr.into() {
+        return None;
+    }
+    let token = LuaNameExpr::cast(node.clone())?.get_name_token()?;
+    get_placeholder_name(LuaTokenKind::TkName, token.get_name_text())
+}
+
+/// Whether an expression of `kind` must be wrapped in parentheses when it takes the
+/// place of `node`, eg: `a + b` as the operand of an operator or the prefix of a call
+pub fn need_parens(kind: LuaSyntaxKind, node: &LuaSyntaxNode) -> bool {
+    let Some(parent) = node.parent() else {
+        return false;
+    };
+    match parent.kind().into() {
+        LuaSyntaxKind::BinaryExpr => match kind {
+            LuaSyntaxKind::BinaryExpr => true,
+            // only `^` binds tighter than a unary operator
+            LuaSyntaxKind::UnaryExpr => parent
+                .children_with_tokens()
+                .any(|element| element.kind() == LuaTokenKind::TkPow.into()),
+            _ => false,
+        },
+        LuaSyntaxKind::UnaryExpr => kind == LuaSyntaxKind::BinaryExpr,
+        LuaSyntaxKind::IndexExpr | LuaSyntaxKind::CallExpr => {
+            let is_prefix = parent
+                .children()
+                .find(|child| LuaExpr::can_cast(child.kind().into()))
+                .is_some_and(|prefix| prefix == *node);
+            is_prefix
+                && !matches!(
+                    kind,
+                    LuaSyntaxKind::NameExpr
+                        | LuaSyntaxKind::IndexExpr
+                        | LuaSyntaxKind::CallExpr
+                        | LuaSyntaxKind::ParenExpr
+                )
+        }
+        _ => false,
+    }
+}
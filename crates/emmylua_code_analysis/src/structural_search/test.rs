@@ -0,0 +1,186 @@
+#[cfg(test)]
+mod tests {
+    use crate::{structural_search, StructuralPattern, VirtualWorkspace};
+
+    fn replace(ws: &mut VirtualWorkspace, code: &str, search: &str, replace: &str) -> String {
+        let file_id = ws.def(code);
+        ws.analysis
+            .structural_replace_files(&[file_id], search, replace)
+            .unwrap()
+            .0
+            .remove(&file_id)
+            .unwrap_or_else(|| code.to_string())
+    }
+
+    #[test]
+    fn test_swap_args() {
+        let mut ws = VirtualWorkspace::new();
+        let result = replace(
+            &mut ws,
+            r#"
+            Log.print("a", 1)
+            Log.print( -- comment
+                x, y + 1)
+            Log.print(f(--[[ kept ]] x), y)
+            Log.print((f()), b)
+            Log.print(Log.print(a, b), c)
+            Log.print(a)
+            "#,
+            "Log.print($a, $b)",
+            "Log.info($b, $a)",
+        );
+        assert_eq!(
+            result,
+            r#"
+            Log.info(1, "a")
+            Log.print( -- comment
+                x, y + 1)
+            Log.info(y, f(--[[ kept ]] x))
+            Log.info(b, (f()))
+            Log.info(c, Log.info(b, a))
+            Log.print(a)
+            "#
+        );
+    }
+
+    #[test]
+    fn test_replace_with_parens() {
+        let mut ws = VirtualWorkspace::new();
+        let result = replace(
+            &mut ws,
+            r#"
+            local n = table.getn(a.b) + table.getn(x or y)
+            local m = f(a) * 2
+            "#,
+            "table.getn($t)",
+            "#$t",
+        );
+        assert_eq!(
+            result,
+            r#"
+            local n = #a.b + #(x or y)
+            local m = f(a) * 2
+            "#
+        );
+
+        let result = replace(&mut ws, "local m = f(a) * 2", "f($x)", "$x or 0");
+        assert_eq!(result, "local m = (a or 0) * 2");
+    }
+
+    #[test]
+    fn test_repeated_placeholder() {
+        let mut ws = VirtualWorkspace::new();
+        let result = replace(
+            &mut ws,
+            r#"
+            a.b = a.b or {}
+            a.b = c.d or {}
+            "#,
+            "$x = $x or {}",
+            "$x = $x or setmetatable({}, mt)",
+        );
+        assert_eq!(
+            result,
+            r#"
+            a.b = a.b or setmetatable({}, mt)
+            a.b = c.d or {}
+            "#
+        );
+    }
+
+    #[test]
+    fn test_type_constraint() {
+        let mut ws = VirtualWorkspace::new();
+        let file_id = ws.def(
+            r#"
+            local t = {}
+            local s = "abc"
+            print(#t, #s)
+            "#,
+        );
+        let semantic_model = ws.analysis.compilation.get_semantic_model(file_id).unwrap();
+        let pattern = StructuralPattern::parse("#$t: table", &ws.analysis.emmyrc).unwrap();
+        let matches = structural_search(&semantic_model, &pattern);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].node.text().to_string(), "#t");
+    }
+
+    #[test]
+    fn test_invalid_pattern() {
+        let ws = VirtualWorkspace::new();
+        assert!(StructuralPattern::parse("$a", &ws.analysis.emmyrc).is_err());
+        assert!(StructuralPattern::parse("f($)", &ws.analysis.emmyrc).is_err());
+        assert!(ws
+            .analysis
+            .structural_replace_files(&[], "f($a)", "g($b)")
+            .is_err());
+        // the template must be of the same kind as the pattern
+        assert!(ws
+            .analysis
+            .structural_replace_files(&[], "f($a)", "local x = $a")
+            .is_err());
+        assert!(ws
+            .analysis
+            .structural_replace_files(&[], "x = $a", "$a + 1")
+            .is_err());
+    }
+
+    #[test]
+    fn test_skipped_with_comment() {
+        let mut ws = VirtualWorkspace::new();
+        let file_id = ws.def(
+            r#"
+            Log.print( -- comment
+                x, Log.print(a, --[[ b ]] b))
+            Log.print(f(--[[ kept ]] x), y)
+            "#,
+        );
+        let (_, skipped) = ws
+            .analysis
+            .structural_replace_files(&[file_id], "Log.print($a, $b)", "Log.info($b, $a)")
+            .unwrap();
+        assert_eq!(skipped, 2);
+    }
+
+    #[test]
+    fn test_parse_error_shows_placeholder() {
+        let ws = VirtualWorkspace::new();
+        let err = StructuralPattern::parse("f($a,)", &ws.analysis.emmyrc).unwrap_err();
+        assert!(err.contains("`f($a,)`"), "{}", err);
+        assert!(!err.contains("__emmy_placeholder_"), "{}", err);
+    }
+
+    #[test]
+    fn test_dollar_in_string_and_comment() {
+        let mut ws = VirtualWorkspace::new();
+        let result = replace(
+            &mut ws,
+            r#"
+            Log.print(cost)
+            "#,
+            "Log.print($a)",
+            r#"Log.info("cost: $", [[$]], $a --[[ $ ]])"#,
+        );
+        assert_eq!(
+            result,
+            r#"
+            Log.info("cost: $", [[$]], cost --[[ $ ]])
+            "#
+        );
+
+        let result = replace(
+            &mut ws,
+            r#"
+            x = 1
+            "#,
+            "x = $a",
+            "f($a)",
+        );
+        assert_eq!(
+            result,
+            r#"
+            f(1)
+            "#
+        );
+    }
+}
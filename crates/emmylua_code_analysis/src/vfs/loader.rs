@@ -6,8 +6,12 @@ use std::{
 };
 use wax::Pattern;
 
-use log::{error, info};
-use walkdir::WalkDir;
+use log::{debug, error, info};
+use walkdir::{DirEntry, WalkDir};
+
+use crate::{update_code_style, Emmyrc};
+
+const VCS_DIRS: [&str; 3] = [".git", ".hg", ".svn"];
 
 #[derive(Debug)]
 pub struct LuaFileInfo {
@@ -105,3 +109,168 @@ pub fn read_file_with_encoding(path: &Path, encoding: &str) -> Option<String> {
 
     Some(content.to_string())
 }
+
+/// Collect the files of the workspaces matching the config, shared by the language server and the cli tools
+pub fn collect_workspace_files(workspaces: &[PathBuf], emmyrc: &Emmyrc) -> Vec<LuaFileInfo> {
+    let mut files = Vec::new();
+    let (match_pattern, exclude, exclude_dir) = calculate_include_and_exclude(emmyrc);
+
+    let encoding = &emmyrc.workspace.encoding;
+
+    info!(
+        "collect_files from: {:?} match_pattern: {:?} exclude: {:?}, exclude_dir: {:?}",
+        workspaces, match_pattern, exclude, exclude_dir
+    );
+    for workspace in workspaces {
+        let loaded = load_workspace_files(
+            workspace,
+            &match_pattern,
+            &exclude,
+            &exclude_dir,
+            Some(encoding),
+        )
+        .ok();
+        if let Some(loaded) = loaded {
+            files.extend(loaded);
+        }
+    }
+
+    info!("load files from workspace count: {:?}", files.len());
+
+    for file in &files {
+        debug!("loaded file: {:?}", file.path);
+    }
+
+    files
+}
+
+pub fn calculate_include_and_exclude(emmyrc: &Emmyrc) -> (Vec<String>, Vec<String>, Vec<PathBuf>) {
+    let mut include = vec!["**/*.lua".to_string()];
+    let mut exclude = Vec::new();
+    let mut exclude_dirs = Vec::new();
+
+    for extension in &emmyrc.runtime.extensions {
+        if extension.starts_with(".") {
+            include.push(format!("**/*{}", extension));
+        } else if extension.starts_with("*.") {
+            include.push(format!("**/{}", extension));
+        } else {
+            include.push(extension.clone());
+        }
+    }
+
+    for ignore_glob in &emmyrc.workspace.ignore_globs {
+        exclude.push(ignore_glob.clone());
+    }
+
+    for dir in &emmyrc.workspace.ignore_dir {
+        exclude_dirs.push(PathBuf::from(dir));
+    }
+
+    // remove duplicate
+    include.sort();
+    include.dedup();
+
+    // remove duplicate
+    exclude.sort();
+    exclude.dedup();
+
+    (include, exclude, exclude_dirs)
+}
+
+/// Register the `.editorconfig` files of the workspaces as code styles,
+/// shared by the language server and the cli tools
+pub fn load_editorconfig(workspace_folders: &[PathBuf]) -> Option<()> {
+    let mut editorconfig_files = Vec::new();
+
+    for workspace in workspace_folders {
+        // 构建 WalkDir 迭代器，递归遍历工作区目录
+        let walker = WalkDir::new(workspace)
+            .into_iter()
+            .filter_entry(|e| !is_vcs_dir(e, &VCS_DIRS));
+
+        for entry in walker {
+            match entry {
+                Ok(entry) => {
+                    if is_editorconfig(&entry) {
+                        editorconfig_files.push(entry.path().to_path_buf());
+                    }
+                }
+                Err(e) => {
+                    error!("Traversal error: {:?}", e);
+                }
+            }
+        }
+    }
+
+    if editorconfig_files.is_empty() {
+        return None;
+    }
+
+    info!("found editorconfig files: {:?}", editorconfig_files);
+    for file in editorconfig_files {
+        let parent_dir = file
+            .parent()
+            .unwrap()
+            .to_path_buf()
+            .to_string_lossy()
+            .to_string()
+            .replace("\\", "/");
+        let file_normalized = file.to_string_lossy().to_string().replace("\\", "/");
+        update_code_style(&parent_dir, &file_normalized);
+    }
+
+    Some(())
+}
+
+/// 判断目录条目是否为 `.editorconfig` 文件
+fn is_editorconfig(entry: &DirEntry) -> bool {
+    entry.file_type().is_file() && entry.file_name().to_string_lossy() == ".editorconfig"
+}
+
+/// 判断目录条目是否属于需要忽略的版本控制系统目录
+fn is_vcs_dir(entry: &DirEntry, vcs_dirs: &[&str]) -> bool {
+    if !entry.file_type().is_dir() {
+        return false;
+    }
+
+    let name = entry.file_name().to_string_lossy();
+    vcs_dirs.iter().any(|&vcs| vcs == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::reformat_code;
+
+    use super::load_editorconfig;
+
+    #[test]
+    fn test_load_editorconfig() {
+        let workspace =
+            std::env::temp_dir().join(format!("emmylua_editorconfig_{}", std::process::id()));
+        let src = workspace.join("src");
+        fs::create_dir_all(&src).unwrap();
+        fs::create_dir_all(workspace.join(".git")).unwrap();
+        fs::write(
+            workspace.join(".editorconfig"),
+            "root = true\n\n[*.lua]\nindent_style = space\nindent_size = 2\n",
+        )
+        .unwrap();
+        // a config inside a vcs dir is ignored
+        fs::write(
+            workspace.join(".git").join(".editorconfig"),
+            "[*.lua]\nindent_size = 8\n",
+        )
+        .unwrap();
+
+        let result = load_editorconfig(std::slice::from_ref(&workspace));
+        let file_path = src.join("a.lua").to_string_lossy().replace("\\", "/");
+        let formatted = reformat_code("if a then\nb()\nend\n", &file_path);
+        fs::remove_dir_all(&workspace).unwrap();
+
+        assert!(result.is_some());
+        assert_eq!(formatted, "if a then\n  b()\nend\n");
+    }
+}
@@ -8,7 +8,10 @@ pub use document::LuaDocument;
 use emmylua_parser::{LineIndex, LuaParser, LuaSyntaxTree};
 pub use file_id::{FileId, InFiled};
 pub use file_uri_handler::{file_path_to_uri, uri_to_file_path};
-pub use loader::{load_workspace_files, read_file_with_encoding, LuaFileInfo};
+pub use loader::{
+    calculate_include_and_exclude, collect_workspace_files, load_editorconfig,
+    load_workspace_files, read_file_with_encoding, LuaFileInfo,
+};
 use lsp_types::Uri;
 use rowan::{NodeCache, TextRange};
 use std::collections::HashMap;
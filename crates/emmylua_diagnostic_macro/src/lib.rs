@@ -2,7 +2,10 @@ extern crate proc_macro;
 
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{parse_macro_input, spanned::Spanned, Data, DeriveInput, Fields, Ident};
+use syn::{
+    parse_macro_input, spanned::Spanned, Attribute, Data, DeriveInput, Expr, ExprLit, Fields,
+    Ident, Lit, Meta,
+};
 
 // Convert enum variant names to kebab-case
 fn to_kebab_case(ident: &Ident) -> String {
@@ -19,6 +22,26 @@ fn to_kebab_case(ident: &Ident) -> String {
     result
 }
 
+// Join the doc comments of a variant into a single line description
+fn get_doc_description(attrs: &[Attribute]) -> String {
+    let mut lines = Vec::new();
+    for attr in attrs {
+        if !attr.path().is_ident("doc") {
+            continue;
+        }
+        if let Meta::NameValue(name_value) = &attr.meta {
+            if let Expr::Lit(ExprLit {
+                lit: Lit::Str(lit_str),
+                ..
+            }) = &name_value.value
+            {
+                lines.push(lit_str.value().trim().to_string());
+            }
+        }
+    }
+    lines.join(" ")
+}
+
 #[proc_macro_derive(LuaDiagnosticMacro)]
 pub fn lua_diagnostic_macro(input: TokenStream) -> TokenStream {
     // Parse the input TokenStream into DeriveInput
@@ -35,9 +58,10 @@ pub fn lua_diagnostic_macro(input: TokenStream) -> TokenStream {
         }
     };
 
-    // Generate get_name / FromStr / Display / all based on variants
+    // Generate get_name / get_description / FromStr / Display / all based on variants
     let mut variant_idents = Vec::new();
     let mut variant_strings = Vec::new();
+    let mut variant_descriptions = Vec::new();
 
     for variant in variants.iter() {
         let variant_ident = &variant.ident;
@@ -46,6 +70,7 @@ pub fn lua_diagnostic_macro(input: TokenStream) -> TokenStream {
             let kebab_case_string = to_kebab_case(variant_ident);
             variant_idents.push(variant_ident);
             variant_strings.push(kebab_case_string);
+            variant_descriptions.push(get_doc_description(&variant.attrs));
         } else {
             // Only unit variants are supported
             return syn::Error::new(variant.ident.span(), "Only unit variants supported")
@@ -64,6 +89,16 @@ pub fn lua_diagnostic_macro(input: TokenStream) -> TokenStream {
             }
         });
 
+    // Build match arms for get_description()
+    let get_description_arms = variant_idents
+        .iter()
+        .zip(variant_descriptions.iter())
+        .map(|(ident, desc)| {
+            quote! {
+                #name::#ident => #desc
+            }
+        });
+
     // Build match arms for FromStr
     let from_str_arms = variant_idents
         .iter()
@@ -89,6 +124,13 @@ pub fn lua_diagnostic_macro(input: TokenStream) -> TokenStream {
                 }
             }
 
+            pub fn get_description(&self) -> &str {
+                match self {
+                    #(#get_description_arms),*,
+                    _ => ""
+                }
+            }
+
             // Return all variants
             pub fn all() -> Vec<#name> {
                 vec![
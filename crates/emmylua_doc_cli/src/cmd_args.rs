@@ -20,6 +20,15 @@ pub struct CmdArgs {
     )]
     pub output: std::path::PathBuf,
 
+    #[structopt(
+        long,
+        help = "The format of the docs (markdown, json, html or meta)",
+        default_value = "markdown",
+        possible_values = &Format::variants(),
+        case_insensitive = true
+    )]
+    pub format: Format,
+
     #[structopt(
         parse(from_os_str),
         long = "override-template",
@@ -34,3 +43,30 @@ pub struct CmdArgs {
     )]
     pub mixin: Option<std::path::PathBuf>,
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Markdown,
+    Json,
+    Html,
+    Meta,
+}
+
+impl std::str::FromStr for Format {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "markdown" => Ok(Format::Markdown),
+            "json" => Ok(Format::Json),
+            "html" => Ok(Format::Html),
+            "meta" => Ok(Format::Meta),
+            _ => Err(format!("Invalid format: {}", s)),
+        }
+    }
+}
+
+impl Format {
+    pub fn variants() -> [&'static str; 4] {
+        ["markdown", "json", "html", "meta"]
+    }
+}
@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+
+// every string except the names is already escaped html, the templates output them as is
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct HtmlDoc {
+    pub kind: String,
+    pub name: String,
+    pub display: Option<String>,
+    pub supers: Option<String>,
+    pub property: HtmlProperty,
+    pub locations: Vec<String>,
+    pub fields: Vec<HtmlMember>,
+    pub methods: Vec<HtmlMember>,
+    pub functions: Vec<HtmlMember>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HtmlMember {
+    pub name: String,
+    pub anchor: String,
+    pub display: String,
+    pub params: Vec<HtmlParam>,
+    pub returns: Vec<HtmlParam>,
+    pub property: HtmlProperty,
+    pub location: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HtmlParam {
+    pub name: String,
+    pub typ: String,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct HtmlProperty {
+    pub description: Option<String>,
+    pub see: Option<String>,
+    pub deprecated: Option<String>,
+    /// Only set when the visibility is not public
+    pub visibility: Option<String>,
+    pub version: Option<String>,
+    pub other: Option<String>,
+}
+
+/// An entry of the index page and of `search_index.js`, `url` is relative to the output directory
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HtmlIndexEntry {
+    pub name: String,
+    pub kind: String,
+    pub url: String,
+}
@@ -0,0 +1,68 @@
+use std::collections::HashMap;
+
+pub fn escape_html(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '&' => result.push_str("&amp;"),
+            '"' => result.push_str("&quot;"),
+            '\'' => result.push_str("&#39;"),
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+/// Turns the type names of a rendered type into links to their pages
+#[derive(Debug, Default)]
+pub struct TypeLinker {
+    urls: HashMap<String, String>,
+}
+
+impl TypeLinker {
+    pub fn add_type(&mut self, name: &str, url: String) {
+        self.urls.insert(name.to_string(), url);
+    }
+
+    pub fn get_url(&self, name: &str) -> Option<&String> {
+        self.urls.get(name)
+    }
+
+    /// `root` is the relative path from the current page to the output directory
+    pub fn link(&self, text: &str, root: &str) -> String {
+        let mut result = String::with_capacity(text.len());
+        let mut word = String::new();
+        for c in text.chars() {
+            if c.is_alphanumeric() || c == '_' || c == '.' {
+                word.push(c);
+                continue;
+            }
+
+            self.push_word(&mut result, &word, root);
+            word.clear();
+            result.push_str(&escape_html(&c.to_string()));
+        }
+        self.push_word(&mut result, &word, root);
+        result
+    }
+
+    fn push_word(&self, result: &mut String, word: &str, root: &str) {
+        if word.is_empty() {
+            return;
+        }
+
+        match self.urls.get(word) {
+            Some(url) => {
+                result.push_str(&format!(
+                    "<a href=\"{}{}\">{}</a>",
+                    root,
+                    url,
+                    escape_html(word)
+                ));
+            }
+            None => result.push_str(&escape_html(word)),
+        }
+    }
+}
@@ -0,0 +1,407 @@
+mod html_types;
+mod link;
+#[cfg(test)]
+mod test;
+
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use emmylua_code_analysis::{DbIndex, EmmyLuaAnalysis};
+use html_types::{HtmlDoc, HtmlIndexEntry, HtmlMember, HtmlParam, HtmlProperty};
+use link::{escape_html, TypeLinker};
+use tera::Tera;
+
+use crate::{
+    json_generator::{
+        export_doc, GlobalDoc, LocationDoc, MemberDoc, MemberKind, ModuleDoc, PropertyDoc,
+        SignatureDoc, TypeDoc, TypeKind,
+    },
+    markdown_generator::{escape_type_name, init_tl::init_tl},
+};
+
+pub fn generate_html(
+    analysis: &mut EmmyLuaAnalysis,
+    input: PathBuf,
+    output: PathBuf,
+    override_template: Option<PathBuf>,
+) -> Option<()> {
+    for dir in ["types", "modules", "globals"] {
+        let dir_out = output.join(dir);
+        if dir_out.exists() {
+            println!("Clearing {} directory: {:?}", dir, dir_out);
+            std::fs::remove_dir_all(&dir_out).ok()?;
+        } else {
+            println!("Creating {} directory: {:?}", dir, dir_out);
+        }
+        std::fs::create_dir_all(&dir_out).ok()?;
+    }
+
+    let tl = init_html_tl(override_template)?;
+    let db = analysis.compilation.get_db();
+    for (url, text) in build_pages(&tl, db, &input)? {
+        let outpath = output.join(url);
+        println!("output html file: {}", outpath.display());
+        match std::fs::write(outpath, text) {
+            Ok(_) => {}
+            Err(e) => {
+                eprintln!("Failed to write file: {}", e);
+                return None;
+            }
+        }
+    }
+
+    Some(())
+}
+
+/// Render every page, keyed by its path relative to the output directory
+fn build_pages(tl: &Tera, db: &DbIndex, input: &Path) -> Option<Vec<(String, String)>> {
+    let doc = export_doc(db, input);
+
+    let mut linker = TypeLinker::default();
+    for type_doc in &doc.types {
+        linker.add_type(&type_doc.name, get_page_url("types", &type_doc.name));
+    }
+
+    let mut pages = Vec::new();
+    let mut index = Vec::new();
+    let mut search_index = Vec::new();
+    for type_doc in &doc.types {
+        let url = linker.get_url(&type_doc.name)?.clone();
+        let html_doc = build_type_doc(&linker, type_doc);
+        add_index_entries(&mut index, &mut search_index, &html_doc, &url);
+        pages.push((url, render_page(tl, &html_doc)?));
+    }
+
+    for module_doc in &doc.modules {
+        let url = get_page_url("modules", &module_doc.name);
+        let html_doc = build_module_doc(&linker, module_doc);
+        add_index_entries(&mut index, &mut search_index, &html_doc, &url);
+        pages.push((url, render_page(tl, &html_doc)?));
+    }
+
+    for (i, global_doc) in doc.globals.iter().enumerate() {
+        // a global may be declared in several files
+        let url = match doc.globals[..i].iter().any(|g| g.name == global_doc.name) {
+            true => get_page_url("globals", &format!("{}_{}", global_doc.name, i)),
+            false => get_page_url("globals", &global_doc.name),
+        };
+        let html_doc = build_global_doc(&linker, global_doc);
+        add_index_entries(&mut index, &mut search_index, &html_doc, &url);
+        pages.push((url, render_page(tl, &html_doc)?));
+    }
+
+    let mut context = tera::Context::new();
+    context.insert("root", "");
+    context.insert("title", "Index");
+    context.insert("entries", &index);
+    let index_text = match tl.render("html_index_template.tl", &context) {
+        Ok(text) => text,
+        Err(e) => {
+            eprintln!("Failed to render index: {}", e);
+            return None;
+        }
+    };
+    pages.push(("index.html".to_string(), index_text));
+
+    // loaded with a script tag so the pages also work when opened from the file system,
+    // the entries are inserted as text by the search script
+    let search_json = serde_json::to_string(&search_index).ok()?;
+    pages.push((
+        "search_index.js".to_string(),
+        format!("window.SEARCH_INDEX = {};\n", search_json),
+    ));
+
+    Some(pages)
+}
+
+fn init_html_tl(override_template: Option<PathBuf>) -> Option<Tera> {
+    let mut tl = init_tl(override_template)?;
+    tl.register_filter("escape_html", escape_html_filter);
+    Some(tl)
+}
+
+fn escape_html_filter(
+    value: &tera::Value,
+    _: &HashMap<String, tera::Value>,
+) -> tera::Result<tera::Value> {
+    let text = tera::try_get_value!("escape_html", "value", String, value);
+    Ok(tera::Value::String(escape_html(&text)))
+}
+
+fn get_page_url(dir: &str, name: &str) -> String {
+    format!("{}/{}.html", dir, escape_type_name(name))
+}
+
+fn get_anchor(name: &str) -> String {
+    let name: String = name
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '-' })
+        .collect();
+    format!("member-{}", name)
+}
+
+fn render_page(tl: &Tera, html_doc: &HtmlDoc) -> Option<String> {
+    let mut context = tera::Context::new();
+    context.insert("root", "../");
+    context.insert("title", &html_doc.name);
+    context.insert("doc", html_doc);
+    match tl.render("html_doc_template.tl", &context) {
+        Ok(text) => Some(text),
+        Err(e) => {
+            eprintln!("Failed to render template: {}", e);
+            None
+        }
+    }
+}
+
+fn add_index_entries(
+    index: &mut Vec<HtmlIndexEntry>,
+    search_index: &mut Vec<HtmlIndexEntry>,
+    html_doc: &HtmlDoc,
+    url: &str,
+) {
+    let entry = HtmlIndexEntry {
+        name: html_doc.name.clone(),
+        kind: html_doc.kind.clone(),
+        url: url.to_string(),
+    };
+    search_index.push(HtmlIndexEntry {
+        name: entry.name.clone(),
+        kind: entry.kind.clone(),
+        url: entry.url.clone(),
+    });
+    index.push(entry);
+
+    for member in html_doc
+        .fields
+        .iter()
+        .chain(html_doc.methods.iter())
+        .chain(html_doc.functions.iter())
+    {
+        search_index.push(HtmlIndexEntry {
+            name: member.name.clone(),
+            kind: "member".to_string(),
+            url: format!("{}#{}", url, member.anchor),
+        });
+    }
+}
+
+fn build_type_doc(linker: &TypeLinker, type_doc: &TypeDoc) -> HtmlDoc {
+    let kind = match type_doc.kind {
+        TypeKind::Class => "class",
+        TypeKind::Enum => "enum",
+        TypeKind::Alias => "alias",
+    };
+    let mut display = format!("{} {}", kind, escape_html(&type_doc.name));
+    if !type_doc.generics.is_empty() {
+        let generics = type_doc
+            .generics
+            .iter()
+            .map(|generic| match &generic.constraint {
+                Some(constraint) => format!(
+                    "{}: {}",
+                    escape_html(&generic.name),
+                    linker.link(constraint, "../")
+                ),
+                None => escape_html(&generic.name),
+            })
+            .collect::<Vec<_>>();
+        display.push_str(&format!(
+            "{}{}{}",
+            escape_html("<"),
+            generics.join(", "),
+            escape_html(">")
+        ));
+    }
+    if let Some(alias) = &type_doc.alias {
+        display.push_str(&format!(" = {}", linker.link(alias, "../")));
+    }
+
+    let supers = if type_doc.supers.is_empty() {
+        None
+    } else {
+        Some(linker.link(&type_doc.supers.join(", "), "../"))
+    };
+
+    let mut html_doc = HtmlDoc {
+        kind: kind.to_string(),
+        name: type_doc.name.clone(),
+        display: Some(display),
+        supers,
+        property: build_property(linker, &type_doc.property),
+        locations: type_doc.locations.iter().map(render_location).collect(),
+        ..Default::default()
+    };
+    add_members(linker, &mut html_doc, &type_doc.members, &type_doc.name);
+    html_doc
+}
+
+fn build_module_doc(linker: &TypeLinker, module_doc: &ModuleDoc) -> HtmlDoc {
+    let mut display = format!(
+        "local {} = require(\"{}\")",
+        escape_html(get_simple_name(&module_doc.name)),
+        escape_html(&module_doc.name)
+    );
+    // the members are listed below, only name the type of other exports
+    if let (Some(typ), true) = (&module_doc.typ, module_doc.members.is_empty()) {
+        display.push_str(&format!(" -- {}", linker.link(typ, "../")));
+    }
+
+    let mut html_doc = HtmlDoc {
+        kind: "module".to_string(),
+        name: module_doc.name.clone(),
+        display: Some(display),
+        property: build_property(linker, &module_doc.property),
+        locations: vec![escape_html(&module_doc.file)],
+        ..Default::default()
+    };
+    add_members(
+        linker,
+        &mut html_doc,
+        &module_doc.members,
+        get_simple_name(&module_doc.name),
+    );
+    html_doc
+}
+
+fn build_global_doc(linker: &TypeLinker, global_doc: &GlobalDoc) -> HtmlDoc {
+    let display = match &global_doc.signature {
+        Some(signature) => render_function(linker, &global_doc.name, signature),
+        None => format!(
+            "{}: {}",
+            escape_html(&global_doc.name),
+            linker.link(&global_doc.typ, "../")
+        ),
+    };
+
+    let mut html_doc = HtmlDoc {
+        kind: "global".to_string(),
+        name: global_doc.name.clone(),
+        display: Some(display),
+        property: build_property(linker, &global_doc.property),
+        locations: global_doc.location.iter().map(render_location).collect(),
+        ..Default::default()
+    };
+    add_members(linker, &mut html_doc, &global_doc.members, &global_doc.name);
+    html_doc
+}
+
+fn get_simple_name(name: &str) -> &str {
+    name.rsplit('.').next().unwrap_or(name)
+}
+
+fn add_members(linker: &TypeLinker, html_doc: &mut HtmlDoc, members: &[MemberDoc], owner: &str) {
+    for member in members {
+        let full_name = format!("{}.{}", owner, member.name);
+        let (display, params, returns) = match &member.signature {
+            Some(signature) => {
+                let func_name = match signature.is_method {
+                    true => format!("{}:{}", owner, member.name),
+                    false => full_name.clone(),
+                };
+                let params = signature
+                    .params
+                    .iter()
+                    .filter(|param| param.description.is_some())
+                    .map(|param| HtmlParam {
+                        name: escape_html(&param.name),
+                        typ: linker.link(param.typ.as_deref().unwrap_or("any"), "../"),
+                        description: param.description.as_deref().map(escape_html),
+                    })
+                    .collect();
+                let returns = signature
+                    .returns
+                    .iter()
+                    .filter(|ret| ret.description.is_some())
+                    .map(|ret| HtmlParam {
+                        name: escape_html(ret.name.as_deref().unwrap_or("")),
+                        typ: linker.link(&ret.typ, "../"),
+                        description: ret.description.as_deref().map(escape_html),
+                    })
+                    .collect();
+                (
+                    render_function(linker, &func_name, signature),
+                    params,
+                    returns,
+                )
+            }
+            None => {
+                let display = format!(
+                    "{}: {}",
+                    escape_html(&full_name),
+                    linker.link(&member.typ, "../")
+                );
+                (display, Vec::new(), Vec::new())
+            }
+        };
+
+        let html_member = HtmlMember {
+            name: full_name,
+            anchor: get_anchor(&member.name),
+            display,
+            params,
+            returns,
+            property: build_property(linker, &member.property),
+            location: member.location.as_ref().map(render_location),
+        };
+        match member.kind {
+            MemberKind::Method => html_doc.methods.push(html_member),
+            MemberKind::Function => html_doc.functions.push(html_member),
+            MemberKind::Field => html_doc.fields.push(html_member),
+        }
+    }
+}
+
+fn render_function(linker: &TypeLinker, func_name: &str, signature: &SignatureDoc) -> String {
+    let params = signature
+        .params
+        .iter()
+        .map(|param| match &param.typ {
+            Some(typ) => format!("{}: {}", escape_html(&param.name), linker.link(typ, "../")),
+            None => escape_html(&param.name),
+        })
+        .collect::<Vec<_>>();
+    let returns = signature
+        .returns
+        .iter()
+        .map(|ret| linker.link(&ret.typ, "../"))
+        .collect::<Vec<_>>();
+
+    let mut result = String::new();
+    if signature.is_async {
+        result.push_str("async ");
+    }
+    result.push_str("function ");
+    result.push_str(&escape_html(func_name));
+    result.push_str(&format!("({})", params.join(", ")));
+    if !returns.is_empty() {
+        result.push_str(&format!(" {} {}", escape_html("->"), returns.join(", ")));
+    }
+    for overload in &signature.overloads {
+        result.push_str(&format!("\n---@overload {}", linker.link(overload, "../")));
+    }
+    result
+}
+
+fn build_property(linker: &TypeLinker, property: &PropertyDoc) -> HtmlProperty {
+    HtmlProperty {
+        description: property.description.as_deref().map(escape_html),
+        see: property.see.as_deref().map(|see| linker.link(see, "../")),
+        deprecated: property.deprecated.as_deref().map(escape_html),
+        visibility: match property.visibility.as_str() {
+            "public" => None,
+            visibility => Some(escape_html(visibility)),
+        },
+        version: match property.version_conds.is_empty() {
+            true => None,
+            false => Some(escape_html(&property.version_conds.join(", "))),
+        },
+        other: property.other.as_deref().map(escape_html),
+    }
+}
+
+fn render_location(location: &LocationDoc) -> String {
+    escape_html(&format!("{}:{}", location.file, location.line))
+}
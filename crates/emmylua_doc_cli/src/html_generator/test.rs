@@ -0,0 +1,101 @@
+use emmylua_code_analysis::VirtualWorkspace;
+
+use super::{build_pages, init_html_tl};
+
+fn build(content: &str) -> Vec<(String, String)> {
+    let mut ws = VirtualWorkspace::new();
+    ws.def_file("shapes.lua", content);
+    let input = ws.virtual_url_generator.base.clone();
+    let tl = init_html_tl(None).unwrap();
+    build_pages(&tl, ws.analysis.compilation.get_db(), &input).unwrap()
+}
+
+fn get_page<'a>(pages: &'a [(String, String)], url: &str) -> &'a str {
+    pages
+        .iter()
+        .find(|(page_url, _)| page_url == url)
+        .map(|(_, text)| text.as_str())
+        .unwrap()
+}
+
+#[test]
+fn test_pages() {
+    let pages = build(
+        r#"
+        ---@class Shape
+        local Shape = {}
+
+        ---@alias ShapeOrNil Shape?
+
+        ---@enum Color
+        Color = {
+            Red = 1,
+        }
+
+        return Shape
+        "#,
+    );
+    let mut urls: Vec<_> = pages.iter().map(|(url, _)| url.as_str()).collect();
+    urls.sort();
+    assert_eq!(
+        urls,
+        vec![
+            "index.html",
+            "modules/shapes.html",
+            "search_index.js",
+            "types/Color.html",
+            "types/Shape.html",
+            "types/ShapeOrNil.html",
+        ]
+    );
+
+    let alias = get_page(&pages, "types/ShapeOrNil.html");
+    assert!(alias.contains(r#"alias ShapeOrNil = (<a href="../types/Shape.html">Shape</a>|nil)"#));
+    let color = get_page(&pages, "types/Color.html");
+    assert!(color.contains("Color.Red: 1"));
+}
+
+#[test]
+fn test_class_members() {
+    let pages = build(
+        r#"
+        ---@class Box<T>
+        ---@field value T the value
+        local Box = {}
+
+        ---@overload fun(self: Box, key: string): T
+        ---@param index integer the index
+        ---@return T item
+        function Box:get(index)
+        end
+        "#,
+    );
+    let page = get_page(&pages, "types/Box.html");
+    assert!(page.contains("class Box&lt;T&gt;"));
+    assert!(page.contains("Box.value: T"));
+    assert!(page.contains("function Box:get(index: integer) -&gt; T"));
+    assert!(page.contains(
+        r#"---@overload fun(self: <a href="../types/Box.html">Box</a>, key: string) -&gt; T"#
+    ));
+    assert!(page.contains("<td>the index</td>"));
+}
+
+#[test]
+fn test_escape_names() {
+    let pages = build(
+        r#"
+        ---@class Tag
+        ---@field ["a<b&c"] integer a < b & c
+        local Tag = {}
+        "#,
+    );
+    let page = get_page(&pages, "types/Tag.html");
+    assert!(page.contains("<h3><a href=\"#member-a-b-c\">Tag.a&lt;b&amp;c</a></h3>"));
+    assert!(page.contains("Tag.a&lt;b&amp;c: integer"));
+    assert!(page.contains("a &lt; b &amp; c"));
+    assert!(!page.contains("a<b"));
+
+    // the search script inserts the names as text
+    let search = get_page(&pages, "search_index.js");
+    assert!(search.contains(r#""name":"Tag.a<b&c""#));
+}
@@ -4,8 +4,12 @@ use emmylua_code_analysis::{
     load_configs, load_workspace_files, EmmyLuaAnalysis, Emmyrc, LuaFileInfo,
 };
 
+/// `ignore_dirs` are skipped when collecting files, eg: the output directory
 #[allow(unused)]
-pub fn load_workspace(workspace_folders: Vec<&str>) -> Option<EmmyLuaAnalysis> {
+pub fn load_workspace(
+    workspace_folders: Vec<&str>,
+    ignore_dirs: Vec<PathBuf>,
+) -> Option<EmmyLuaAnalysis> {
     let mut analysis = EmmyLuaAnalysis::new();
     analysis.init_std_lib(false);
 
@@ -24,6 +28,12 @@ pub fn load_workspace(workspace_folders: Vec<&str>) -> Option<EmmyLuaAnalysis> {
     ];
     let mut emmyrc = load_configs(config_files, None);
     emmyrc.pre_process_emmyrc(&main_path);
+    for dir in ignore_dirs {
+        emmyrc
+            .workspace
+            .ignore_dir
+            .push(dir.to_string_lossy().to_string());
+    }
     let emmyrc = Arc::new(emmyrc);
 
     for root in &emmyrc.workspace.workspace_roots {
@@ -0,0 +1,392 @@
+use std::path::Path;
+
+use emmylua_code_analysis::{
+    humanize_type, DbIndex, FileId, LuaMemberKey, LuaMemberOwner, LuaSemanticDeclId, LuaType,
+    LuaTypeDecl, ModuleInfo, RenderLevel,
+};
+use emmylua_parser::VisibilityKind;
+use rowan::TextRange;
+
+use super::json_types::{
+    DocJson, GenericDoc, GlobalDoc, LocationDoc, MemberDoc, MemberKind, ModuleDoc, ParamDoc,
+    PropertyDoc, ReturnDoc, SignatureDoc, TypeDoc, TypeKind, DOC_JSON_VERSION,
+};
+
+/// Collect everything documented in the main workspace, paths are made relative to `input`
+pub fn export_doc(db: &DbIndex, input: &Path) -> DocJson {
+    let mut types: Vec<TypeDoc> = db
+        .get_type_index()
+        .get_all_types()
+        .into_iter()
+        .filter_map(|type_decl| export_type(db, input, type_decl))
+        .collect();
+    types.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let mut modules: Vec<ModuleDoc> = db
+        .get_module_index()
+        .get_module_infos()
+        .into_iter()
+        .filter_map(|module| export_module(db, input, module))
+        .collect();
+    modules.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let mut globals: Vec<GlobalDoc> = Vec::new();
+    for decl_id in db.get_decl_index().get_global_decls() {
+        if !is_main_file(db, decl_id.file_id) {
+            continue;
+        }
+        let Some(decl) = db.get_decl_index().get_decl(&decl_id) else {
+            continue;
+        };
+        let typ = decl.get_type().cloned().unwrap_or(LuaType::Unknown);
+        // classes and enums are documented as types
+        if matches!(typ, LuaType::Ref(_) | LuaType::Def(_)) {
+            continue;
+        }
+
+        let members = match &typ {
+            LuaType::TableConst(table) => {
+                export_members(db, input, LuaMemberOwner::Element(table.clone()))
+            }
+            _ => Vec::new(),
+        };
+        globals.push(GlobalDoc {
+            name: decl.get_name().to_string(),
+            typ: render_type(db, &typ),
+            signature: export_signature(db, &typ),
+            members,
+            property: export_value_property(db, LuaSemanticDeclId::LuaDecl(decl_id), &typ),
+            location: export_location(db, input, decl.get_file_id(), decl.get_range()),
+        });
+    }
+    globals.sort_by(|a, b| {
+        let a_key = (
+            &a.name,
+            a.location.as_ref().map(|loc| (&loc.file, loc.line)),
+        );
+        let b_key = (
+            &b.name,
+            b.location.as_ref().map(|loc| (&loc.file, loc.line)),
+        );
+        a_key.cmp(&b_key)
+    });
+
+    DocJson {
+        version: DOC_JSON_VERSION,
+        types,
+        modules,
+        globals,
+    }
+}
+
+fn is_main_file(db: &DbIndex, file_id: FileId) -> bool {
+    db.get_module_index()
+        .get_module(file_id)
+        .is_some_and(|module| module.workspace_id.is_main())
+}
+
+fn export_type(db: &DbIndex, input: &Path, type_decl: &LuaTypeDecl) -> Option<TypeDoc> {
+    let locations = type_decl.get_locations();
+    if !locations.iter().any(|loc| is_main_file(db, loc.file_id)) {
+        return None;
+    }
+
+    let type_id = type_decl.get_id();
+    let kind = if type_decl.is_class() {
+        TypeKind::Class
+    } else if type_decl.is_enum() {
+        TypeKind::Enum
+    } else {
+        TypeKind::Alias
+    };
+
+    let mut attributes = Vec::new();
+    if type_decl.is_exact() {
+        attributes.push("exact".to_string());
+    }
+    if type_decl.is_partial() {
+        attributes.push("partial".to_string());
+    }
+    if type_decl.is_enum_key() {
+        attributes.push("key".to_string());
+    }
+
+    let generics = db
+        .get_type_index()
+        .get_generic_params(&type_id)
+        .map(|params| export_generics(db, params))
+        .unwrap_or_default();
+    let supers = db
+        .get_type_index()
+        .get_super_types(&type_id)
+        .unwrap_or_default()
+        .iter()
+        .map(|super_type| render_type(db, super_type))
+        .collect();
+
+    let (alias, members) = if kind == TypeKind::Alias {
+        let origin = type_decl
+            .get_alias_origin(db, None)
+            .map(|origin| render_type(db, &origin));
+        (origin, Vec::new())
+    } else {
+        let members = export_members(db, input, LuaMemberOwner::Type(type_id.clone()));
+        (None, members)
+    };
+
+    Some(TypeDoc {
+        name: type_decl.get_full_name().to_string(),
+        kind,
+        namespace: type_decl
+            .get_namespace()
+            .map(|namespace| namespace.to_string()),
+        attributes,
+        generics,
+        supers,
+        alias,
+        members,
+        property: export_property(db, LuaSemanticDeclId::TypeDecl(type_id)),
+        locations: locations
+            .iter()
+            .filter_map(|loc| export_location(db, input, loc.file_id, loc.range))
+            .collect(),
+    })
+}
+
+fn export_module(db: &DbIndex, input: &Path, module: &ModuleInfo) -> Option<ModuleDoc> {
+    if !module.workspace_id.is_main() {
+        return None;
+    }
+
+    let members = match &module.export_type {
+        Some(LuaType::Def(type_id)) => {
+            export_members(db, input, LuaMemberOwner::Type(type_id.clone()))
+        }
+        Some(LuaType::TableConst(table)) => {
+            export_members(db, input, LuaMemberOwner::Element(table.clone()))
+        }
+        Some(LuaType::Instance(instance)) => export_members(
+            db,
+            input,
+            LuaMemberOwner::Element(instance.get_range().clone()),
+        ),
+        _ => Vec::new(),
+    };
+
+    let mut property = match &module.property_owner_id {
+        Some(property_id) => export_property(db, property_id.clone()),
+        None => PropertyDoc::default(),
+    };
+    if let Some(version_conds) = &module.version_conds {
+        property.version_conds = version_conds.iter().map(|cond| cond.to_string()).collect();
+    }
+
+    Some(ModuleDoc {
+        name: module.full_module_name.clone(),
+        file: get_relative_file(db, input, module.file_id)?,
+        typ: module
+            .export_type
+            .as_ref()
+            .map(|export_type| render_type(db, export_type)),
+        members,
+        property,
+    })
+}
+
+fn export_members(db: &DbIndex, input: &Path, owner: LuaMemberOwner) -> Vec<MemberDoc> {
+    let Some(members) = db.get_member_index().get_sorted_members(&owner) else {
+        return Vec::new();
+    };
+
+    let mut result = Vec::new();
+    for member in members {
+        let name = match member.get_key() {
+            LuaMemberKey::Name(name) => name.to_string(),
+            LuaMemberKey::Integer(i) => format!("[{}]", i),
+            _ => continue,
+        };
+        let typ = member.get_decl_type();
+        let signature = export_signature(db, &typ);
+        let kind = match &signature {
+            Some(signature) if signature.is_method => MemberKind::Method,
+            _ if typ.is_function() => MemberKind::Function,
+            _ => MemberKind::Field,
+        };
+        result.push(MemberDoc {
+            name,
+            kind,
+            typ: render_type(db, &typ),
+            signature,
+            property: export_value_property(db, LuaSemanticDeclId::Member(member.get_id()), &typ),
+            location: export_location(db, input, member.get_file_id(), member.get_range()),
+        });
+    }
+
+    result
+}
+
+fn export_signature(db: &DbIndex, typ: &LuaType) -> Option<SignatureDoc> {
+    match typ {
+        LuaType::Signature(signature_id) => {
+            let signature = db.get_signature_index().get(signature_id)?;
+            let params = signature
+                .get_type_params()
+                .into_iter()
+                .enumerate()
+                .map(|(idx, (name, typ))| {
+                    let param_info = signature.get_param_info_by_id(idx);
+                    ParamDoc {
+                        name,
+                        typ: typ.map(|typ| render_type(db, &typ)),
+                        nullable: param_info.is_some_and(|info| info.nullable),
+                        description: param_info.and_then(|info| info.description.clone()),
+                    }
+                })
+                .collect();
+            let returns = signature
+                .return_docs
+                .iter()
+                .map(|ret| ReturnDoc {
+                    name: ret.name.clone(),
+                    typ: render_type(db, &ret.type_ref),
+                    description: ret.description.clone(),
+                })
+                .collect();
+
+            Some(SignatureDoc {
+                generics: export_generics(db, &signature.generic_params),
+                params,
+                returns,
+                overloads: signature
+                    .overloads
+                    .iter()
+                    .map(|overload| render_type(db, &LuaType::DocFunction(overload.clone())))
+                    .collect(),
+                is_async: signature.is_async,
+                is_method: signature.is_colon_define,
+                is_nodiscard: signature.is_nodiscard,
+            })
+        }
+        LuaType::DocFunction(func) => Some(SignatureDoc {
+            generics: Vec::new(),
+            params: func
+                .get_params()
+                .iter()
+                .map(|(name, typ)| ParamDoc {
+                    name: name.clone(),
+                    typ: typ.as_ref().map(|typ| render_type(db, typ)),
+                    nullable: typ.as_ref().is_some_and(|typ| typ.is_optional()),
+                    description: None,
+                })
+                .collect(),
+            returns: func
+                .get_ret()
+                .iter()
+                .map(|ret| ReturnDoc {
+                    name: None,
+                    typ: render_type(db, ret),
+                    description: None,
+                })
+                .collect(),
+            overloads: Vec::new(),
+            is_async: func.is_async(),
+            is_method: func.is_colon_define(),
+            is_nodiscard: false,
+        }),
+        _ => None,
+    }
+}
+
+fn export_generics(db: &DbIndex, params: &[(String, Option<LuaType>)]) -> Vec<GenericDoc> {
+    params
+        .iter()
+        .map(|(name, constraint)| GenericDoc {
+            name: name.clone(),
+            constraint: constraint
+                .as_ref()
+                .map(|constraint| render_type(db, constraint)),
+        })
+        .collect()
+}
+
+// the comments of a function statement are attached to its signature
+fn export_value_property(
+    db: &DbIndex,
+    semantic_decl: LuaSemanticDeclId,
+    typ: &LuaType,
+) -> PropertyDoc {
+    if db
+        .get_property_index()
+        .get_property(&semantic_decl)
+        .is_none()
+    {
+        if let LuaType::Signature(signature_id) = typ {
+            return export_property(db, LuaSemanticDeclId::Signature(*signature_id));
+        }
+    }
+
+    export_property(db, semantic_decl)
+}
+
+fn export_property(db: &DbIndex, semantic_decl: LuaSemanticDeclId) -> PropertyDoc {
+    let mut doc_property = PropertyDoc::default();
+    let Some(property) = db.get_property_index().get_property(&semantic_decl) else {
+        return doc_property;
+    };
+
+    doc_property.description = property
+        .description
+        .as_ref()
+        .filter(|description| !description.is_empty())
+        .map(|description| description.to_string());
+    doc_property.see = property.see_content.as_ref().map(|s| s.to_string());
+    doc_property.other = property.other_content.as_ref().map(|s| s.to_string());
+    if property.is_deprecated {
+        doc_property.deprecated = Some(
+            property
+                .deprecated_message
+                .as_ref()
+                .map(|s| s.to_string())
+                .unwrap_or("Deprecated".to_string()),
+        );
+    }
+    doc_property.visibility = match property.visibility.unwrap_or(VisibilityKind::Public) {
+        VisibilityKind::Protected => "protected",
+        VisibilityKind::Private => "private",
+        VisibilityKind::Internal => "internal",
+        VisibilityKind::Package => "package",
+        VisibilityKind::Public | VisibilityKind::None => "public",
+    }
+    .to_string();
+    if let Some(version_conds) = &property.version_conds {
+        doc_property.version_conds = version_conds.iter().map(|cond| cond.to_string()).collect();
+    }
+
+    doc_property
+}
+
+fn export_location(
+    db: &DbIndex,
+    input: &Path,
+    file_id: FileId,
+    range: TextRange,
+) -> Option<LocationDoc> {
+    let document = db.get_vfs().get_document(&file_id)?;
+    let (line, col) = document.get_line_col(range.start())?;
+    Some(LocationDoc {
+        file: get_relative_file(db, input, file_id)?,
+        line: line + 1,
+        column: col + 1,
+    })
+}
+
+fn get_relative_file(db: &DbIndex, input: &Path, file_id: FileId) -> Option<String> {
+    let file_path = db.get_vfs().get_file_path(&file_id)?;
+    let relative_path = file_path.strip_prefix(input).unwrap_or(file_path);
+    Some(relative_path.to_string_lossy().replace('\\', "/"))
+}
+
+// the detailed level expands the fields of classes, the docs link to them instead
+fn render_type(db: &DbIndex, typ: &LuaType) -> String {
+    humanize_type(db, typ, RenderLevel::Simple)
+}
@@ -0,0 +1,166 @@
+use serde::{Deserialize, Serialize};
+
+/// Bumped whenever a field is removed or changes meaning, new fields may be added without a bump
+pub const DOC_JSON_VERSION: u32 = 1;
+
+/// The root of `doc.json`
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct DocJson {
+    pub version: u32,
+    /// Classes, enums and aliases sorted by full name
+    pub types: Vec<TypeDoc>,
+    /// Modules sorted by full module name
+    pub modules: Vec<ModuleDoc>,
+    /// Globals which are not a class or enum, sorted by name
+    pub globals: Vec<GlobalDoc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum TypeKind {
+    Class,
+    Enum,
+    Alias,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TypeDoc {
+    /// The full name, including the namespace
+    pub name: String,
+    pub kind: TypeKind,
+    pub namespace: Option<String>,
+    /// `exact`, `partial` and `key` (enum keys)
+    pub attributes: Vec<String>,
+    pub generics: Vec<GenericDoc>,
+    /// Rendered super types
+    pub supers: Vec<String>,
+    /// The rendered origin of an alias
+    pub alias: Option<String>,
+    pub members: Vec<MemberDoc>,
+    pub property: PropertyDoc,
+    /// Every declaration of the type, partial classes may have several
+    pub locations: Vec<LocationDoc>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GenericDoc {
+    pub name: String,
+    /// The rendered constraint of `---@generic T: Constraint`
+    pub constraint: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum MemberKind {
+    Field,
+    /// Defined with `:`
+    Method,
+    /// Any other function, e.g. defined with `.`
+    Function,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MemberDoc {
+    /// The member name, integer keys are rendered as `[1]`
+    pub name: String,
+    pub kind: MemberKind,
+    /// The rendered type of the member
+    #[serde(rename = "type")]
+    pub typ: String,
+    /// Only present for methods
+    pub signature: Option<SignatureDoc>,
+    pub property: PropertyDoc,
+    pub location: Option<LocationDoc>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SignatureDoc {
+    pub generics: Vec<GenericDoc>,
+    pub params: Vec<ParamDoc>,
+    pub returns: Vec<ReturnDoc>,
+    /// Rendered `---@overload` function types
+    pub overloads: Vec<String>,
+    pub is_async: bool,
+    /// Defined with `:`, `self` is not part of `params`
+    pub is_method: bool,
+    pub is_nodiscard: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ParamDoc {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub typ: Option<String>,
+    pub nullable: bool,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ReturnDoc {
+    pub name: Option<String>,
+    #[serde(rename = "type")]
+    pub typ: String,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ModuleDoc {
+    /// The full module name used by `require`
+    pub name: String,
+    /// The path of the file relative to the input directory
+    pub file: String,
+    /// The rendered type of the value returned by the module
+    #[serde(rename = "type")]
+    pub typ: Option<String>,
+    pub members: Vec<MemberDoc>,
+    pub property: PropertyDoc,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GlobalDoc {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub typ: String,
+    /// Only present for functions
+    pub signature: Option<SignatureDoc>,
+    /// The fields of a global table
+    pub members: Vec<MemberDoc>,
+    pub property: PropertyDoc,
+    pub location: Option<LocationDoc>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PropertyDoc {
+    pub description: Option<String>,
+    pub see: Option<String>,
+    /// The deprecation message, `Deprecated` when `---@deprecated` has no message
+    pub deprecated: Option<String>,
+    /// `public`, `protected`, `private`, `package` or `internal`
+    pub visibility: String,
+    /// `---@version` conditions, eg: `>= Lua 5.3`
+    pub version_conds: Vec<String>,
+    pub other: Option<String>,
+}
+
+impl Default for PropertyDoc {
+    fn default() -> Self {
+        Self {
+            description: None,
+            see: None,
+            deprecated: None,
+            visibility: "public".to_string(),
+            version_conds: Vec::new(),
+            other: None,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LocationDoc {
+    /// The path of the file relative to the input directory, separated by `/`
+    pub file: String,
+    /// 1-based line
+    pub line: usize,
+    /// 1-based column
+    pub column: usize,
+}
@@ -0,0 +1,42 @@
+mod export;
+mod json_types;
+#[cfg(test)]
+mod test;
+
+use std::path::PathBuf;
+
+use emmylua_code_analysis::EmmyLuaAnalysis;
+pub use export::export_doc;
+pub use json_types::*;
+
+pub fn generate_json(
+    analysis: &mut EmmyLuaAnalysis,
+    input: PathBuf,
+    output: PathBuf,
+) -> Option<()> {
+    if !output.exists() {
+        println!("Creating output directory: {:?}", output);
+        std::fs::create_dir_all(&output).ok()?;
+    }
+
+    let db = analysis.compilation.get_db();
+    let doc = export_doc(db, &input);
+    let json_text = match serde_json::to_string_pretty(&doc) {
+        Ok(text) => text,
+        Err(e) => {
+            eprintln!("Failed to serialize doc: {}", e);
+            return None;
+        }
+    };
+
+    let outpath = output.join("doc.json");
+    println!("output json file: {}", outpath.display());
+    match std::fs::write(outpath, json_text) {
+        Ok(_) => {}
+        Err(e) => {
+            eprintln!("Failed to write file: {}", e);
+            return None;
+        }
+    }
+    Some(())
+}
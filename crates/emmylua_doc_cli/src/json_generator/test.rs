@@ -0,0 +1,135 @@
+use emmylua_code_analysis::VirtualWorkspace;
+
+use super::{export_doc, DocJson, MemberKind, TypeDoc, TypeKind};
+
+const SHAPES: &str = r#"
+---@class Shape
+---@field name string the name
+---@field ["a<b&c"] integer
+local Shape = {}
+
+---@overload fun(self: Shape, scale: number): number
+---@param w number the width
+---@return number area
+function Shape:area(w)
+    return w
+end
+
+---@param name string
+---@return Shape
+function Shape.new(name)
+    return Shape
+end
+
+---@class Box<T>: Shape
+---@field value T
+
+---@alias ShapeOrNil Shape?
+
+---@enum Color
+Color = {
+    Red = 1,
+    Green = 2,
+}
+
+return Shape
+"#;
+
+fn export(content: &str) -> DocJson {
+    let mut ws = VirtualWorkspace::new();
+    ws.def_file("shapes.lua", content);
+    let input = ws.virtual_url_generator.base.clone();
+    export_doc(ws.analysis.compilation.get_db(), &input)
+}
+
+fn get_type<'a>(doc: &'a DocJson, name: &str) -> &'a TypeDoc {
+    doc.types.iter().find(|typ| typ.name == name).unwrap()
+}
+
+#[test]
+fn test_types() {
+    let doc = export(SHAPES);
+    let names: Vec<_> = doc.types.iter().map(|typ| typ.name.as_str()).collect();
+    assert_eq!(names, vec!["Box", "Color", "Shape", "ShapeOrNil"]);
+
+    let shape = get_type(&doc, "Shape");
+    assert_eq!(shape.kind, TypeKind::Class);
+    let members: Vec<_> = shape
+        .members
+        .iter()
+        .map(|member| (member.name.as_str(), member.kind, member.typ.as_str()))
+        .collect();
+    assert_eq!(
+        members,
+        vec![
+            ("name", MemberKind::Field, "string"),
+            ("a<b&c", MemberKind::Field, "integer"),
+            ("area", MemberKind::Method, "fun(w: number) -> number"),
+            ("new", MemberKind::Function, "fun(name: string) -> Shape"),
+        ]
+    );
+    assert_eq!(
+        shape.members[0].property.description.as_deref(),
+        Some("the name")
+    );
+    let location = shape.locations[0].clone();
+    assert_eq!((location.file.as_str(), location.line), ("shapes.lua", 2));
+
+    let boxed = get_type(&doc, "Box");
+    assert_eq!(boxed.generics[0].name, "T");
+    assert_eq!(boxed.supers, vec!["Shape"]);
+    assert_eq!(boxed.members[0].typ, "T");
+}
+
+#[test]
+fn test_alias_and_enum() {
+    let doc = export(SHAPES);
+    let alias = get_type(&doc, "ShapeOrNil");
+    assert_eq!(alias.kind, TypeKind::Alias);
+    assert_eq!(alias.alias.as_deref(), Some("(Shape|nil)"));
+    assert!(alias.members.is_empty());
+
+    let color = get_type(&doc, "Color");
+    assert_eq!(color.kind, TypeKind::Enum);
+    let members: Vec<_> = color
+        .members
+        .iter()
+        .map(|member| (member.name.as_str(), member.typ.as_str()))
+        .collect();
+    assert_eq!(members, vec![("Red", "1"), ("Green", "2")]);
+}
+
+#[test]
+fn test_signature() {
+    let doc = export(SHAPES);
+    let area = get_type(&doc, "Shape")
+        .members
+        .iter()
+        .find(|member| member.name == "area")
+        .unwrap();
+    let signature = area.signature.as_ref().unwrap();
+    assert!(signature.is_method);
+    assert_eq!(signature.params.len(), 1);
+    assert_eq!(signature.params[0].typ.as_deref(), Some("number"));
+    assert_eq!(
+        signature.params[0].description.as_deref(),
+        Some("the width")
+    );
+    assert_eq!(signature.returns[0].name.as_deref(), Some("area"));
+    assert_eq!(
+        signature.overloads,
+        vec!["fun(self: Shape, scale: number) -> number"]
+    );
+}
+
+#[test]
+fn test_module() {
+    let doc = export(SHAPES);
+    assert_eq!(doc.modules.len(), 1);
+    let module = &doc.modules[0];
+    assert_eq!(module.name, "shapes");
+    assert_eq!(module.file, "shapes.lua");
+    assert_eq!(module.typ.as_deref(), Some("Shape"));
+    // the enum is documented as a type
+    assert!(doc.globals.iter().all(|global| global.name != "Color"));
+}
@@ -1,9 +1,12 @@
-use cmd_args::CmdArgs;
+use cmd_args::{CmdArgs, Format};
 use structopt::StructOpt;
 
 mod cmd_args;
+mod html_generator;
 mod init;
+mod json_generator;
 mod markdown_generator;
+mod meta_generator;
 
 fn main() {
     let args = CmdArgs::from_args();
@@ -12,14 +15,33 @@ fn main() {
         input = std::env::current_dir().ok().unwrap().join(&input);
     }
 
-    let analysis = init::load_workspace(vec![input.to_str().unwrap()]);
+    let mut output = args.output;
+    if output.is_relative() {
+        output = std::env::current_dir().ok().unwrap().join(&output);
+    }
+
+    // the generated files must not be indexed again when the output is inside the input
+    let analysis = init::load_workspace(vec![input.to_str().unwrap()], vec![output.clone()]);
     if let Some(mut analysis) = analysis {
-        markdown_generator::generate_markdown(
-            &mut analysis,
-            input,
-            args.output,
-            args.override_template,
-            args.mixin,
-        );
+        match args.format {
+            Format::Markdown => {
+                markdown_generator::generate_markdown(
+                    &mut analysis,
+                    input,
+                    output,
+                    args.override_template,
+                    args.mixin,
+                );
+            }
+            Format::Json => {
+                json_generator::generate_json(&mut analysis, input, output);
+            }
+            Format::Html => {
+                html_generator::generate_html(&mut analysis, input, output, args.override_template);
+            }
+            Format::Meta => {
+                meta_generator::generate_meta(&mut analysis, input, output);
+            }
+        }
     }
 }
@@ -8,7 +8,7 @@ use tera::Tera;
 
 use crate::markdown_generator::{
     escape_type_name,
-    gen::mod_gen::generate_member_owner_module,
+    gen::{mod_gen::generate_member_owner_module, type_relation::render_type_links},
     markdown_types::{Doc, IndexStruct, MkdocsIndex},
     render::{render_const_type, render_function_type},
 };
@@ -94,6 +94,7 @@ fn generate_simple_global(db: &DbIndex, decl: &LuaDecl, doc: &mut Doc) -> Option
 
     let name = decl.get_name();
     let ty = decl.get_type().unwrap_or(&LuaType::Unknown);
+    doc.links = render_type_links(db, ty, "../types/");
     if ty.is_function() {
         let display = render_function_type(db, ty, &name, false);
         doc.display = Some(display);
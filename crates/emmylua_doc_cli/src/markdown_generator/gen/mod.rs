@@ -2,12 +2,14 @@ mod global_gen;
 mod index_gen;
 mod mod_gen;
 mod typ_gen;
+mod type_relation;
 
 use emmylua_code_analysis::{DbIndex, LuaSemanticDeclId};
 pub use global_gen::generate_global_markdown;
 pub use index_gen::generate_index;
 pub use mod_gen::generate_module_markdown;
 pub use typ_gen::generate_type_markdown;
+pub use type_relation::TypeRelations;
 
 use super::markdown_types::Property;
 
@@ -9,6 +9,7 @@ use tera::Tera;
 
 use crate::markdown_generator::{
     escape_type_name,
+    gen::type_relation::render_type_links,
     markdown_types::{Doc, IndexStruct, MemberDoc, MkdocsIndex},
     render::{render_const_type, render_function_type},
 };
@@ -118,6 +119,7 @@ pub fn generate_member_owner_module(
                 _ => continue,
             };
 
+            let member_links = render_type_links(db, &member_typ, "../types/");
             let title_name = format!("{}.{}", owner_name, name);
             if member_typ.is_function() {
                 let func_name = format!("{}.{}", owner_name, name);
@@ -126,6 +128,7 @@ pub fn generate_member_owner_module(
                     name: title_name,
                     display,
                     property: member_property,
+                    links: member_links,
                 });
             } else if member_typ.is_const() {
                 let display = render_const_type(db, &member_typ);
@@ -133,6 +136,7 @@ pub fn generate_member_owner_module(
                     name: title_name,
                     display: format!("```lua\n{}.{}: {}\n```\n", owner_name, name, display),
                     property: member_property,
+                    links: member_links,
                 });
             } else {
                 let typ_display = humanize_type(db, &member_typ, RenderLevel::Detailed);
@@ -140,6 +144,7 @@ pub fn generate_member_owner_module(
                     name: title_name,
                     display: format!("```lua\n{}.{} : {}\n```\n", owner_name, name, typ_display),
                     property: member_property,
+                    links: member_links,
                 });
             }
         }
@@ -1,16 +1,22 @@
-use std::path::Path;
+use std::{collections::HashSet, path::Path};
 
 use emmylua_code_analysis::{
-    humanize_type, DbIndex, LuaMemberKey, LuaMemberOwner, LuaSemanticDeclId, LuaTypeDecl,
-    RenderLevel,
+    humanize_type, DbIndex, LuaMemberKey, LuaMemberOwner, LuaSemanticDeclId, LuaType, LuaTypeDecl,
+    LuaTypeDeclId, RenderLevel,
 };
 use emmylua_parser::VisibilityKind;
 use tera::{Context, Tera};
 
 use crate::markdown_generator::{
     escape_type_name,
-    gen::collect_property,
-    markdown_types::{Doc, IndexStruct, MemberDoc, MkdocsIndex},
+    gen::{
+        collect_property,
+        type_relation::{
+            get_subclasses, is_doc_type, render_type_id_links, render_type_link, render_type_links,
+            TypeRelations,
+        },
+    },
+    markdown_types::{Doc, IndexStruct, InheritedDoc, MemberDoc, MkdocsIndex, UsedByDoc},
     render::{render_const_type, render_function_type},
 };
 
@@ -20,12 +26,16 @@ pub fn generate_type_markdown(
     typ: &LuaTypeDecl,
     output: &Path,
     mkdocs_index: &mut MkdocsIndex,
+    relations: &TypeRelations,
 ) -> Option<()> {
-    check_filter(db, typ)?;
+    if !is_doc_type(db, &typ.get_id()) {
+        return None;
+    }
     let mut context = tera::Context::new();
     let typ_name = typ.get_name();
     let mut doc = Doc::default();
     doc.name = typ_name.to_string();
+    collect_relations(db, typ, relations, &mut doc);
 
     if typ.is_class() {
         generate_class_type_markdown(db, tl, typ, &mut doc, &mut context, output, mkdocs_index);
@@ -37,19 +47,6 @@ pub fn generate_type_markdown(
     Some(())
 }
 
-fn check_filter(db: &DbIndex, typ: &LuaTypeDecl) -> Option<()> {
-    let location = typ.get_locations();
-    for loc in location {
-        let file_id = loc.file_id;
-        let module = db.get_module_index().get_module(file_id)?;
-        if module.workspace_id.is_main() {
-            return Some(());
-        }
-    }
-
-    None
-}
-
 fn generate_class_type_markdown(
     db: &DbIndex,
     tl: &Tera,
@@ -79,7 +76,7 @@ fn generate_class_type_markdown(
         doc.supers = Some(super_type_texts.join(", "));
     }
 
-    let member_owner = LuaMemberOwner::Type(typ_id);
+    let member_owner = LuaMemberOwner::Type(typ_id.clone());
     let members = db.get_member_index().get_sorted_members(&member_owner);
     let mut method_members: Vec<MemberDoc> = Vec::new();
     let mut field_members: Vec<MemberDoc> = Vec::new();
@@ -106,6 +103,7 @@ fn generate_class_type_markdown(
                 _ => continue,
             };
 
+            let member_links = render_type_links(db, &member_typ, "");
             let title_name = format!("{}.{}", typ_name, name);
             if member_typ.is_function() {
                 let func_name = format!("{}.{}", typ_name, name);
@@ -114,6 +112,7 @@ fn generate_class_type_markdown(
                     name: title_name,
                     display,
                     property: member_property,
+                    links: member_links,
                 });
             } else if member_typ.is_const() {
                 let const_type_display = render_const_type(db, &member_typ);
@@ -124,6 +123,7 @@ fn generate_class_type_markdown(
                         typ_name, name, const_type_display
                     ),
                     property: member_property,
+                    links: member_links,
                 });
             } else {
                 let typ_display = humanize_type(db, &member_typ, RenderLevel::Detailed);
@@ -131,6 +131,7 @@ fn generate_class_type_markdown(
                     name: title_name,
                     display: format!("```lua\n{}.{} : {}\n```\n", typ_name, name, typ_display),
                     property: member_property,
+                    links: member_links,
                 });
             }
         }
@@ -143,6 +144,8 @@ fn generate_class_type_markdown(
         doc.fields = Some(field_members);
     }
 
+    collect_inherited_members(db, &typ_id, doc);
+
     context.insert("doc", &doc);
     let render_text = match tl.render("lua_type_template.tl", &context) {
         Ok(text) => text,
@@ -217,6 +220,7 @@ fn generate_enum_type_markdown(
                 name: name.to_string(),
                 display: typ_display,
                 property: member_property,
+                links: None,
             });
         }
     }
@@ -272,6 +276,7 @@ fn generate_alias_type_markdown(
     doc.property = collect_property(db, type_property_id);
 
     if let Some(origin_typ) = typ.get_alias_origin(db, None) {
+        doc.links = render_type_links(db, &origin_typ, "");
         let origin_type_display = humanize_type(db, &origin_typ, RenderLevel::Detailed);
         let display = format!(
             "```lua\n(alias) {} = {}\n```\n",
@@ -307,3 +312,87 @@ fn generate_alias_type_markdown(
     }
     Some(())
 }
+
+fn collect_relations(db: &DbIndex, typ: &LuaTypeDecl, relations: &TypeRelations, doc: &mut Doc) {
+    let typ_id = typ.get_id();
+    let mut super_types = Vec::new();
+    typ_id.collect_super_types(db, &mut super_types);
+    let super_ids = super_types
+        .iter()
+        .filter_map(|super_type| match super_type {
+            LuaType::Ref(super_id) => Some(super_id.clone()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    doc.inheritance = render_type_id_links(db, &super_ids, "");
+    doc.subclasses = render_type_id_links(db, &get_subclasses(db, &typ_id), "");
+
+    let used_by = relations
+        .get_used_by(&typ_id)
+        .iter()
+        .map(|used_by| UsedByDoc {
+            name: used_by.name.clone(),
+            file: used_by.file.clone(),
+        })
+        .collect::<Vec<_>>();
+    if !used_by.is_empty() {
+        doc.used_by = Some(used_by);
+    }
+}
+
+fn collect_inherited_members(db: &DbIndex, typ_id: &LuaTypeDeclId, doc: &mut Doc) {
+    let mut super_types = Vec::new();
+    typ_id.collect_super_types(db, &mut super_types);
+
+    let self_owner = LuaMemberOwner::Type(typ_id.clone());
+    let mut seen: HashSet<LuaMemberKey> = db
+        .get_member_index()
+        .get_members(&self_owner)
+        .unwrap_or_default()
+        .iter()
+        .map(|member| member.get_key().clone())
+        .collect();
+
+    let mut inherited = Vec::new();
+    for super_type in super_types {
+        let LuaType::Ref(super_id) = super_type else {
+            continue;
+        };
+        let owner = LuaMemberOwner::Type(super_id.clone());
+        let Some(members) = db.get_member_index().get_sorted_members(&owner) else {
+            continue;
+        };
+
+        let mut member_names = Vec::new();
+        for member in members {
+            let member_key = member.get_key();
+            let LuaMemberKey::Name(name) = member_key else {
+                continue;
+            };
+            let member_property_id = LuaSemanticDeclId::Member(member.get_id());
+            if let Some(member_property) = db.get_property_index().get_property(&member_property_id)
+            {
+                if member_property.visibility.unwrap_or(VisibilityKind::Public)
+                    != VisibilityKind::Public
+                {
+                    continue;
+                }
+            }
+            // overridden members are documented by the subclass
+            if seen.insert(member_key.clone()) {
+                member_names.push(name.to_string());
+            }
+        }
+
+        if !member_names.is_empty() {
+            inherited.push(InheritedDoc {
+                from: render_type_link(&super_id, ""),
+                members: member_names,
+            });
+        }
+    }
+
+    if !inherited.is_empty() {
+        doc.inherited = Some(inherited);
+    }
+}
@@ -0,0 +1,265 @@
+use std::collections::{HashMap, HashSet};
+
+use emmylua_code_analysis::{DbIndex, LuaMemberKey, LuaMemberOwner, LuaType, LuaTypeDeclId};
+
+use crate::markdown_generator::escape_type_name;
+
+/// The functions using the documented types, computed once for all the type pages
+#[derive(Debug, Default)]
+pub struct TypeRelations {
+    used_by: HashMap<LuaTypeDeclId, Vec<UsedBy>>,
+}
+
+/// A function whose params or returns mention a type
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct UsedBy {
+    pub name: String,
+    /// The page of the function, relative to the types directory
+    pub file: String,
+}
+
+impl TypeRelations {
+    pub fn new(db: &DbIndex) -> Self {
+        let mut relations = TypeRelations::default();
+        let type_index = db.get_type_index();
+        for type_decl in type_index.get_all_types() {
+            let type_id = type_decl.get_id();
+            if !is_doc_type(db, &type_id) {
+                continue;
+            }
+
+            let page = format!("{}.md", escape_type_name(type_decl.get_full_name()));
+            relations.add_owner_usages(
+                db,
+                LuaMemberOwner::Type(type_id.clone()),
+                type_decl.get_name(),
+                &page,
+            );
+        }
+
+        for module in db.get_module_index().get_module_infos() {
+            if !module.workspace_id.is_main() {
+                continue;
+            }
+            let owner = match &module.export_type {
+                Some(LuaType::TableConst(table)) => LuaMemberOwner::Element(table.clone()),
+                Some(LuaType::Instance(instance)) => {
+                    LuaMemberOwner::Element(instance.get_range().clone())
+                }
+                _ => continue,
+            };
+            let page = format!(
+                "../modules/{}.md",
+                escape_type_name(&module.full_module_name)
+            );
+            relations.add_owner_usages(db, owner, &module.full_module_name, &page);
+        }
+
+        let decl_index = db.get_decl_index();
+        for decl_id in decl_index.get_global_decls() {
+            let Some(module) = db.get_module_index().get_module(decl_id.file_id) else {
+                continue;
+            };
+            if !module.workspace_id.is_main() {
+                continue;
+            }
+            let Some(decl) = decl_index.get_decl(&decl_id) else {
+                continue;
+            };
+            let Some(typ) = decl.get_type() else {
+                continue;
+            };
+            let page = format!("../globals/{}.md", escape_type_name(decl.get_name()));
+            relations.add_function_usages(db, typ, decl.get_name(), &page);
+        }
+
+        for used_by in relations.used_by.values_mut() {
+            used_by.sort();
+            used_by.dedup();
+        }
+        relations
+    }
+
+    pub fn get_used_by(&self, type_id: &LuaTypeDeclId) -> &[UsedBy] {
+        self.used_by
+            .get(type_id)
+            .map(|used_by| used_by.as_slice())
+            .unwrap_or_default()
+    }
+
+    fn add_owner_usages(
+        &mut self,
+        db: &DbIndex,
+        owner: LuaMemberOwner,
+        owner_name: &str,
+        page: &str,
+    ) {
+        let Some(members) = db.get_member_index().get_members(&owner) else {
+            return;
+        };
+        for member in members {
+            let LuaMemberKey::Name(name) = member.get_key() else {
+                continue;
+            };
+            let func_name = format!("{}.{}", owner_name, name);
+            self.add_function_usages(db, &member.get_decl_type(), &func_name, page);
+        }
+    }
+
+    fn add_function_usages(&mut self, db: &DbIndex, typ: &LuaType, func_name: &str, page: &str) {
+        let func = match typ {
+            LuaType::Signature(signature_id) => match db.get_signature_index().get(signature_id) {
+                Some(signature) => signature.to_doc_func_type(),
+                None => return,
+            },
+            LuaType::DocFunction(func) => func.clone(),
+            _ => return,
+        };
+
+        let mut type_ids = Vec::new();
+        for (_, param_type) in func.get_params() {
+            if let Some(param_type) = param_type {
+                collect_type_ids(db, param_type, &mut type_ids);
+            }
+        }
+        for ret in func.get_ret() {
+            collect_type_ids(db, ret, &mut type_ids);
+        }
+
+        for type_id in type_ids {
+            self.used_by.entry(type_id).or_default().push(UsedBy {
+                name: func_name.to_string(),
+                file: page.to_string(),
+            });
+        }
+    }
+}
+
+/// The direct subclasses which have a page, sorted by name
+pub fn get_subclasses(db: &DbIndex, type_id: &LuaTypeDeclId) -> Vec<LuaTypeDeclId> {
+    db.get_type_index()
+        .get_sub_types(type_id)
+        .into_iter()
+        .filter(|sub_type_id| is_doc_type(db, sub_type_id))
+        .collect()
+}
+
+/// Only the types of the main workspace have a page
+pub fn is_doc_type(db: &DbIndex, type_id: &LuaTypeDeclId) -> bool {
+    let Some(type_decl) = db.get_type_index().get_type_decl(type_id) else {
+        return false;
+    };
+    type_decl.get_locations().iter().any(|loc| {
+        db.get_module_index()
+            .get_module(loc.file_id)
+            .is_some_and(|module| module.workspace_id.is_main())
+    })
+}
+
+/// Collect the named types mentioned by a type, including inside unions, generic arguments and function types
+pub fn collect_type_ids(db: &DbIndex, typ: &LuaType, type_ids: &mut Vec<LuaTypeDeclId>) {
+    match typ {
+        LuaType::Ref(type_id) | LuaType::Def(type_id) if !type_ids.contains(type_id) => {
+            type_ids.push(type_id.clone());
+        }
+        LuaType::Array(inner) | LuaType::Variadic(inner) => collect_type_ids(db, inner, type_ids),
+        LuaType::Tuple(tuple) => {
+            for typ in tuple.get_types() {
+                collect_type_ids(db, typ, type_ids);
+            }
+        }
+        LuaType::Union(union) => {
+            for typ in union.get_types() {
+                collect_type_ids(db, typ, type_ids);
+            }
+        }
+        LuaType::Intersection(intersection) => {
+            for typ in intersection.get_types() {
+                collect_type_ids(db, typ, type_ids);
+            }
+        }
+        LuaType::MultiLineUnion(multi_union) => {
+            for (typ, _) in multi_union.get_unions() {
+                collect_type_ids(db, typ, type_ids);
+            }
+        }
+        LuaType::Generic(generic) => {
+            let base_id = generic.get_base_type_id();
+            if !type_ids.contains(&base_id) {
+                type_ids.push(base_id);
+            }
+            for typ in generic.get_params() {
+                collect_type_ids(db, typ, type_ids);
+            }
+        }
+        LuaType::TableGeneric(params) => {
+            for typ in params.iter() {
+                collect_type_ids(db, typ, type_ids);
+            }
+        }
+        LuaType::DocFunction(func) => {
+            for (_, param_type) in func.get_params() {
+                if let Some(param_type) = param_type {
+                    collect_type_ids(db, param_type, type_ids);
+                }
+            }
+            for ret in func.get_ret() {
+                collect_type_ids(db, ret, type_ids);
+            }
+        }
+        LuaType::Signature(signature_id) => {
+            if let Some(signature) = db.get_signature_index().get(signature_id) {
+                collect_type_ids(
+                    db,
+                    &LuaType::DocFunction(signature.to_doc_func_type()),
+                    type_ids,
+                );
+            }
+        }
+        LuaType::Object(object) => {
+            for typ in object.get_fields().values() {
+                collect_type_ids(db, typ, type_ids);
+            }
+            for (key, value) in object.get_index_access() {
+                collect_type_ids(db, key, type_ids);
+                collect_type_ids(db, value, type_ids);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Render markdown links to the pages of the documented types mentioned by a type,
+/// `prefix` is the path from the current page to the types directory
+pub fn render_type_links(db: &DbIndex, typ: &LuaType, prefix: &str) -> Option<String> {
+    let mut type_ids = Vec::new();
+    collect_type_ids(db, typ, &mut type_ids);
+    render_type_id_links(db, &type_ids, prefix)
+}
+
+pub fn render_type_id_links(
+    db: &DbIndex,
+    type_ids: &[LuaTypeDeclId],
+    prefix: &str,
+) -> Option<String> {
+    let mut seen = HashSet::new();
+    let links = type_ids
+        .iter()
+        .filter(|type_id| seen.insert((*type_id).clone()) && is_doc_type(db, type_id))
+        .map(|type_id| render_type_link(type_id, prefix))
+        .collect::<Vec<_>>();
+    if links.is_empty() {
+        None
+    } else {
+        Some(links.join(", "))
+    }
+}
+
+pub fn render_type_link(type_id: &LuaTypeDeclId, prefix: &str) -> String {
+    format!(
+        "[{}]({}{}.md)",
+        type_id.get_name(),
+        prefix,
+        escape_type_name(type_id.get_name())
+    )
+}
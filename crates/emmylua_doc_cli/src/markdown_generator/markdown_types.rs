@@ -9,6 +9,13 @@ pub struct Doc {
     pub fields: Option<Vec<MemberDoc>>,
     pub methods: Option<Vec<MemberDoc>>,
     pub property: Property,
+    /// Links to the super types, the nearest first
+    pub inheritance: Option<String>,
+    pub inherited: Option<Vec<InheritedDoc>>,
+    pub subclasses: Option<String>,
+    pub used_by: Option<Vec<UsedByDoc>>,
+    /// Links to the types referenced by an alias
+    pub links: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -16,6 +23,21 @@ pub struct MemberDoc {
     pub name: String,
     pub display: String,
     pub property: Property,
+    /// Links to the types referenced by the member
+    pub links: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct InheritedDoc {
+    /// Link to the super type
+    pub from: String,
+    pub members: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UsedByDoc {
+    pub name: String,
+    pub file: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, Default)]
@@ -1,14 +1,17 @@
 mod gen;
-mod init_tl;
+pub mod init_tl;
 mod markdown_types;
 mod mixin_copy;
 mod render;
+#[cfg(test)]
+mod test;
 
 use std::path::PathBuf;
 
 use emmylua_code_analysis::EmmyLuaAnalysis;
 use gen::{
     generate_global_markdown, generate_index, generate_module_markdown, generate_type_markdown,
+    TypeRelations,
 };
 use markdown_types::MkdocsIndex;
 
@@ -56,8 +59,16 @@ pub fn generate_markdown(
     let db = analysis.compilation.get_db();
     let type_index = db.get_type_index();
     let types = type_index.get_all_types();
+    let relations = TypeRelations::new(db);
     for type_decl in types {
-        generate_type_markdown(db, &tl, type_decl, &types_out, &mut mkdocs_index);
+        generate_type_markdown(
+            db,
+            &tl,
+            type_decl,
+            &types_out,
+            &mut mkdocs_index,
+            &relations,
+        );
     }
 
     let module_index = db.get_module_index();
@@ -81,7 +92,7 @@ pub fn generate_markdown(
     Some(())
 }
 
-fn escape_type_name(name: &str) -> String {
+pub fn escape_type_name(name: &str) -> String {
     name.chars()
         .map(|c| {
             // Windows Invalid Characters
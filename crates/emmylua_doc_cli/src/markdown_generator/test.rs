@@ -0,0 +1,79 @@
+use std::path::{Path, PathBuf};
+
+use emmylua_code_analysis::VirtualWorkspace;
+
+use super::generate_markdown;
+
+fn build(name: &str, content: &str) -> PathBuf {
+    let mut ws = VirtualWorkspace::new();
+    ws.def_file("animals.lua", content);
+    let input = ws.virtual_url_generator.base.clone();
+    let output = std::env::temp_dir().join(format!(
+        "emmylua_doc_markdown_{}_{}",
+        name,
+        std::process::id()
+    ));
+    generate_markdown(&mut ws.analysis, input, output.clone(), None, None).unwrap();
+    output
+}
+
+fn read_type_page(output: &Path, type_name: &str) -> String {
+    std::fs::read_to_string(output.join("docs/types").join(format!("{}.md", type_name))).unwrap()
+}
+
+const ANIMALS: &str = r#"
+    ---@class Base
+    ---@field id integer
+    local Base = {}
+
+    ---@class Animal: Base
+    ---@field name string
+    local Animal = {}
+
+    ---@class Dog: Animal
+    ---@field name string
+    ---@field friend Animal|Base
+    ---@field litter Box<Dog>
+    local Dog = {}
+
+    ---@class Cat: Animal
+    local Cat = {}
+
+    ---@class Box<T>
+    local Box = {}
+
+    ---@param animal Animal
+    ---@return Dog
+    function adopt(animal)
+    end
+"#;
+
+#[test]
+fn test_inheritance_and_subclasses() {
+    let output = build("inheritance", ANIMALS);
+    let dog = read_type_page(&output, "Dog");
+    assert!(dog.contains("- inheritance: [Animal](Animal.md), [Base](Base.md)"));
+    // `name` is overridden by `Dog`, only `id` is inherited
+    assert!(dog.contains("- from [Base](Base.md): `id`"));
+    assert!(!dog.contains("- from [Animal](Animal.md)"));
+
+    let animal = read_type_page(&output, "Animal");
+    assert!(animal.contains("- subclasses: [Cat](Cat.md), [Dog](Dog.md)"));
+    assert!(animal.contains("- from [Base](Base.md): `id`"));
+    let _ = std::fs::remove_dir_all(output);
+}
+
+#[test]
+fn test_used_by_and_type_links() {
+    let output = build("links", ANIMALS);
+    let animal = read_type_page(&output, "Animal");
+    assert!(animal.contains("## used by"));
+    assert!(animal.contains("- [adopt](../globals/adopt.md)"));
+    let dog = read_type_page(&output, "Dog");
+    assert!(dog.contains("- [adopt](../globals/adopt.md)"));
+
+    // unions and generic arguments link every documented type
+    assert!(dog.contains("types: [Animal](Animal.md), [Base](Base.md)"));
+    assert!(dog.contains("types: [Box](Box.md), [Dog](Dog.md)"));
+    let _ = std::fs::remove_dir_all(output);
+}
@@ -0,0 +1,251 @@
+mod render;
+mod stub_gen;
+#[cfg(test)]
+mod test;
+
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+};
+
+use emmylua_code_analysis::{
+    DbIndex, EmmyLuaAnalysis, FileId, LuaMemberOwner, LuaType, LuaTypeDeclId, ModuleInfo,
+};
+use render::{render_doc_type, render_version_cond};
+use rowan::TextSize;
+use stub_gen::{
+    generate_alias_stub, generate_class_stub, generate_enum_stub, generate_global_stub,
+    generate_table_members_stub, get_type_local_name,
+};
+
+/// Write a `---@meta` file for every file of the main workspace, at the same relative path
+pub fn generate_meta(
+    analysis: &mut EmmyLuaAnalysis,
+    input: PathBuf,
+    output: PathBuf,
+) -> Option<()> {
+    let db = analysis.compilation.get_db();
+    let meta_files = build_meta_files(db);
+    let file_paths = meta_files
+        .iter()
+        .filter_map(|(file_id, _)| Some((*file_id, db.get_vfs().get_file_path(file_id)?.clone())))
+        .collect::<Vec<_>>();
+    let meta_paths = get_meta_paths(&input, &file_paths);
+    for (file_id, text) in meta_files {
+        let outpath = output.join(meta_paths.get(&file_id)?);
+        if let Some(parent) = outpath.parent() {
+            std::fs::create_dir_all(parent).ok()?;
+        }
+        println!("output meta file: {}", outpath.display());
+        match std::fs::write(outpath, text) {
+            Ok(_) => {}
+            Err(e) => {
+                eprintln!("Failed to write file: {}", e);
+                return None;
+            }
+        }
+    }
+
+    Some(())
+}
+
+/// The output path of every file relative to the output directory. Files outside of `input`,
+/// eg: libraries, are written next to the top level files and numbered when their names collide
+fn get_meta_paths(input: &Path, file_paths: &[(FileId, PathBuf)]) -> HashMap<FileId, PathBuf> {
+    let mut meta_paths = HashMap::new();
+    let mut outside_files = Vec::new();
+    for (file_id, file_path) in file_paths {
+        match file_path.strip_prefix(input) {
+            Ok(relative_path) => {
+                meta_paths.insert(*file_id, relative_path.to_path_buf());
+            }
+            Err(_) => outside_files.push((*file_id, file_path)),
+        }
+    }
+
+    let mut used_paths: HashSet<PathBuf> = meta_paths.values().cloned().collect();
+    for (file_id, file_path) in outside_files {
+        let Some(file_name) = file_path.file_name() else {
+            continue;
+        };
+        let mut meta_path = PathBuf::from(file_name);
+        let mut count = 1;
+        while used_paths.contains(&meta_path) {
+            let stem = file_path.file_stem().unwrap_or_default().to_string_lossy();
+            meta_path = match file_path.extension() {
+                Some(extension) => PathBuf::from(format!(
+                    "{}_{}.{}",
+                    stem,
+                    count,
+                    extension.to_string_lossy()
+                )),
+                None => PathBuf::from(format!("{}_{}", stem, count)),
+            };
+            count += 1;
+        }
+        used_paths.insert(meta_path.clone());
+        meta_paths.insert(file_id, meta_path);
+    }
+
+    meta_paths
+}
+
+/// The text of the meta file of every file of the main workspace which has stubs
+fn build_meta_files(db: &DbIndex) -> Vec<(FileId, String)> {
+    let mut meta_files = Vec::new();
+    // the stubs of each file, sorted by their position in the source file
+    let mut file_stubs: HashMap<FileId, Vec<(TextSize, String)>> = HashMap::new();
+    let mut type_files: HashMap<LuaTypeDeclId, FileId> = HashMap::new();
+    let global_types = collect_global_types(db);
+
+    let mut types = db.get_type_index().get_all_types();
+    types.sort_by(|a, b| a.get_full_name().cmp(b.get_full_name()));
+    for type_decl in types {
+        let Some(location) = type_decl
+            .get_locations()
+            .iter()
+            .find(|loc| is_main_file(db, loc.file_id))
+        else {
+            continue;
+        };
+
+        let type_id = type_decl.get_id();
+        let is_global = global_types.get(&type_id) == Some(&location.file_id);
+        let mut stub = String::new();
+        if type_decl.is_class() {
+            generate_class_stub(db, type_decl, is_global, &mut stub);
+        } else if type_decl.is_enum() {
+            generate_enum_stub(db, type_decl, is_global, &mut stub);
+        } else {
+            generate_alias_stub(db, type_decl, &mut stub);
+        }
+        type_files.insert(type_id, location.file_id);
+        file_stubs
+            .entry(location.file_id)
+            .or_default()
+            .push((location.range.start(), stub));
+    }
+
+    for decl_id in db.get_decl_index().get_global_decls() {
+        if !is_main_file(db, decl_id.file_id) {
+            continue;
+        }
+        let Some(decl) = db.get_decl_index().get_decl(&decl_id) else {
+            continue;
+        };
+
+        // classes and enums stored in a global of the same name are declared by the stub of the type
+        if let Some(LuaType::Def(type_id) | LuaType::Ref(type_id)) = decl.get_type() {
+            if global_types.get(type_id) == Some(&decl_id.file_id) {
+                continue;
+            }
+        }
+        let mut stub = String::new();
+        generate_global_stub(db, decl, &mut stub);
+        file_stubs
+            .entry(decl_id.file_id)
+            .or_default()
+            .push((decl_id.position, stub));
+    }
+
+    let mut modules = db.get_module_index().get_module_infos();
+    modules.retain(|module| module.workspace_id.is_main());
+    for module in modules {
+        let mut stubs = file_stubs.remove(&module.file_id).unwrap_or_default();
+        let export_stub = generate_module_export(db, module, &type_files);
+        if stubs.is_empty() && export_stub.is_none() {
+            continue;
+        }
+        stubs.sort_by_key(|(position, _)| *position);
+
+        let mut text = get_meta_header(module, export_stub.is_some());
+        for (_, stub) in stubs {
+            text.push('\n');
+            text.push_str(&stub);
+        }
+        if let Some(export_stub) = export_stub {
+            text.push('\n');
+            text.push_str(&export_stub);
+        }
+        meta_files.push((module.file_id, text));
+    }
+
+    meta_files
+}
+
+/// The types stored in a global of the same name, with the file of the global
+fn collect_global_types(db: &DbIndex) -> HashMap<LuaTypeDeclId, FileId> {
+    let mut global_types = HashMap::new();
+    for decl_id in db.get_decl_index().get_global_decls() {
+        let Some(decl) = db.get_decl_index().get_decl(&decl_id) else {
+            continue;
+        };
+        if let Some(LuaType::Def(type_id) | LuaType::Ref(type_id)) = decl.get_type() {
+            if type_id.get_name() == decl.get_name() {
+                global_types.insert(type_id.clone(), decl_id.file_id);
+            }
+        }
+    }
+    global_types
+}
+
+fn is_main_file(db: &DbIndex, file_id: FileId) -> bool {
+    db.get_module_index()
+        .get_module(file_id)
+        .is_some_and(|module| module.workspace_id.is_main())
+}
+
+fn get_meta_header(module: &ModuleInfo, is_require: bool) -> String {
+    let mut header = match is_require && module.visible {
+        true => format!("---@meta {}\n", module.full_module_name),
+        false => "---@meta _\n".to_string(),
+    };
+    if let Some(version_conds) = &module.version_conds {
+        let conds = version_conds
+            .iter()
+            .map(render_version_cond)
+            .collect::<Vec<_>>();
+        header.push_str(&format!("---@version {}\n", conds.join(", ")));
+    }
+    header
+}
+
+fn generate_module_export(
+    db: &DbIndex,
+    module: &ModuleInfo,
+    type_files: &HashMap<LuaTypeDeclId, FileId>,
+) -> Option<String> {
+    let export_type = module.export_type.as_ref()?;
+    let mut stub = String::new();
+    match export_type {
+        LuaType::Def(type_id) | LuaType::Ref(type_id)
+            if type_files.get(type_id) == Some(&module.file_id) =>
+        {
+            let type_decl = db.get_type_index().get_type_decl(type_id)?;
+            stub.push_str(&format!("return {}\n", get_type_local_name(type_decl)));
+        }
+        LuaType::TableConst(table) => {
+            stub.push_str("local M = {}\n");
+            generate_table_members_stub(db, LuaMemberOwner::Element(table.clone()), "M", &mut stub);
+            stub.push_str("\nreturn M\n");
+        }
+        LuaType::Instance(instance) => {
+            stub.push_str("local M = {}\n");
+            generate_table_members_stub(
+                db,
+                LuaMemberOwner::Element(instance.get_range().clone()),
+                "M",
+                &mut stub,
+            );
+            stub.push_str("\nreturn M\n");
+        }
+        _ => {
+            stub.push_str(&format!(
+                "---@type {}\nlocal M\n\nreturn M\n",
+                render_doc_type(db, export_type)
+            ));
+        }
+    }
+
+    Some(stub)
+}
@@ -0,0 +1,194 @@
+use emmylua_code_analysis::{DbIndex, LuaFunctionType, LuaMemberKey, LuaType};
+use emmylua_parser::{LuaVersionCondition, LuaVersionNumber};
+
+/// Render a type with the annotation syntax, so it can be parsed back
+pub fn render_doc_type(db: &DbIndex, typ: &LuaType) -> String {
+    match typ {
+        LuaType::Any | LuaType::Unknown => "any".to_string(),
+        LuaType::Nil => "nil".to_string(),
+        LuaType::Table | LuaType::TableConst(_) | LuaType::Instance(_) => "table".to_string(),
+        LuaType::Userdata => "userdata".to_string(),
+        LuaType::Function => "function".to_string(),
+        LuaType::Thread => "thread".to_string(),
+        LuaType::Boolean | LuaType::BooleanConst(_) => "boolean".to_string(),
+        LuaType::String | LuaType::StringConst(_) => "string".to_string(),
+        LuaType::Integer | LuaType::IntegerConst(_) => "integer".to_string(),
+        LuaType::Number | LuaType::FloatConst(_) => "number".to_string(),
+        LuaType::Io => "io".to_string(),
+        LuaType::SelfInfer => "self".to_string(),
+        LuaType::Global => "global".to_string(),
+        LuaType::DocStringConst(s) => render_string(s),
+        LuaType::DocIntegerConst(i) => i.to_string(),
+        LuaType::DocBooleanConst(b) => b.to_string(),
+        LuaType::Ref(id) | LuaType::Def(id) => id.get_name().to_string(),
+        LuaType::Array(inner) => format!("{}[]", render_inner_type(db, inner)),
+        LuaType::Tuple(tuple) => format!("[{}]", render_type_list(db, tuple.get_types())),
+        LuaType::DocFunction(func) => render_doc_function_type(db, func),
+        LuaType::Signature(signature_id) => match db.get_signature_index().get(signature_id) {
+            Some(signature) => render_doc_function_type(db, &signature.to_doc_func_type()),
+            None => "function".to_string(),
+        },
+        LuaType::Object(object) => {
+            let mut fields = object
+                .get_fields()
+                .iter()
+                .map(|(key, typ)| {
+                    format!("{}: {}", render_field_key(key), render_doc_type(db, typ))
+                })
+                .collect::<Vec<_>>();
+            // the fields are stored in a hash map
+            fields.sort();
+            for (key, value) in object.get_index_access() {
+                fields.push(format!(
+                    "[{}]: {}",
+                    render_doc_type(db, key),
+                    render_doc_type(db, value)
+                ));
+            }
+            format!("{{ {} }}", fields.join(", "))
+        }
+        LuaType::Union(union) => union
+            .get_types()
+            .iter()
+            .map(|typ| render_inner_type(db, typ))
+            .collect::<Vec<_>>()
+            .join("|"),
+        LuaType::MultiLineUnion(multi_union) => multi_union
+            .get_unions()
+            .iter()
+            .map(|(typ, _)| render_inner_type(db, typ))
+            .collect::<Vec<_>>()
+            .join("|"),
+        LuaType::Intersection(intersection) => intersection
+            .get_types()
+            .iter()
+            .map(|typ| render_inner_type(db, typ))
+            .collect::<Vec<_>>()
+            .join(" & "),
+        LuaType::Generic(generic) => format!(
+            "{}<{}>",
+            generic.get_base_type_id().get_name(),
+            render_type_list(db, generic.get_params())
+        ),
+        LuaType::TableGeneric(params) => format!("table<{}>", render_type_list(db, params)),
+        LuaType::TplRef(tpl) => tpl.get_name().to_string(),
+        LuaType::StrTplRef(str_tpl) => format!(
+            "{}`{}`{}",
+            str_tpl.get_prefix(),
+            str_tpl.get_name(),
+            str_tpl.get_suffix()
+        ),
+        LuaType::Variadic(inner) => format!("{}...", render_inner_type(db, inner)),
+        _ => "any".to_string(),
+    }
+}
+
+pub fn render_doc_function_type(db: &DbIndex, func: &LuaFunctionType) -> String {
+    let params = func
+        .get_params()
+        .iter()
+        .map(|(name, typ)| match typ {
+            Some(typ) => format!("{}: {}", name, render_doc_type(db, typ)),
+            None => name.clone(),
+        })
+        .collect::<Vec<_>>();
+    let mut result = format!(
+        "{}fun({})",
+        if func.is_async() { "async " } else { "" },
+        params.join(", ")
+    );
+    let rets = func.get_ret();
+    if !rets.is_empty() {
+        result.push_str(": ");
+        result.push_str(&render_type_list(db, rets));
+    }
+    result
+}
+
+/// Render a literal value for the fields of an enum
+pub fn render_value(typ: &LuaType) -> String {
+    match typ {
+        LuaType::IntegerConst(i) | LuaType::DocIntegerConst(i) => i.to_string(),
+        LuaType::FloatConst(f) => f.to_string(),
+        LuaType::StringConst(s) | LuaType::DocStringConst(s) => render_string(s),
+        LuaType::BooleanConst(b) | LuaType::DocBooleanConst(b) => b.to_string(),
+        _ => "nil".to_string(),
+    }
+}
+
+/// Render a table key, names which are not identifiers are quoted
+pub fn render_field_key(key: &LuaMemberKey) -> String {
+    match key {
+        LuaMemberKey::Name(name) if is_identifier(name) => name.to_string(),
+        LuaMemberKey::Name(name) => format!("[{}]", render_string(name)),
+        LuaMemberKey::Integer(i) => format!("[{}]", i),
+        _ => "[any]".to_string(),
+    }
+}
+
+/// Render a string as a double quoted Lua string literal
+pub fn render_string(s: &str) -> String {
+    let mut result = String::with_capacity(s.len() + 2);
+    result.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            // a decimal escape is valid in every Lua version, unlike `\x` and `\u{}`
+            c if c.is_ascii_control() => result.push_str(&format!("\\{:03}", c as u32)),
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
+
+pub fn render_version_cond(cond: &LuaVersionCondition) -> String {
+    // `>` and `<` are inclusive in `---@version`
+    match cond {
+        LuaVersionCondition::Eq(v) => render_version_number(v),
+        LuaVersionCondition::Gte(v) => format!(">{}", render_version_number(v)),
+        LuaVersionCondition::Lte(v) => format!("<{}", render_version_number(v)),
+    }
+}
+
+fn render_version_number(version: &LuaVersionNumber) -> String {
+    if *version == LuaVersionNumber::LUA_JIT {
+        "JIT".to_string()
+    } else {
+        format!("{}.{}", version.major, version.minor)
+    }
+}
+
+pub fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(c) if c.is_alphabetic() || c == '_' => {}
+        _ => return false,
+    }
+    chars.all(|c| c.is_alphanumeric() || c == '_')
+}
+
+fn render_type_list(db: &DbIndex, types: &[LuaType]) -> String {
+    types
+        .iter()
+        .map(|typ| render_doc_type(db, typ))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+// functions and unions need parentheses inside arrays and unions
+fn render_inner_type(db: &DbIndex, typ: &LuaType) -> String {
+    let text = render_doc_type(db, typ);
+    match typ {
+        LuaType::DocFunction(_)
+        | LuaType::Signature(_)
+        | LuaType::Union(_)
+        | LuaType::MultiLineUnion(_)
+        | LuaType::Intersection(_) => format!("({})", text),
+        _ => text,
+    }
+}
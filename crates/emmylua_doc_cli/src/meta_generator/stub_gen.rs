@@ -0,0 +1,443 @@
+use emmylua_code_analysis::{
+    DbIndex, LuaDecl, LuaDeclProperty, LuaMember, LuaMemberKey, LuaMemberOwner, LuaSemanticDeclId,
+    LuaSignature, LuaSignatureId, LuaType, LuaTypeDecl,
+};
+use emmylua_parser::VisibilityKind;
+
+use super::render::{
+    is_identifier, render_doc_function_type, render_doc_type, render_field_key, render_value,
+    render_version_cond,
+};
+
+/// The local variable holding a type in the meta file
+pub fn get_type_local_name(type_decl: &LuaTypeDecl) -> String {
+    let name: String = type_decl
+        .get_name()
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    match is_identifier(&name) {
+        true => name,
+        false => format!("_{}", name),
+    }
+}
+
+/// `is_global` when the source stores the type in a global of the same name
+pub fn generate_class_stub(
+    db: &DbIndex,
+    type_decl: &LuaTypeDecl,
+    is_global: bool,
+    out: &mut String,
+) {
+    let type_id = type_decl.get_id();
+    let type_property = get_property(db, LuaSemanticDeclId::TypeDecl(type_id.clone()));
+    push_property(type_property, out);
+
+    let mut attributes = Vec::new();
+    if type_decl.is_partial() {
+        attributes.push("partial");
+    }
+    if type_decl.is_exact() {
+        attributes.push("exact");
+    }
+    out.push_str("---@class ");
+    if !attributes.is_empty() {
+        out.push_str(&format!("({}) ", attributes.join(",")));
+    }
+    out.push_str(type_decl.get_full_name());
+    push_generic_list(db, db.get_type_index().get_generic_params(&type_id), out);
+    if let Some(supers) = db.get_type_index().get_super_types(&type_id) {
+        let supers = supers
+            .iter()
+            .map(|super_type| render_doc_type(db, super_type))
+            .collect::<Vec<_>>();
+        if !supers.is_empty() {
+            out.push_str(&format!(": {}", supers.join(", ")));
+        }
+    }
+    out.push('\n');
+
+    let members = db
+        .get_member_index()
+        .get_sorted_members(&LuaMemberOwner::Type(type_id))
+        .unwrap_or_default();
+    let mut methods = Vec::new();
+    for member in members {
+        let typ = member.get_decl_type();
+        if let LuaType::Signature(signature_id) = &typ {
+            methods.push((member, *signature_id));
+            continue;
+        }
+        push_field_tag(db, member, &typ, out);
+    }
+
+    let local_name = get_type_local_name(type_decl);
+    out.push_str(&format!(
+        "{}{} = {{}}\n",
+        if is_global { "" } else { "local " },
+        local_name
+    ));
+    for (member, signature_id) in methods {
+        out.push('\n');
+        let property = get_property(db, LuaSemanticDeclId::Member(member.get_id()))
+            .or_else(|| get_property(db, LuaSemanticDeclId::Signature(signature_id)));
+        generate_function_stub(
+            db,
+            &signature_id,
+            property,
+            &local_name,
+            member.get_key(),
+            out,
+        );
+    }
+}
+
+pub fn generate_enum_stub(
+    db: &DbIndex,
+    type_decl: &LuaTypeDecl,
+    is_global: bool,
+    out: &mut String,
+) {
+    let type_id = type_decl.get_id();
+    push_property(
+        get_property(db, LuaSemanticDeclId::TypeDecl(type_id.clone())),
+        out,
+    );
+    out.push_str("---@enum ");
+    if type_decl.is_enum_key() {
+        out.push_str("(key) ");
+    }
+    out.push_str(type_decl.get_full_name());
+    out.push('\n');
+
+    out.push_str(&format!(
+        "{}{} = {{\n",
+        if is_global { "" } else { "local " },
+        get_type_local_name(type_decl)
+    ));
+    let members = db
+        .get_member_index()
+        .get_sorted_members(&LuaMemberOwner::Type(type_id))
+        .unwrap_or_default();
+    for member in members {
+        if let Some(property) = get_property(db, LuaSemanticDeclId::Member(member.get_id())) {
+            if let Some(description) = &property.description {
+                for line in description.lines() {
+                    out.push_str(&format!("    ---{}\n", line));
+                }
+            }
+        }
+        out.push_str(&format!(
+            "    {} = {},\n",
+            render_field_key(member.get_key()),
+            render_value(&member.get_decl_type())
+        ));
+    }
+    out.push_str("}\n");
+}
+
+pub fn generate_alias_stub(db: &DbIndex, type_decl: &LuaTypeDecl, out: &mut String) {
+    let type_id = type_decl.get_id();
+    push_property(
+        get_property(db, LuaSemanticDeclId::TypeDecl(type_id.clone())),
+        out,
+    );
+    out.push_str("---@alias ");
+    out.push_str(type_decl.get_full_name());
+    push_generic_list(db, db.get_type_index().get_generic_params(&type_id), out);
+
+    match type_decl.get_alias_origin(db, None) {
+        Some(LuaType::MultiLineUnion(multi_union)) => {
+            out.push('\n');
+            for (typ, description) in multi_union.get_unions() {
+                out.push_str(&format!("---| {}", render_doc_type(db, typ)));
+                if let Some(description) = description {
+                    out.push_str(&format!(" # {}", description));
+                }
+                out.push('\n');
+            }
+        }
+        Some(origin) => out.push_str(&format!(" {}\n", render_doc_type(db, &origin))),
+        None => out.push_str(" any\n"),
+    }
+}
+
+/// A global function, variable or table
+pub fn generate_global_stub(db: &DbIndex, decl: &LuaDecl, out: &mut String) {
+    let name = decl.get_name();
+    let typ = decl.get_type().cloned().unwrap_or(LuaType::Unknown);
+    let property = get_property(db, LuaSemanticDeclId::LuaDecl(decl.get_id()));
+    match &typ {
+        LuaType::Signature(signature_id) => {
+            let property =
+                property.or_else(|| get_property(db, LuaSemanticDeclId::Signature(*signature_id)));
+            push_property(property, out);
+            push_signature_tags(db, signature_id, out);
+            out.push_str(&format!(
+                "function {}({}) end\n",
+                name,
+                get_param_names(db, signature_id).join(", ")
+            ));
+        }
+        LuaType::TableConst(table) => {
+            push_property(property, out);
+            out.push_str(&format!("{} = {{}}\n", name));
+            generate_table_members_stub(db, LuaMemberOwner::Element(table.clone()), name, out);
+        }
+        _ => {
+            push_property(property, out);
+            out.push_str(&format!(
+                "---@type {}\n{} = nil\n",
+                render_doc_type(db, &typ),
+                name
+            ));
+        }
+    }
+}
+
+/// The members of a table value which is not a class, eg: the table returned by a module
+pub fn generate_table_members_stub(
+    db: &DbIndex,
+    owner: LuaMemberOwner,
+    owner_name: &str,
+    out: &mut String,
+) {
+    let members = db
+        .get_member_index()
+        .get_sorted_members(&owner)
+        .unwrap_or_default();
+    for member in members {
+        out.push('\n');
+        let typ = member.get_decl_type();
+        let property = get_property(db, LuaSemanticDeclId::Member(member.get_id()));
+        if let LuaType::Signature(signature_id) = &typ {
+            let property =
+                property.or_else(|| get_property(db, LuaSemanticDeclId::Signature(*signature_id)));
+            generate_function_stub(
+                db,
+                signature_id,
+                property,
+                owner_name,
+                member.get_key(),
+                out,
+            );
+            continue;
+        }
+
+        push_property(property, out);
+        out.push_str(&format!("---@type {}\n", render_doc_type(db, &typ)));
+        let key = render_field_key(member.get_key());
+        match key.starts_with('[') {
+            true => out.push_str(&format!("{}{} = nil\n", owner_name, key)),
+            false => out.push_str(&format!("{}.{} = nil\n", owner_name, key)),
+        }
+    }
+}
+
+fn generate_function_stub(
+    db: &DbIndex,
+    signature_id: &LuaSignatureId,
+    property: Option<&LuaDeclProperty>,
+    owner_name: &str,
+    key: &LuaMemberKey,
+    out: &mut String,
+) {
+    push_property(property, out);
+    if let Some(visibility) = property.and_then(get_visibility_text) {
+        out.push_str(&format!("---@{}\n", visibility));
+    }
+    push_signature_tags(db, signature_id, out);
+
+    let is_colon_define = db
+        .get_signature_index()
+        .get(signature_id)
+        .is_some_and(|signature| signature.is_colon_define);
+    let params = get_param_names(db, signature_id).join(", ");
+    match key {
+        LuaMemberKey::Name(name) if is_identifier(name) => {
+            let op = if is_colon_define { ":" } else { "." };
+            out.push_str(&format!(
+                "function {}{}{}({}) end\n",
+                owner_name, op, name, params
+            ));
+        }
+        _ => {
+            out.push_str(&format!(
+                "{}{} = function({}) end\n",
+                owner_name,
+                render_field_key(key),
+                params
+            ));
+        }
+    }
+}
+
+fn push_signature_tags(db: &DbIndex, signature_id: &LuaSignatureId, out: &mut String) {
+    let Some(signature) = db.get_signature_index().get(signature_id) else {
+        return;
+    };
+
+    for (name, constraint) in &signature.generic_params {
+        match constraint {
+            Some(constraint) => out.push_str(&format!(
+                "---@generic {}: {}\n",
+                name,
+                render_doc_type(db, constraint)
+            )),
+            None => out.push_str(&format!("---@generic {}\n", name)),
+        }
+    }
+    if signature.is_async {
+        out.push_str("---@async\n");
+    }
+    if signature.is_nodiscard {
+        out.push_str("---@nodiscard\n");
+    }
+
+    // every param of the signature is declared, the documentation only adds to it
+    for (idx, (name, typ)) in signature.get_type_params().iter().enumerate() {
+        let param_info = signature.get_param_info_by_id(idx);
+        let nullable = param_info.is_some_and(|param_info| param_info.nullable);
+        out.push_str(&format!(
+            "---@param {}{} {}",
+            name,
+            if nullable { "?" } else { "" },
+            render_doc_type(db, typ.as_ref().unwrap_or(&LuaType::Any))
+        ));
+        push_inline_description(
+            param_info.and_then(|param_info| param_info.description.as_deref()),
+            out,
+        );
+    }
+
+    push_return_tags(db, signature, out);
+    for overload in &signature.overloads {
+        out.push_str(&format!(
+            "---@overload {}\n",
+            render_doc_function_type(db, overload)
+        ));
+    }
+}
+
+// the returns are documented or inferred from the body, names and descriptions come from the docs
+fn push_return_tags(db: &DbIndex, signature: &LuaSignature, out: &mut String) {
+    if !signature.is_resolve_return() {
+        return;
+    }
+
+    for (idx, typ) in signature.get_return_types().iter().enumerate() {
+        out.push_str(&format!("---@return {}", render_doc_type(db, typ)));
+        let ret = signature.return_docs.get(idx);
+        if let Some(name) = ret.and_then(|ret| ret.name.as_ref()) {
+            out.push_str(&format!(" {}", name));
+        }
+        push_inline_description(ret.and_then(|ret| ret.description.as_deref()), out);
+    }
+}
+
+fn get_param_names(db: &DbIndex, signature_id: &LuaSignatureId) -> Vec<String> {
+    db.get_signature_index()
+        .get(signature_id)
+        .map(|signature| signature.params.clone())
+        .unwrap_or_default()
+}
+
+fn push_field_tag(db: &DbIndex, member: &LuaMember, typ: &LuaType, out: &mut String) {
+    let property = get_property(db, LuaSemanticDeclId::Member(member.get_id()));
+    out.push_str("---@field ");
+    if let Some(visibility) = property.and_then(get_visibility_text) {
+        out.push_str(&format!("{} ", visibility));
+    }
+    out.push_str(&format!(
+        "{} {}",
+        render_field_key(member.get_key()),
+        render_doc_type(db, typ)
+    ));
+    // `---@field` has no place for `@deprecated`, keep it in the description
+    let description = property.and_then(|property| {
+        let description = property.description.as_deref().map(|s| s.as_str());
+        match (description, property.is_deprecated) {
+            (Some(description), true) => Some(format!("Deprecated. {}", description)),
+            (None, true) => Some("Deprecated".to_string()),
+            (description, false) => description.map(|s| s.to_string()),
+        }
+    });
+    push_inline_description(description.as_deref(), out);
+}
+
+fn push_generic_list(
+    db: &DbIndex,
+    params: Option<&Vec<(String, Option<LuaType>)>>,
+    out: &mut String,
+) {
+    let Some(params) = params else {
+        return;
+    };
+    if params.is_empty() {
+        return;
+    }
+
+    let params = params
+        .iter()
+        .map(|(name, constraint)| match constraint {
+            Some(constraint) => format!("{}: {}", name, render_doc_type(db, constraint)),
+            None => name.clone(),
+        })
+        .collect::<Vec<_>>();
+    out.push_str(&format!("<{}>", params.join(", ")));
+}
+
+fn push_property(property: Option<&LuaDeclProperty>, out: &mut String) {
+    let Some(property) = property else {
+        return;
+    };
+
+    if let Some(description) = &property.description {
+        for line in description.lines() {
+            out.push_str(&format!("---{}\n", line));
+        }
+    }
+    if property.is_deprecated {
+        match &property.deprecated_message {
+            Some(message) => out.push_str(&format!("---@deprecated {}\n", message)),
+            None => out.push_str("---@deprecated\n"),
+        }
+    }
+    if let Some(version_conds) = &property.version_conds {
+        let conds = version_conds
+            .iter()
+            .map(render_version_cond)
+            .collect::<Vec<_>>();
+        out.push_str(&format!("---@version {}\n", conds.join(", ")));
+    }
+    if let Some(see) = &property.see_content {
+        out.push_str(&format!("---@see {}\n", see));
+    }
+}
+
+// only the first line fits after a tag
+fn push_inline_description(description: Option<&str>, out: &mut String) {
+    if let Some(line) = description.and_then(|description| description.lines().next()) {
+        out.push_str(&format!(" {}", line));
+    }
+    out.push('\n');
+}
+
+fn get_visibility_text(property: &LuaDeclProperty) -> Option<&'static str> {
+    match property.visibility? {
+        VisibilityKind::Protected => Some("protected"),
+        VisibilityKind::Private => Some("private"),
+        VisibilityKind::Package => Some("package"),
+        VisibilityKind::Public | VisibilityKind::Internal | VisibilityKind::None => None,
+    }
+}
+
+fn get_property(db: &DbIndex, semantic_decl: LuaSemanticDeclId) -> Option<&LuaDeclProperty> {
+    db.get_property_index().get_property(&semantic_decl)
+}
@@ -0,0 +1,150 @@
+use std::path::PathBuf;
+
+use emmylua_code_analysis::{FileId, VirtualWorkspace};
+
+use super::{build_meta_files, get_meta_paths};
+use crate::init::load_workspace;
+
+fn build_meta(file_name: &str, content: &str) -> String {
+    let mut ws = VirtualWorkspace::new();
+    let file_id = ws.def_file(file_name, content);
+    build_meta_files(ws.analysis.compilation.get_db())
+        .into_iter()
+        .find(|(id, _)| *id == file_id)
+        .map(|(_, text)| text)
+        .unwrap_or_default()
+}
+
+#[test]
+fn test_class_stub() {
+    let text = build_meta(
+        "point.lua",
+        r#"
+        ---@class Point
+        ---@field x number
+        local Point = {}
+
+        ---Move the point
+        ---@param dx number the distance
+        function Point:move(dx, dy)
+            return self
+        end
+
+        return Point
+        "#,
+    );
+    assert_eq!(
+        text,
+        r#"---@meta point
+
+---@class Point
+---@field x number
+local Point = {}
+
+---Move the point
+---@param dx number the distance
+---@param dy any
+---@return Point
+function Point:move(dx, dy) end
+
+return Point
+"#
+    );
+}
+
+#[test]
+fn test_enum_stub() {
+    let text = build_meta(
+        "sep.lua",
+        r#"
+        ---@enum Sep
+        Sep = {
+            Line = "\n",
+            Quote = "a\"b",
+            Accent = "é",
+            Bell = "\a",
+        }
+        "#,
+    );
+    assert_eq!(
+        text,
+        r#"---@meta _
+
+---@enum Sep
+Sep = {
+    Line = "\n",
+    Quote = "a\"b",
+    Accent = "é",
+    Bell = "\007",
+}
+"#
+    );
+}
+
+#[test]
+fn test_global_function_stub() {
+    let text = build_meta(
+        "util.lua",
+        r#"
+        function add(a, b)
+            return 1
+        end
+
+        ---@param name string
+        ---@return string greeting the text
+        function greet(name, punctuation)
+            return "hello " .. name
+        end
+        "#,
+    );
+    assert_eq!(
+        text,
+        r#"---@meta _
+
+---@param a any
+---@param b any
+---@return integer
+function add(a, b) end
+
+---@param name string
+---@param punctuation any
+---@return string greeting the text
+function greet(name, punctuation) end
+"#
+    );
+}
+
+#[test]
+fn test_meta_paths() {
+    let input = PathBuf::from("/project");
+    let file_paths = vec![
+        (FileId { id: 1 }, PathBuf::from("/lib_a/util.lua")),
+        (FileId { id: 2 }, PathBuf::from("/project/util.lua")),
+        (FileId { id: 3 }, PathBuf::from("/lib_b/util.lua")),
+        (FileId { id: 4 }, PathBuf::from("/project/sub/util.lua")),
+    ];
+    let meta_paths = get_meta_paths(&input, &file_paths);
+    let get_path = |id| meta_paths.get(&FileId { id }).unwrap().clone();
+    assert_eq!(get_path(2), PathBuf::from("util.lua"));
+    assert_eq!(get_path(4), PathBuf::from("sub/util.lua"));
+    assert_eq!(get_path(1), PathBuf::from("util_1.lua"));
+    assert_eq!(get_path(3), PathBuf::from("util_2.lua"));
+}
+
+#[test]
+fn test_output_not_indexed() {
+    let input = std::env::temp_dir().join(format!("emmylua_doc_meta_{}", std::process::id()));
+    let output = input.join("meta");
+    std::fs::create_dir_all(&output).unwrap();
+    std::fs::write(input.join("point.lua"), "local Point = {}\nreturn Point\n").unwrap();
+    std::fs::write(output.join("point.lua"), "---@meta point\n").unwrap();
+
+    let analysis = load_workspace(vec![input.to_str().unwrap()], vec![output.clone()]).unwrap();
+    let file_ids = analysis
+        .compilation
+        .get_db()
+        .get_module_index()
+        .get_main_workspace_file_ids();
+    std::fs::remove_dir_all(&input).ok();
+    assert_eq!(file_ids.len(), 1);
+}
@@ -1,7 +1,10 @@
-use std::str::FromStr;
+use std::{collections::HashMap, str::FromStr};
 
-use emmylua_code_analysis::{DiagnosticCode, FileId, SemanticModel};
-use lsp_types::{CodeActionOrCommand, CodeActionResponse, Diagnostic, NumberOrString, Range};
+use emmylua_code_analysis::{get_diagnostic_fix, DiagnosticCode, FileId, SemanticModel};
+use lsp_types::{
+    CodeAction, CodeActionKind, CodeActionOrCommand, CodeActionResponse, Diagnostic,
+    NumberOrString, Range, WorkspaceEdit,
+};
 
 use crate::handlers::command::{make_disable_code_command, DisableAction};
 
@@ -16,15 +19,15 @@ pub fn build_actions(
             continue;
         }
 
-        let source = diagnostic.source.unwrap();
+        let source = diagnostic.source.as_ref().unwrap();
         if source != "EmmyLua" {
             continue;
         }
 
-        if let Some(code) = diagnostic.code {
+        if let Some(code) = diagnostic.code.clone() {
             if let NumberOrString::String(action_string) = code {
                 if let Some(diagnostic_code) = DiagnosticCode::from_str(&action_string).ok() {
-                    add_fix_code_action(&mut actions, diagnostic_code, file_id, diagnostic.range);
+                    add_fix_code_action(&mut actions, semantic_model, &diagnostic);
                     add_disable_code_action(
                         &mut actions,
                         diagnostic_code,
@@ -39,13 +42,25 @@ pub fn build_actions(
     Some(actions)
 }
 
-#[allow(unused_variables)]
 fn add_fix_code_action(
     actions: &mut Vec<CodeActionOrCommand>,
-    diagnostic_code: DiagnosticCode,
-    file_id: FileId,
-    range: Range,
+    semantic_model: &SemanticModel,
+    diagnostic: &Diagnostic,
 ) -> Option<()> {
+    let fix = get_diagnostic_fix(semantic_model, diagnostic)?;
+    let uri = semantic_model.get_document().get_uri();
+    actions.push(CodeActionOrCommand::CodeAction(CodeAction {
+        title: fix.title,
+        kind: Some(CodeActionKind::QUICKFIX),
+        diagnostics: Some(vec![diagnostic.clone()]),
+        edit: Some(WorkspaceEdit {
+            changes: Some(HashMap::from([(uri, fix.edits)])),
+            ..Default::default()
+        }),
+        is_preferred: Some(true),
+        ..Default::default()
+    }));
+
     Some(())
 }
 
@@ -1,20 +1,54 @@
-use emmylua_code_analysis::{LuaDeclId, LuaMemberId, SemanticModel};
-use emmylua_parser::{LuaAst, LuaAstNode, LuaAstToken, LuaFuncStat, LuaLocalFuncStat, LuaVarExpr};
-use lsp_types::CodeLens;
+use std::collections::HashSet;
 
-use super::CodeLensData;
+use emmylua_code_analysis::{
+    LuaDeclId, LuaMember, LuaMemberId, LuaMemberKey, LuaMemberOwner, LuaType, LuaTypeDeclId,
+    SemanticModel,
+};
+use emmylua_parser::{
+    LuaAst, LuaAstNode, LuaAstToken, LuaCallExpr, LuaCallExprStat, LuaDocFieldKey, LuaDocTagClass,
+    LuaDocTagField, LuaExpr, LuaFuncStat, LuaLiteralToken, LuaLocalFuncStat, LuaVarExpr,
+};
+use lsp_types::{CodeLens, Command, Range};
 
-pub fn build_code_lens(semantic_model: &SemanticModel) -> Option<Vec<CodeLens>> {
+use crate::context::ClientId;
+
+use super::{
+    resolve_code_lens::{get_member_location, make_references_command},
+    CodeLensData,
+};
+
+// the client runs the test, the server only provides the test path
+const RUN_TEST_COMMAND_NAME: &str = "emmy.runTest";
+
+pub fn build_code_lens(
+    semantic_model: &SemanticModel,
+    client_id: ClientId,
+) -> Option<Vec<CodeLens>> {
     let mut result = Vec::new();
     let root = semantic_model.get_root().clone();
     for node in root.descendants::<LuaAst>() {
         match node {
             LuaAst::LuaFuncStat(func_stat) => {
+                add_func_stat_relation_code_lens(
+                    semantic_model,
+                    &mut result,
+                    func_stat.clone(),
+                    client_id,
+                );
                 add_func_stat_code_lens(semantic_model, &mut result, func_stat)?;
             }
             LuaAst::LuaLocalFuncStat(local_func_stat) => {
                 add_local_func_stat_code_lens(semantic_model, &mut result, local_func_stat)?;
             }
+            LuaAst::LuaDocTagField(field) => {
+                add_doc_field_code_lens(semantic_model, &mut result, field);
+            }
+            LuaAst::LuaDocTagClass(class) => {
+                add_class_code_lens(semantic_model, &mut result, class);
+            }
+            LuaAst::LuaCallExprStat(call_expr_stat) => {
+                add_run_test_code_lens(semantic_model, &mut result, call_expr_stat);
+            }
             _ => {}
         }
     }
@@ -77,3 +111,241 @@ fn add_local_func_stat_code_lens(
     });
     Some(())
 }
+
+// "N implementations" and "overrides Base:method" on methods of a class
+fn add_func_stat_relation_code_lens(
+    semantic_model: &SemanticModel,
+    result: &mut Vec<CodeLens>,
+    func_stat: LuaFuncStat,
+    client_id: ClientId,
+) -> Option<()> {
+    let code_lens_config = &semantic_model.get_emmyrc().code_lens;
+    let LuaVarExpr::IndexExpr(index_expr) = func_stat.get_func_name()? else {
+        return None;
+    };
+    let member_id = LuaMemberId::new(index_expr.get_syntax_id(), semantic_model.get_file_id());
+    let member = semantic_model
+        .get_db()
+        .get_member_index()
+        .get_member(&member_id)?;
+    let owner_id = member.get_owner().get_type_id()?.clone();
+    let key = member.get_key().clone();
+    let document = semantic_model.get_document();
+    let range = document.to_lsp_range(index_expr.get_index_name_token()?.text_range())?;
+
+    if code_lens_config.implementation {
+        add_implementation_code_lens(semantic_model, result, member_id, &owner_id, range);
+    }
+
+    if code_lens_config.overrides {
+        if let Some((super_id, super_member)) =
+            find_overridden_member(semantic_model, &owner_id, &key)
+        {
+            let separator = if index_expr.get_index_token()?.is_colon() {
+                ":"
+            } else {
+                "."
+            };
+            let title = format!(
+                "overrides {}{}{}",
+                super_id.get_simple_name(),
+                separator,
+                key.to_path()
+            );
+            let location = get_member_location(semantic_model, super_member)?;
+            result.push(CodeLens {
+                range,
+                command: Some(make_references_command(
+                    title,
+                    document.get_uri(),
+                    range,
+                    client_id,
+                    vec![location],
+                )),
+                data: None,
+            });
+        }
+    }
+
+    Some(())
+}
+
+// `---@field` of an interface or a base class
+fn add_doc_field_code_lens(
+    semantic_model: &SemanticModel,
+    result: &mut Vec<CodeLens>,
+    field: LuaDocTagField,
+) -> Option<()> {
+    if !semantic_model.get_emmyrc().code_lens.implementation {
+        return None;
+    }
+
+    let LuaDocFieldKey::Name(name_token) = field.get_field_key()? else {
+        return None;
+    };
+    let member_id = LuaMemberId::new(field.get_syntax_id(), semantic_model.get_file_id());
+    let member = semantic_model
+        .get_db()
+        .get_member_index()
+        .get_member(&member_id)?;
+    let owner_id = member.get_owner().get_type_id()?.clone();
+    let range = semantic_model
+        .get_document()
+        .to_lsp_range(name_token.get_range())?;
+    add_implementation_code_lens(semantic_model, result, member_id, &owner_id, range)
+}
+
+// the implementations are searched when the lens is resolved
+fn add_implementation_code_lens(
+    semantic_model: &SemanticModel,
+    result: &mut Vec<CodeLens>,
+    member_id: LuaMemberId,
+    owner_id: &LuaTypeDeclId,
+    range: Range,
+) -> Option<()> {
+    if !has_sub_types(semantic_model, owner_id) {
+        return None;
+    }
+
+    let data = CodeLensData::Implementation(member_id);
+    result.push(CodeLens {
+        range,
+        command: None,
+        data: Some(serde_json::to_value(data).unwrap()),
+    });
+
+    Some(())
+}
+
+fn add_class_code_lens(
+    semantic_model: &SemanticModel,
+    result: &mut Vec<CodeLens>,
+    class: LuaDocTagClass,
+) -> Option<()> {
+    if !semantic_model.get_emmyrc().code_lens.subclass {
+        return None;
+    }
+
+    let name_token = class.get_name_token()?;
+    let file_id = semantic_model.get_file_id();
+    let type_decl = semantic_model
+        .get_db()
+        .get_type_index()
+        .find_type_decl(file_id, name_token.get_name_text())?;
+    let type_decl_id = type_decl.get_id();
+    if !has_sub_types(semantic_model, &type_decl_id) {
+        return None;
+    }
+
+    let range = semantic_model
+        .get_document()
+        .to_lsp_range(name_token.get_range())?;
+    let data = CodeLensData::Subclass(file_id, type_decl_id);
+    result.push(CodeLens {
+        range,
+        command: None,
+        data: Some(serde_json::to_value(data).unwrap()),
+    });
+
+    Some(())
+}
+
+// busted style `describe("name", function() it("name", function() end) end)`
+fn add_run_test_code_lens(
+    semantic_model: &SemanticModel,
+    result: &mut Vec<CodeLens>,
+    call_expr_stat: LuaCallExprStat,
+) -> Option<()> {
+    let code_lens_config = &semantic_model.get_emmyrc().code_lens;
+    if !code_lens_config.run_test {
+        return None;
+    }
+
+    let call_expr = call_expr_stat.get_call_expr()?;
+    let test_functions = &code_lens_config.test_functions;
+    get_test_name(&call_expr, test_functions)?;
+    let mut test_path = call_expr
+        .syntax()
+        .ancestors()
+        .filter_map(LuaCallExpr::cast)
+        .filter_map(|call_expr| get_test_name(&call_expr, test_functions))
+        .collect::<Vec<_>>();
+    test_path.reverse();
+
+    let document = semantic_model.get_document();
+    let range = document.to_lsp_range(call_expr.get_prefix_expr()?.get_range())?;
+    result.push(CodeLens {
+        range,
+        command: Some(Command {
+            title: "Run test".to_string(),
+            command: RUN_TEST_COMMAND_NAME.to_string(),
+            arguments: Some(vec![
+                serde_json::to_value(document.get_uri()).unwrap(),
+                serde_json::to_value(test_path.join(" ")).unwrap(),
+            ]),
+        }),
+        data: None,
+    });
+
+    Some(())
+}
+
+fn get_test_name(call_expr: &LuaCallExpr, test_functions: &[String]) -> Option<String> {
+    let LuaExpr::NameExpr(name_expr) = call_expr.get_prefix_expr()? else {
+        return None;
+    };
+    let name = name_expr.get_name_text()?;
+    if !test_functions.contains(&name) {
+        return None;
+    }
+
+    let LuaExpr::LiteralExpr(literal_expr) = call_expr.get_args_list()?.get_args().next()? else {
+        return None;
+    };
+    match literal_expr.get_literal()? {
+        LuaLiteralToken::String(string_token) => Some(string_token.get_value()),
+        _ => None,
+    }
+}
+
+fn has_sub_types(semantic_model: &SemanticModel, type_decl_id: &LuaTypeDeclId) -> bool {
+    !semantic_model
+        .get_db()
+        .get_type_index()
+        .get_sub_types(type_decl_id)
+        .is_empty()
+}
+
+fn find_overridden_member<'a>(
+    semantic_model: &'a SemanticModel,
+    type_decl_id: &LuaTypeDeclId,
+    key: &LuaMemberKey,
+) -> Option<(LuaTypeDeclId, &'a LuaMember)> {
+    let db = semantic_model.get_db();
+    let mut visited = HashSet::new();
+    let mut queue = vec![type_decl_id.clone()];
+    while let Some(id) = queue.pop() {
+        for super_type in db.get_type_index().get_super_types(&id).unwrap_or_default() {
+            let super_id = match super_type {
+                LuaType::Ref(super_id) => super_id,
+                LuaType::Generic(generic) => generic.get_base_type_id(),
+                _ => continue,
+            };
+            if !visited.insert(super_id.clone()) {
+                continue;
+            }
+
+            let member = db
+                .get_member_index()
+                .get_member_item(&LuaMemberOwner::Type(super_id.clone()), key)
+                .and_then(|member_item| member_item.get_member_ids().first().cloned())
+                .and_then(|member_id| db.get_member_index().get_member(&member_id));
+            if let Some(member) = member {
+                return Some((super_id, member));
+            }
+            queue.push(super_id);
+        }
+    }
+
+    None
+}
@@ -1,8 +1,9 @@
 mod build_code_lens;
 mod resolve_code_lens;
+mod test;
 
 use build_code_lens::build_code_lens;
-use emmylua_code_analysis::{LuaDeclId, LuaMemberId};
+use emmylua_code_analysis::{FileId, LuaDeclId, LuaMemberId, LuaTypeDeclId};
 use lsp_types::{
     ClientCapabilities, CodeLens, CodeLensOptions, CodeLensParams, ServerCapabilities,
 };
@@ -28,7 +29,13 @@ pub async fn on_code_lens_handler(
         return None;
     }
 
-    build_code_lens(&mut semantic_model)
+    let client_id = context
+        .workspace_manager
+        .read()
+        .await
+        .client_config
+        .client_id;
+    build_code_lens(&mut semantic_model, client_id)
 }
 
 pub async fn on_resolve_code_lens_handler(
@@ -52,6 +59,8 @@ pub async fn on_resolve_code_lens_handler(
 pub enum CodeLensData {
     Member(LuaMemberId),
     DeclId(LuaDeclId),
+    Implementation(LuaMemberId),
+    Subclass(FileId, LuaTypeDeclId),
 }
 
 pub struct CodeLensCapabilities;
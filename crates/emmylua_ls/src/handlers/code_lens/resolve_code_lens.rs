@@ -1,4 +1,8 @@
-use emmylua_code_analysis::LuaCompilation;
+use std::collections::HashSet;
+
+use emmylua_code_analysis::{
+    LuaCompilation, LuaMember, LuaMemberOwner, LuaTypeDeclId, SemanticModel,
+};
 use lsp_types::{CodeLens, Command, Location, Range, Uri};
 
 use crate::{
@@ -54,9 +58,103 @@ pub fn resolve_code_lens(
                 data: None,
             })
         }
+        CodeLensData::Implementation(member_id) => {
+            let semantic_model = compilation.get_semantic_model(member_id.file_id)?;
+            let member_index = semantic_model.get_db().get_member_index();
+            let member = member_index.get_member(&member_id)?;
+            let owner_id = member.get_owner().get_type_id()?.clone();
+            let key = member.get_key();
+            let mut locations = Vec::new();
+            for sub_type_id in get_all_sub_types(&semantic_model, &owner_id) {
+                let Some(member_item) =
+                    member_index.get_member_item(&LuaMemberOwner::Type(sub_type_id), key)
+                else {
+                    continue;
+                };
+                for member_id in member_item.get_member_ids() {
+                    if let Some(location) = member_index
+                        .get_member(&member_id)
+                        .and_then(|member| get_member_location(&semantic_model, member))
+                    {
+                        locations.push(location);
+                    }
+                }
+            }
+
+            let title = if locations.len() == 1 {
+                "1 implementation".to_string()
+            } else {
+                format!("{} implementations", locations.len())
+            };
+            let uri = semantic_model.get_document().get_uri();
+            let command =
+                make_references_command(title, uri, code_lens.range, client_id, locations);
+            Some(CodeLens {
+                range: code_lens.range,
+                command: Some(command),
+                data: None,
+            })
+        }
+        CodeLensData::Subclass(file_id, type_decl_id) => {
+            let semantic_model = compilation.get_semantic_model(file_id)?;
+            let type_index = semantic_model.get_db().get_type_index();
+            let mut locations = Vec::new();
+            for sub_type_id in get_all_sub_types(&semantic_model, &type_decl_id) {
+                let Some(sub_type_decl) = type_index.get_type_decl(&sub_type_id) else {
+                    continue;
+                };
+                for location in sub_type_decl.get_locations() {
+                    if let Some(location) = semantic_model
+                        .get_document_by_file_id(location.file_id)
+                        .and_then(|document| document.to_lsp_location(location.range))
+                    {
+                        locations.push(location);
+                    }
+                }
+            }
+
+            let title = if locations.len() == 1 {
+                "1 subclass".to_string()
+            } else {
+                format!("{} subclasses", locations.len())
+            };
+            let uri = semantic_model.get_document().get_uri();
+            let command =
+                make_references_command(title, uri, code_lens.range, client_id, locations);
+            Some(CodeLens {
+                range: code_lens.range,
+                command: Some(command),
+                data: None,
+            })
+        }
     }
 }
 
+fn get_all_sub_types(
+    semantic_model: &SemanticModel,
+    type_decl_id: &LuaTypeDeclId,
+) -> Vec<LuaTypeDeclId> {
+    let type_index = semantic_model.get_db().get_type_index();
+    let mut visited = HashSet::new();
+    let mut queue = vec![type_decl_id.clone()];
+    let mut sub_types = Vec::new();
+    while let Some(id) = queue.pop() {
+        for sub_type_id in type_index.get_sub_types(&id) {
+            if visited.insert(sub_type_id.clone()) {
+                sub_types.push(sub_type_id.clone());
+                queue.push(sub_type_id);
+            }
+        }
+    }
+
+    sub_types
+}
+
+pub fn get_member_location(semantic_model: &SemanticModel, member: &LuaMember) -> Option<Location> {
+    let document = semantic_model.get_document_by_file_id(member.get_file_id())?;
+    document.to_lsp_location(member.get_range())
+}
+
 fn get_command_name(client_id: ClientId) -> &'static str {
     match client_id {
         ClientId::VSCode => VSCODE_COMMAND_NAME,
@@ -72,6 +170,16 @@ fn make_usage_command(
     refs: Vec<Location>,
 ) -> Command {
     let title = format!("{} usage", ref_count);
+    make_references_command(title, uri, range, client_id, refs)
+}
+
+pub fn make_references_command(
+    title: String,
+    uri: Uri,
+    range: Range,
+    client_id: ClientId,
+    refs: Vec<Location>,
+) -> Command {
     let mut args = Vec::new();
     args.push(serde_json::to_value(uri).unwrap());
     args.push(serde_json::to_value(range.start).unwrap());
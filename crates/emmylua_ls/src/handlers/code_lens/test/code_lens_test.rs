@@ -0,0 +1,107 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use emmylua_code_analysis::Emmyrc;
+
+    use crate::handlers::{code_lens::test::get_titles, test_lib::ProviderVirtualWorkspace};
+
+    #[test]
+    fn test_implementation_code_lens() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let code_lens = ws
+            .get_code_lens(
+                r#"
+                ---@class Shape
+                ---@field area fun(self: Shape): number
+
+                ---@class Circle: Shape
+                local Circle = {}
+
+                function Circle:area()
+                    return 1
+                end
+
+                ---@class Square: Shape
+                local Square = {}
+
+                function Square:area()
+                    return 2
+                end
+                "#,
+            )
+            .unwrap();
+        let titles = get_titles(&code_lens);
+        assert!(titles.contains(&(2, "2 implementations".to_string())));
+    }
+
+    #[test]
+    fn test_overrides_code_lens() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let code_lens = ws
+            .get_code_lens(
+                r#"
+                ---@class Animal
+                local Animal = {}
+
+                function Animal:speak()
+                end
+
+                ---@class Dog: Animal
+                local Dog = {}
+
+                function Dog:speak()
+                end
+                "#,
+            )
+            .unwrap();
+        let titles = get_titles(&code_lens);
+        assert!(titles.contains(&(4, "1 implementation".to_string())));
+        assert!(titles.contains(&(10, "overrides Animal:speak".to_string())));
+    }
+
+    #[test]
+    fn test_subclass_code_lens() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let code_lens = ws
+            .get_code_lens(
+                r#"
+                ---@class Base
+
+                ---@class Middle: Base
+
+                ---@class Leaf: Middle
+                "#,
+            )
+            .unwrap();
+        let titles = get_titles(&code_lens);
+        assert!(titles.contains(&(1, "2 subclasses".to_string())));
+        assert!(titles.contains(&(3, "1 subclass".to_string())));
+        assert!(!titles.iter().any(|(line, _)| *line == 5));
+    }
+
+    #[test]
+    fn test_run_test_code_lens() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let mut emmyrc = Emmyrc::default();
+        emmyrc.code_lens.run_test = true;
+        ws.analysis.update_config(Arc::new(emmyrc));
+        let code_lens = ws
+            .get_code_lens(
+                r#"
+                describe("math", function()
+                    it("adds", function()
+                    end)
+                end)
+                "#,
+            )
+            .unwrap();
+        let run_tests = code_lens
+            .iter()
+            .filter_map(|lens| lens.command.as_ref())
+            .filter(|command| command.title == "Run test")
+            .map(|command| command.arguments.as_ref().unwrap()[1].clone())
+            .collect::<Vec<_>>();
+        assert_eq!(run_tests, vec!["math", "math adds"]);
+    }
+}
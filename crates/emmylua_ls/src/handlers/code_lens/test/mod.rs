@@ -0,0 +1,34 @@
+use lsp_types::CodeLens;
+
+mod code_lens_test;
+use super::{build_code_lens::build_code_lens, resolve_code_lens::resolve_code_lens};
+use crate::{context::ClientId, handlers::test_lib::ProviderVirtualWorkspace};
+
+#[allow(unused)]
+impl ProviderVirtualWorkspace {
+    /// The lenses of `content`, each one resolved like the client would
+    pub fn get_code_lens(&mut self, content: &str) -> Option<Vec<CodeLens>> {
+        let file_id = self.def(content);
+        let semantic_model = self.analysis.compilation.get_semantic_model(file_id)?;
+        let compilation = &self.analysis.compilation;
+        let code_lens = build_code_lens(&semantic_model, ClientId::Other)?
+            .into_iter()
+            .map(|lens| {
+                resolve_code_lens(compilation, lens.clone(), ClientId::Other).unwrap_or(lens)
+            })
+            .collect();
+        Some(code_lens)
+    }
+}
+
+/// `(line, title)` of the lenses which have a command
+#[allow(unused)]
+fn get_titles(code_lens: &[CodeLens]) -> Vec<(u32, String)> {
+    code_lens
+        .iter()
+        .filter_map(|lens| {
+            let command = lens.command.as_ref()?;
+            Some((lens.range.start.line, command.title.clone()))
+        })
+        .collect()
+}
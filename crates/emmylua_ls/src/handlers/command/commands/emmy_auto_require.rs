@@ -1,7 +1,7 @@
 use std::{collections::HashMap, time::Duration};
 
-use emmylua_code_analysis::FileId;
-use emmylua_parser::{LuaAstNode, LuaExpr, LuaStat};
+use emmylua_code_analysis::{Emmyrc, FileId};
+use emmylua_parser::{LuaAstNode, LuaExpr, LuaLiteralToken, LuaStat};
 use lsp_types::{ApplyWorkspaceEditParams, Command, Position, TextEdit, WorkspaceEdit};
 use serde_json::Value;
 
@@ -17,6 +17,11 @@ pub async fn handle(context: ServerContextSnapshot, args: Vec<Value>) -> Option<
     let need_require_file_id: FileId = serde_json::from_value(args.get(1)?.clone()).ok()?;
     let position: Position = serde_json::from_value(args.get(2)?.clone()).ok()?;
 
+    // the local name defaults to the converted file name, classes are bound to their own name
+    let local_name: Option<String> = args
+        .get(3)
+        .and_then(|value| serde_json::from_value(value.clone()).ok());
+
     let analysis = context.analysis.read().await;
     let semantic_model = analysis.compilation.get_semantic_model(add_to)?;
     let module_info = semantic_model
@@ -24,10 +29,10 @@ pub async fn handle(context: ServerContextSnapshot, args: Vec<Value>) -> Option<
         .get_module_index()
         .get_module(need_require_file_id)?;
     let emmyrc = semantic_model.get_emmyrc();
-    let require_like_func = &emmyrc.runtime.require_like_function;
     let auto_require_func = emmyrc.completion.auto_require_function.clone();
     let file_conversion = emmyrc.completion.auto_require_naming_convention;
-    let local_name = module_name_convert(&module_info.name, file_conversion);
+    let local_name =
+        local_name.unwrap_or_else(|| module_name_convert(&module_info.name, file_conversion));
     let require_str = format!(
         "local {} = {}(\"{}\")",
         local_name, auto_require_func, module_info.full_module_name
@@ -35,18 +40,36 @@ pub async fn handle(context: ServerContextSnapshot, args: Vec<Value>) -> Option<
     let document = semantic_model.get_document();
     let offset = document.get_offset(position.line as usize, position.character as usize)?;
     let root_block = semantic_model.get_root().get_block()?;
-    let mut last_require_stat: Option<LuaStat> = None;
+    let mut require_stats: Vec<(LuaStat, Option<String>)> = Vec::new();
     for stat in root_block.get_stats() {
         if stat.get_position() > offset {
             break;
         }
 
-        if is_require_stat(stat.clone(), &require_like_func).unwrap_or(false) {
-            last_require_stat = Some(stat);
+        if is_require_stat(stat.clone(), &emmyrc).unwrap_or(false) {
+            let require_path = get_require_path(&stat);
+            require_stats.push((stat, require_path));
         }
     }
 
-    let line = if let Some(last_require_stat) = last_require_stat {
+    // keep a sorted require block sorted, otherwise append to it
+    let is_sorted = require_stats
+        .iter()
+        .all(|(_, require_path)| require_path.is_some())
+        && require_stats.windows(2).all(|pair| pair[0].1 <= pair[1].1);
+    let insert_before = if is_sorted {
+        require_stats.iter().find(|(_, require_path)| {
+            require_path
+                .as_ref()
+                .is_some_and(|path| path.as_str() > module_info.full_module_name.as_str())
+        })
+    } else {
+        None
+    };
+
+    let line = if let Some((stat, _)) = insert_before {
+        document.get_line(stat.get_position())?
+    } else if let Some((last_require_stat, _)) = require_stats.last() {
         let last_require_stat_end = last_require_stat.get_range().end();
         document.get_line(last_require_stat_end)? + 1
     } else {
@@ -94,12 +117,12 @@ pub async fn handle(context: ServerContextSnapshot, args: Vec<Value>) -> Option<
     Some(())
 }
 
-fn is_require_stat(stat: LuaStat, require_like_func: &Vec<String>) -> Option<bool> {
+fn is_require_stat(stat: LuaStat, emmyrc: &Emmyrc) -> Option<bool> {
     match stat {
         LuaStat::LocalStat(local_stat) => {
             let exprs = local_stat.get_value_exprs();
             for expr in exprs {
-                if is_require_expr(expr, require_like_func).unwrap_or(false) {
+                if is_require_expr(expr, emmyrc).unwrap_or(false) {
                     return Some(true);
                 }
             }
@@ -107,14 +130,14 @@ fn is_require_stat(stat: LuaStat, require_like_func: &Vec<String>) -> Option<boo
         LuaStat::AssignStat(assign_stat) => {
             let (_, exprs) = assign_stat.get_var_and_expr_list();
             for expr in exprs {
-                if is_require_expr(expr, require_like_func).unwrap_or(false) {
+                if is_require_expr(expr, emmyrc).unwrap_or(false) {
                     return Some(true);
                 }
             }
         }
         LuaStat::CallExprStat(call_expr_stat) => {
             let expr = call_expr_stat.get_call_expr()?;
-            if is_require_expr(expr.into(), require_like_func).unwrap_or(false) {
+            if is_require_expr(expr.into(), emmyrc).unwrap_or(false) {
                 return Some(true);
             }
         }
@@ -124,12 +147,16 @@ fn is_require_stat(stat: LuaStat, require_like_func: &Vec<String>) -> Option<boo
     Some(false)
 }
 
-fn is_require_expr(expr: LuaExpr, require_like_func: &Vec<String>) -> Option<bool> {
+/// `require`, the `runtime.requireLikeFunction`s and the `completion.autoRequireFunction`
+pub fn is_require_expr(expr: LuaExpr, emmyrc: &Emmyrc) -> Option<bool> {
     if let LuaExpr::CallExpr(call_expr) = expr {
         let name = call_expr.get_prefix_expr()?;
         if let LuaExpr::NameExpr(name_expr) = name {
             let name = name_expr.get_name_text()?;
-            if require_like_func.contains(&name.to_string()) || name == "require" {
+            if name == "require"
+                || name == emmyrc.completion.auto_require_function
+                || emmyrc.runtime.require_like_function.contains(&name)
+            {
                 return Some(true);
             }
         }
@@ -138,17 +165,38 @@ fn is_require_expr(expr: LuaExpr, require_like_func: &Vec<String>) -> Option<boo
     Some(false)
 }
 
+// the module path of `local x = require("a.b")`
+fn get_require_path(stat: &LuaStat) -> Option<String> {
+    let LuaStat::LocalStat(local_stat) = stat else {
+        return None;
+    };
+    let LuaExpr::CallExpr(call_expr) = local_stat.get_value_exprs().next()? else {
+        return None;
+    };
+    let LuaExpr::LiteralExpr(literal_expr) = call_expr.get_args_list()?.get_args().next()? else {
+        return None;
+    };
+    match literal_expr.get_literal()? {
+        LuaLiteralToken::String(string_token) => Some(string_token.get_value()),
+        _ => None,
+    }
+}
+
 pub fn make_auto_require(
     title: &str,
     add_to: FileId,
     need_require_file_id: FileId,
     position: Position,
+    local_name: Option<String>,
 ) -> Command {
-    let args = vec![
+    let mut args = vec![
         serde_json::to_value(add_to).unwrap(),
         serde_json::to_value(need_require_file_id).unwrap(),
         serde_json::to_value(position).unwrap(),
     ];
+    if let Some(local_name) = local_name {
+        args.push(serde_json::to_value(local_name).unwrap());
+    }
 
     Command {
         title: title.to_string(),
@@ -0,0 +1,96 @@
+use std::{collections::HashMap, time::Duration};
+
+use emmylua_code_analysis::{structural_replace, StructuralPattern, StructuralTemplate};
+use lsp_types::{ApplyWorkspaceEditParams, MessageType, ShowMessageParams, Uri, WorkspaceEdit};
+use serde_json::Value;
+
+use crate::{context::ServerContextSnapshot, util::time_cancel_token};
+
+pub const COMMAND: &str = "emmy.structuralReplace";
+
+/// args: `[search, replace, uri?]`, the whole main workspace is replaced when the uri is absent
+pub async fn handle(context: ServerContextSnapshot, args: Vec<Value>) -> Option<()> {
+    let search: String = serde_json::from_value(args.first()?.clone()).ok()?;
+    let replace: String = serde_json::from_value(args.get(1)?.clone()).ok()?;
+    let uri: Option<Uri> = args
+        .get(2)
+        .and_then(|value| serde_json::from_value(value.clone()).ok());
+
+    let analysis = context.analysis.read().await;
+    let client = context.client;
+    let emmyrc = &analysis.emmyrc;
+    let parsed = StructuralPattern::parse(&search, emmyrc).and_then(|pattern| {
+        let template = StructuralTemplate::parse(&replace, &pattern, emmyrc)?;
+        Ok((pattern, template))
+    });
+    let (pattern, template) = match parsed {
+        Ok(parsed) => parsed,
+        Err(message) => {
+            client.show_message(ShowMessageParams {
+                typ: MessageType::ERROR,
+                message: format!("Invalid structural replace: {}", message),
+            });
+            return None;
+        }
+    };
+
+    let file_ids = match &uri {
+        Some(uri) => vec![analysis.get_file_id(uri)?],
+        None => analysis
+            .compilation
+            .get_db()
+            .get_module_index()
+            .get_main_workspace_file_ids(),
+    };
+
+    // collected into the `WorkspaceEdit` directly, a map keyed by `Uri` trips `mutable_key_type`
+    let mut changes = Vec::new();
+    let mut skipped = 0;
+    for file_id in file_ids {
+        let Some(semantic_model) = analysis.compilation.get_semantic_model(file_id) else {
+            continue;
+        };
+        let result = structural_replace(&semantic_model, &pattern, &template);
+        skipped += result.skipped;
+        if !result.edits.is_empty() {
+            changes.push((semantic_model.get_document().get_uri(), result.edits));
+        }
+    }
+    if skipped > 0 {
+        client.show_message(ShowMessageParams {
+            typ: MessageType::WARNING,
+            message: format!(
+                "{} matches with a comment outside the placeholders were skipped",
+                skipped
+            ),
+        });
+    }
+    if changes.is_empty() {
+        client.show_message(ShowMessageParams {
+            typ: MessageType::INFO,
+            message: format!("No match found for `{}`", search),
+        });
+        return None;
+    }
+
+    let cancel_token = time_cancel_token(Duration::from_secs(5));
+    let apply_edit_params = ApplyWorkspaceEditParams {
+        label: Some("Structural replace".to_string()),
+        edit: WorkspaceEdit {
+            changes: Some(HashMap::from_iter(changes)),
+            document_changes: None,
+            change_annotations: None,
+        },
+    };
+
+    tokio::spawn(async move {
+        let res = client.apply_edit(apply_edit_params, cancel_token).await;
+        if let Some(res) = res {
+            if !res.applied {
+                log::error!("Failed to apply edit: {:?}", res.failure_reason);
+            }
+        }
+    });
+
+    Some(())
+}
@@ -5,8 +5,9 @@ use crate::context::ServerContextSnapshot;
 mod emmy_auto_require;
 mod emmy_disable_code;
 mod emmy_fix_format;
+mod emmy_structural_replace;
 
-pub use emmy_auto_require::make_auto_require;
+pub use emmy_auto_require::{is_require_expr, make_auto_require};
 pub use emmy_disable_code::{make_disable_code_command, DisableAction};
 
 pub fn get_commands_list() -> Vec<String> {
@@ -23,6 +24,7 @@ pub fn get_commands_list() -> Vec<String> {
     command_from!(emmy_auto_require);
     command_from!(emmy_disable_code);
     command_from!(emmy_fix_format);
+    command_from!(emmy_structural_replace);
 
     commands
 }
@@ -49,7 +51,12 @@ pub async fn dispatch_command(
         command_name,
         context,
         args,
-        [emmy_auto_require, emmy_disable_code, emmy_fix_format]
+        [
+            emmy_auto_require,
+            emmy_disable_code,
+            emmy_fix_format,
+            emmy_structural_replace
+        ]
     );
 
     Some(())
@@ -4,8 +4,8 @@ use lsp_types::CompletionItem;
 use crate::handlers::completion::completion_builder::CompletionBuilder;
 
 use super::{
-    check_visibility, get_completion_kind, get_description, get_detail, is_deprecated, CallDisplay,
-    CompletionData,
+    add_signature_overloads, check_visibility, get_call_snippet, get_completion_kind,
+    get_description, get_detail, is_deprecated, set_call_snippet, CallDisplay, CompletionData,
 };
 
 pub fn add_decl_completion(
@@ -32,6 +32,23 @@ pub fn add_decl_completion(
         completion_item.deprecated = Some(true);
     }
 
+    let call_snippet = get_call_snippet(builder, name, typ, CallDisplay::None);
+    // the overloads are only listed for call snippets, each one inserts its own parameters
+    let has_call_snippet = call_snippet.is_some();
+    set_call_snippet(&mut completion_item, call_snippet);
+    let deprecated = completion_item.deprecated;
     builder.add_completion_item(completion_item)?;
+
+    if has_call_snippet {
+        add_signature_overloads(
+            builder,
+            &Some(property_owner),
+            typ,
+            false,
+            deprecated,
+            name.to_string(),
+            true,
+        );
+    }
     Some(())
 }
@@ -1,11 +1,12 @@
-use emmylua_code_analysis::{DbIndex, LuaMemberInfo, LuaMemberKey, LuaSemanticDeclId, LuaType};
+use emmylua_code_analysis::{DbIndex, LuaMemberInfo, LuaMemberKey, LuaType};
 use emmylua_parser::LuaTokenKind;
 use lsp_types::CompletionItem;
 
 use crate::handlers::completion::completion_builder::CompletionBuilder;
 
 use super::{
-    check_visibility, get_completion_kind, get_description, get_detail, is_deprecated, CallDisplay,
+    add_signature_overloads, check_visibility, get_call_display, get_call_snippet,
+    get_completion_kind, get_description, get_detail, is_deprecated, set_call_snippet, CallDisplay,
     CompletionData,
 };
 
@@ -106,61 +107,33 @@ pub fn add_member_completion(
         }]);
     }
 
+    let with_call_snippet = matches!(
+        status,
+        CompletionTriggerStatus::Dot | CompletionTriggerStatus::Colon
+    ) && member_key.is_name();
+    if with_call_snippet {
+        set_call_snippet(
+            &mut completion_item,
+            get_call_snippet(builder, &label, &typ, display),
+        );
+    }
+
     builder.add_completion_item(completion_item)?;
 
     // add overloads if the type is function
-    add_signature_overloads(builder, property_owner, &typ, display, deprecated, label)?;
+    add_signature_overloads(
+        builder,
+        property_owner,
+        &typ,
+        status == CompletionTriggerStatus::Colon,
+        deprecated,
+        label,
+        with_call_snippet,
+    )?;
 
     Some(())
 }
 
-fn add_signature_overloads(
-    builder: &mut CompletionBuilder,
-    property_owner: &Option<LuaSemanticDeclId>,
-    typ: &LuaType,
-    display: CallDisplay,
-    deprecated: Option<bool>,
-    label: String,
-) -> Option<()> {
-    if let LuaType::Signature(signature_id) = typ {
-        let overloads = builder
-            .semantic_model
-            .get_db()
-            .get_signature_index()
-            .get(&signature_id)?
-            .overloads
-            .clone();
-
-        overloads
-            .into_iter()
-            .enumerate()
-            .for_each(|(index, overload)| {
-                let typ = LuaType::DocFunction(overload);
-                let description = get_description(builder, &typ);
-                let detail = get_detail(builder, &typ, display);
-                let data = if let Some(id) = &property_owner {
-                    CompletionData::from_overload(id.clone().into(), index)
-                } else {
-                    None
-                };
-                let completion_item = CompletionItem {
-                    label: label.clone(),
-                    kind: Some(get_completion_kind(&typ)),
-                    data,
-                    label_details: Some(lsp_types::CompletionItemLabelDetails {
-                        detail,
-                        description,
-                    }),
-                    deprecated,
-                    ..Default::default()
-                };
-
-                builder.add_completion_item(completion_item);
-            });
-    };
-    Some(())
-}
-
 fn get_call_show(
     db: &DbIndex,
     typ: &LuaType,
@@ -181,9 +154,5 @@ fn get_call_show(
         _ => return None,
     };
 
-    match (colon_call, colon_define) {
-        (false, true) => Some(CallDisplay::AddSelf),
-        (true, false) => Some(CallDisplay::RemoveFirst),
-        _ => Some(CallDisplay::None),
-    }
+    Some(get_call_display(colon_call, colon_define))
 }
@@ -6,7 +6,8 @@ pub use add_decl_completion::add_decl_completion;
 pub use add_member_completion::{add_member_completion, CompletionTriggerStatus};
 pub use check_match_word::check_match_word;
 use emmylua_code_analysis::{LuaSemanticDeclId, LuaType, RenderLevel};
-use lsp_types::CompletionItemKind;
+use emmylua_parser::LuaTokenKind;
+use lsp_types::{CompletionItem, CompletionItemKind, InsertTextFormat};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -31,7 +32,7 @@ pub fn check_visibility(builder: &mut CompletionBuilder, id: LuaSemanticDeclId)
     Some(())
 }
 
-fn get_completion_kind(typ: &LuaType) -> CompletionItemKind {
+pub fn get_completion_kind(typ: &LuaType) -> CompletionItemKind {
     if typ.is_function() {
         return CompletionItemKind::FUNCTION;
     } else if typ.is_const() {
@@ -65,6 +66,14 @@ pub enum CallDisplay {
     RemoveFirst,
 }
 
+fn get_call_display(colon_call: bool, colon_define: bool) -> CallDisplay {
+    match (colon_call, colon_define) {
+        (false, true) => CallDisplay::AddSelf,
+        (true, false) => CallDisplay::RemoveFirst,
+        _ => CallDisplay::None,
+    }
+}
+
 fn get_detail(builder: &CompletionBuilder, typ: &LuaType, display: CallDisplay) -> Option<String> {
     match typ {
         LuaType::Signature(signature_id) => {
@@ -154,6 +163,128 @@ fn get_detail(builder: &CompletionBuilder, typ: &LuaType, display: CallDisplay)
     }
 }
 
+/// Returns `name(${1:a}, ${2:b})` when `completion.callSnippet` is enabled and the function is
+/// not already called
+fn get_call_snippet(
+    builder: &CompletionBuilder,
+    name: &str,
+    typ: &LuaType,
+    display: CallDisplay,
+) -> Option<String> {
+    if !builder.semantic_model.get_emmyrc().completion.call_snippet {
+        return None;
+    }
+    if let Some(next_token) = builder.trigger_token.next_token() {
+        if next_token.kind() == LuaTokenKind::TkLeftParen.into() {
+            return None;
+        }
+    }
+
+    let mut params = match typ {
+        LuaType::Signature(signature_id) => builder
+            .semantic_model
+            .get_db()
+            .get_signature_index()
+            .get(signature_id)?
+            .get_type_params()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect::<Vec<_>>(),
+        LuaType::DocFunction(f) => f
+            .get_params()
+            .iter()
+            .map(|(name, _)| name.clone())
+            .collect::<Vec<_>>(),
+        _ => return None,
+    };
+    match display {
+        CallDisplay::AddSelf => params.insert(0, "self".to_string()),
+        CallDisplay::RemoveFirst => {
+            if !params.is_empty() {
+                params.remove(0);
+            }
+        }
+        CallDisplay::None => {}
+    }
+
+    let params = params
+        .iter()
+        .enumerate()
+        .map(|(index, param)| format!("${{{}:{}}}", index + 1, escape_snippet(param)))
+        .collect::<Vec<_>>();
+    Some(format!("{}({})", escape_snippet(name), params.join(", ")))
+}
+
+pub fn escape_snippet(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('$', "\\$")
+        .replace('}', "\\}")
+}
+
+fn set_call_snippet(completion_item: &mut CompletionItem, snippet: Option<String>) {
+    if let Some(snippet) = snippet {
+        completion_item.insert_text = Some(snippet);
+        completion_item.insert_text_format = Some(InsertTextFormat::SNIPPET);
+    }
+}
+
+/// Add a completion item for every `---@overload` of the function,
+/// each overload declares its own `self` so the call display is computed per overload
+fn add_signature_overloads(
+    builder: &mut CompletionBuilder,
+    property_owner: &Option<LuaSemanticDeclId>,
+    typ: &LuaType,
+    colon_call: bool,
+    deprecated: Option<bool>,
+    label: String,
+    with_call_snippet: bool,
+) -> Option<()> {
+    if let LuaType::Signature(signature_id) = typ {
+        let overloads = builder
+            .semantic_model
+            .get_db()
+            .get_signature_index()
+            .get(signature_id)?
+            .overloads
+            .clone();
+
+        overloads
+            .into_iter()
+            .enumerate()
+            .for_each(|(index, overload)| {
+                let display = get_call_display(colon_call, overload.is_colon_define());
+                let typ = LuaType::DocFunction(overload);
+                let description = get_description(builder, &typ);
+                let detail = get_detail(builder, &typ, display);
+                let data = if let Some(id) = &property_owner {
+                    CompletionData::from_overload(id.clone(), index)
+                } else {
+                    None
+                };
+                let mut completion_item = CompletionItem {
+                    label: label.clone(),
+                    kind: Some(get_completion_kind(&typ)),
+                    data,
+                    label_details: Some(lsp_types::CompletionItemLabelDetails {
+                        detail,
+                        description,
+                    }),
+                    deprecated,
+                    ..Default::default()
+                };
+                if with_call_snippet {
+                    set_call_snippet(
+                        &mut completion_item,
+                        get_call_snippet(builder, &label, &typ, display),
+                    );
+                }
+
+                builder.add_completion_item(completion_item);
+            });
+    };
+    Some(())
+}
+
 #[allow(unused)]
 fn truncate_with_ellipsis(s: &str, max_len: usize) -> String {
     if s.chars().count() > max_len {
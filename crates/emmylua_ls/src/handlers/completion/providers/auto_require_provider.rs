@@ -1,9 +1,20 @@
-use emmylua_code_analysis::{EmmyrcFilenameConvention, ModuleInfo};
-use emmylua_parser::{LuaAstNode, LuaNameExpr};
+use std::collections::HashMap;
+
+use emmylua_code_analysis::{
+    EmmyrcFilenameConvention, FileId, LuaMember, LuaMemberKey, LuaMemberOwner, LuaSemanticDeclId,
+    LuaType, ModuleInfo,
+};
+use emmylua_parser::{LuaAstNode, LuaExpr, LuaLiteralToken, LuaNameExpr, LuaStat};
 use lsp_types::{CompletionItem, Position};
 
 use crate::{
-    handlers::{command::make_auto_require, completion::completion_builder::CompletionBuilder},
+    handlers::{
+        command::{is_require_expr, make_auto_require},
+        completion::{
+            add_completions::{get_completion_kind, CompletionData},
+            completion_builder::CompletionBuilder,
+        },
+    },
     util::module_name_convert,
 };
 
@@ -33,16 +44,29 @@ pub fn add_completion(builder: &mut CompletionBuilder) -> Option<()> {
     let document = builder.semantic_model.get_document();
     let lsp_position = document.to_lsp_range(range)?.start;
 
+    let required_modules = get_required_modules(builder).unwrap_or_default();
     let mut completions = Vec::new();
     for module_info in module_infos {
         if module_info.is_visible(&version_number)
             && module_info.file_id != file_id
             && module_info.export_type.is_some()
         {
-            add_module_completion_item(
+            let required_name = required_modules.get(&module_info.file_id);
+            if required_name.is_none() {
+                add_module_completion_item(
+                    builder,
+                    &prefix,
+                    module_info,
+                    file_conversion,
+                    lsp_position,
+                    &mut completions,
+                );
+            }
+            add_module_member_completion_items(
                 builder,
                 &prefix,
-                &module_info,
+                module_info,
+                required_name,
                 file_conversion,
                 lsp_position,
                 &mut completions,
@@ -65,7 +89,7 @@ fn add_module_completion_item(
     position: Position,
     completions: &mut Vec<CompletionItem>,
 ) -> Option<()> {
-    let completion_name = module_name_convert(&module_info.name, file_conversion);
+    let (completion_name, is_class) = get_module_local_name(builder, module_info, file_conversion);
     if !completion_name.to_lowercase().starts_with(prefix) {
         return None;
     }
@@ -75,8 +99,12 @@ fn add_module_completion_item(
     }
 
     let completion_item = CompletionItem {
-        label: completion_name,
-        kind: Some(lsp_types::CompletionItemKind::MODULE),
+        label: completion_name.clone(),
+        kind: Some(if is_class {
+            lsp_types::CompletionItemKind::CLASS
+        } else {
+            lsp_types::CompletionItemKind::MODULE
+        }),
         label_details: Some(lsp_types::CompletionItemLabelDetails {
             detail: Some(format!("    (in {})", module_info.full_module_name)),
             ..Default::default()
@@ -86,6 +114,7 @@ fn add_module_completion_item(
             builder.semantic_model.get_file_id(),
             module_info.file_id,
             position,
+            Some(completion_name),
         )),
         ..Default::default()
     };
@@ -94,3 +123,160 @@ fn add_module_completion_item(
 
     Some(())
 }
+
+/// The local bound to a required module, a module returning a class is bound to the class name,
+/// otherwise the file name is converted by `completion.autoRequireNamingConvention`
+fn get_module_local_name(
+    builder: &CompletionBuilder,
+    module_info: &ModuleInfo,
+    file_conversion: EmmyrcFilenameConvention,
+) -> (String, bool) {
+    if let Some(LuaType::Def(type_decl_id) | LuaType::Ref(type_decl_id)) = &module_info.export_type
+    {
+        let is_class = builder
+            .semantic_model
+            .get_db()
+            .get_type_index()
+            .get_type_decl(type_decl_id)
+            .is_some_and(|type_decl| type_decl.is_class());
+        if is_class {
+            return (type_decl_id.get_simple_name().to_string(), true);
+        }
+    }
+
+    (
+        module_name_convert(&module_info.name, file_conversion),
+        false,
+    )
+}
+
+// `encodeJson` completes to `json.encodeJson`, requiring the module when it is not required yet.
+// only the members declared on the export are listed, nothing is inferred on each keystroke
+fn add_module_member_completion_items(
+    builder: &CompletionBuilder,
+    prefix: &str,
+    module_info: &ModuleInfo,
+    required_name: Option<&String>,
+    file_conversion: EmmyrcFilenameConvention,
+    position: Position,
+    completions: &mut Vec<CompletionItem>,
+) -> Option<()> {
+    let owner = match module_info.export_type.as_ref()? {
+        LuaType::Def(type_decl_id) | LuaType::Ref(type_decl_id) => {
+            LuaMemberOwner::Type(type_decl_id.clone())
+        }
+        LuaType::TableConst(range) => LuaMemberOwner::Element(range.clone()),
+        LuaType::Instance(instance) => LuaMemberOwner::Element(instance.get_range().clone()),
+        _ => return None,
+    };
+    let members = builder
+        .semantic_model
+        .get_db()
+        .get_member_index()
+        .get_members(&owner)?;
+    let mut command = None;
+    for member in members {
+        let LuaMemberKey::Name(name) = member.get_key() else {
+            continue;
+        };
+        if !name.to_lowercase().starts_with(prefix)
+            || builder.env_duplicate_name.contains(name.as_str())
+            || !is_static_member(builder, member)
+        {
+            continue;
+        }
+        let id = LuaSemanticDeclId::Member(member.get_id());
+        if !builder
+            .semantic_model
+            .is_semantic_visible(builder.trigger_token.clone(), id.clone())
+        {
+            continue;
+        }
+
+        let (local_name, command) = command.get_or_insert_with(|| match required_name {
+            Some(name) => (name.clone(), None),
+            None => {
+                let (local_name, _) = get_module_local_name(builder, module_info, file_conversion);
+                let command = make_auto_require(
+                    "",
+                    builder.semantic_model.get_file_id(),
+                    module_info.file_id,
+                    position,
+                    Some(local_name.clone()),
+                );
+                (local_name, Some(command))
+            }
+        });
+        completions.push(CompletionItem {
+            label: name.to_string(),
+            kind: Some(get_completion_kind(&member.get_decl_type())),
+            label_details: Some(lsp_types::CompletionItemLabelDetails {
+                detail: Some(format!("    (in {})", module_info.full_module_name)),
+                ..Default::default()
+            }),
+            filter_text: Some(name.to_string()),
+            insert_text: Some(format!("{}.{}", local_name, name)),
+            data: CompletionData::from_property_owner_id(id),
+            command: command.clone(),
+            ..Default::default()
+        });
+    }
+
+    Some(())
+}
+
+// `---@field` members and methods defined with `:` belong to the instances, not to the exported table
+fn is_static_member(builder: &CompletionBuilder, member: &LuaMember) -> bool {
+    if member.is_field() {
+        return false;
+    }
+    match member.get_decl_type() {
+        LuaType::Signature(signature_id) => !builder
+            .semantic_model
+            .get_db()
+            .get_signature_index()
+            .get(&signature_id)
+            .is_some_and(|signature| signature.is_colon_define),
+        _ => true,
+    }
+}
+
+// modules already required at the top level of the current file, by the local bound to them
+fn get_required_modules(builder: &CompletionBuilder) -> Option<HashMap<FileId, String>> {
+    let module_index = builder.semantic_model.get_db().get_module_index();
+    let emmyrc = builder.semantic_model.get_emmyrc();
+    let root_block = builder.semantic_model.get_root().get_block()?;
+    let mut required_modules = HashMap::new();
+    for stat in root_block.get_stats() {
+        let LuaStat::LocalStat(local_stat) = stat else {
+            continue;
+        };
+        let Some(LuaExpr::CallExpr(call_expr)) = local_stat.get_value_exprs().next() else {
+            continue;
+        };
+        if !is_require_expr(call_expr.clone().into(), emmyrc).unwrap_or(false) {
+            continue;
+        }
+        let Some(LuaExpr::LiteralExpr(literal_expr)) = call_expr
+            .get_args_list()
+            .and_then(|args| args.get_args().next())
+        else {
+            continue;
+        };
+        let Some(LuaLiteralToken::String(path)) = literal_expr.get_literal() else {
+            continue;
+        };
+        let Some(local_name) = local_stat
+            .get_local_name_list()
+            .next()
+            .and_then(|local_name| local_name.get_name_token())
+        else {
+            continue;
+        };
+        if let Some(module_info) = module_index.find_module(&path.get_value()) {
+            required_modules.insert(module_info.file_id, local_name.get_name_text().to_string());
+        }
+    }
+
+    Some(required_modules)
+}
@@ -8,6 +8,7 @@ mod keywords_provider;
 mod member_provider;
 mod module_path_provider;
 mod postfix_provider;
+mod snippet_provider;
 mod table_field_provider;
 mod type_special_provider;
 
@@ -27,6 +28,7 @@ pub fn add_completions(builder: &mut CompletionBuilder) -> Option<()> {
     // 如果`table_field_provider`执行成功会中止补全
     table_field_provider::add_completion(builder);
     keywords_provider::add_completion(builder);
+    snippet_provider::add_completion(builder);
     member_provider::add_completion(builder);
 
     module_path_provider::add_completion(builder);
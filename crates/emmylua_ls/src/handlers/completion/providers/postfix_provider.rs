@@ -1,9 +1,11 @@
-use emmylua_code_analysis::Emmyrc;
-use emmylua_parser::{LuaAstNode, LuaSyntaxToken, LuaTokenKind};
+use emmylua_code_analysis::{Emmyrc, EmmyrcPostfixExprType, LuaType};
+use emmylua_parser::{LuaAstNode, LuaExpr, LuaSyntaxToken, LuaTokenKind};
 use lsp_types::{CompletionItem, Range};
 use rowan::{TextRange, TextSize, TokenAtOffset};
 
-use crate::handlers::completion::completion_builder::CompletionBuilder;
+use crate::handlers::completion::{
+    add_completions::escape_snippet, completion_builder::CompletionBuilder,
+};
 
 pub fn add_completion(builder: &mut CompletionBuilder) -> Option<()> {
     if builder.is_cancelled() {
@@ -39,7 +41,7 @@ pub fn add_completion(builder: &mut CompletionBuilder) -> Option<()> {
         }
         TokenAtOffset::None => return None,
     };
-    let (text_range, replace_range) = get_left_valid_range(left_token, trigger_pos.into())?;
+    let (text_range, replace_range) = get_left_valid_range(left_token.clone(), trigger_pos.into())?;
 
     let (left_token_text, replace_lsp_range) = {
         let document = builder.semantic_model.get_document();
@@ -47,107 +49,123 @@ pub fn add_completion(builder: &mut CompletionBuilder) -> Option<()> {
         let range = document.to_lsp_range(replace_range)?;
         (text.to_string(), range)
     };
+    let expr_text = escape_snippet(&left_token_text);
+
+    let user_templates = emmyrc.completion.postfix_templates.clone();
+    for (trigger, body) in BUILTIN_POSTFIX_TEMPLATES {
+        if user_templates
+            .iter()
+            .any(|template| template.trigger == *trigger)
+        {
+            continue;
+        }
+
+        add_postfix_completion(
+            builder,
+            replace_lsp_range,
+            trigger,
+            body.replace("$expr", &expr_text),
+            None,
+        );
+    }
 
-    add_postfix_completion(
-        builder,
-        replace_lsp_range,
-        "if",
-        format!("if {} then\n\t$0\nend", left_token_text),
-    );
-
-    add_postfix_completion(
-        builder,
-        replace_lsp_range,
-        "ifn",
-        format!("if not {} then\n\t$0\nend", left_token_text),
-    );
-
-    add_postfix_completion(
-        builder,
-        replace_lsp_range,
-        "while",
-        format!("while {} do\n\t$0\nend", left_token_text),
-    );
-
-    add_postfix_completion(
-        builder,
-        replace_lsp_range,
-        "forp",
-        format!(
-            "for ${{1:k}}, ${{2:v}} in pairs({}) do\n\t$0\nend",
-            left_token_text
-        ),
-    );
-
-    add_postfix_completion(
-        builder,
-        replace_lsp_range,
-        "forip",
-        format!(
-            "for ${{1:i}}, ${{2:v}} in ipairs({}) do\n\t$0\nend",
-            left_token_text
-        ),
-    );
-
-    add_postfix_completion(
-        builder,
-        replace_lsp_range,
-        "fori",
-        format!("for ${{1:i}} = 1, {} do\n\t$0\nend", left_token_text),
-    );
-
-    add_postfix_completion(
-        builder,
-        replace_lsp_range,
-        "function",
-        format!("function {}(${{1:...}})\n\t$0\nend", left_token_text),
-    );
-
-    add_postfix_completion(
-        builder,
-        replace_lsp_range,
-        "insert",
-        format!("table.insert({}, ${{1:value}})", left_token_text),
-    );
-
-    add_postfix_completion(
-        builder,
-        replace_lsp_range,
-        "remove",
-        format!("table.remove({}, ${{1:index}})", left_token_text),
-    );
-
-    add_postfix_completion(
-        builder,
-        replace_lsp_range,
-        "++",
-        format!("{0} = {0} + 1", left_token_text),
-    );
-
-    add_postfix_completion(
-        builder,
-        replace_lsp_range,
-        "--",
-        format!("{0} = {0} - 1", left_token_text),
-    );
-
-    add_postfix_completion(
-        builder,
-        replace_lsp_range,
-        "+n",
-        format!("{0} = {0} + $1", left_token_text),
-    );
-
-    add_postfix_completion(
-        builder,
-        replace_lsp_range,
-        "-n",
-        format!("{0} = {0} - $1", left_token_text),
-    );
+    // only the templates filtered by type need the expression, the others are still offered
+    let expr = left_token.parent().and_then(LuaExpr::cast);
+    let mut expr_type = None;
+    for template in user_templates {
+        if let Some(filter) = template.expr_type {
+            let Some(expr) = &expr else {
+                continue;
+            };
+            let expr_type = expr_type.get_or_insert_with(|| {
+                builder
+                    .semantic_model
+                    .infer_expr(expr.clone())
+                    .unwrap_or(LuaType::Unknown)
+            });
+            if !is_expr_type_match(builder, expr_type, filter) {
+                continue;
+            }
+        }
+
+        add_postfix_completion(
+            builder,
+            replace_lsp_range,
+            &template.trigger,
+            template.body.replace("$expr", &expr_text),
+            template.description,
+        );
+    }
 
     Some(())
 }
 
+const BUILTIN_POSTFIX_TEMPLATES: &[(&str, &str)] = &[
+    ("if", "if $expr then\n\t$0\nend"),
+    ("ifn", "if not $expr then\n\t$0\nend"),
+    ("while", "while $expr do\n\t$0\nend"),
+    ("forp", "for ${1:k}, ${2:v} in pairs($expr) do\n\t$0\nend"),
+    ("forip", "for ${1:i}, ${2:v} in ipairs($expr) do\n\t$0\nend"),
+    ("fori", "for ${1:i} = 1, $expr do\n\t$0\nend"),
+    ("function", "function $expr(${1:...})\n\t$0\nend"),
+    ("insert", "table.insert($expr, ${1:value})"),
+    ("remove", "table.remove($expr, ${1:index})"),
+    ("++", "$expr = $expr + 1"),
+    ("--", "$expr = $expr - 1"),
+    ("+n", "$expr = $expr + $1"),
+    ("-n", "$expr = $expr - $1"),
+];
+
+// an expression of unknown type matches every filter
+fn is_expr_type_match(
+    builder: &CompletionBuilder,
+    typ: &LuaType,
+    filter: EmmyrcPostfixExprType,
+) -> bool {
+    if filter == EmmyrcPostfixExprType::Nullable {
+        return typ.is_optional();
+    }
+
+    match typ {
+        LuaType::Unknown | LuaType::Any => true,
+        LuaType::Union(union) => union
+            .get_types()
+            .iter()
+            .filter(|typ| !typ.is_nil())
+            .all(|typ| is_expr_type_match(builder, typ, filter)),
+        LuaType::Ref(type_decl_id) | LuaType::Def(type_decl_id) => {
+            let Some(type_decl) = builder
+                .semantic_model
+                .get_db()
+                .get_type_index()
+                .get_type_decl(type_decl_id)
+            else {
+                return false;
+            };
+            if type_decl.is_alias() {
+                return type_decl
+                    .get_alias_origin(builder.semantic_model.get_db(), None)
+                    .is_some_and(|origin| is_expr_type_match(builder, &origin, filter));
+            }
+            filter == EmmyrcPostfixExprType::Table && type_decl.is_class()
+        }
+        LuaType::TableGeneric(params) if filter == EmmyrcPostfixExprType::Array => params
+            .first()
+            .is_some_and(|key| key.is_integer() || key.is_number()),
+        _ => match filter {
+            EmmyrcPostfixExprType::Array => typ.is_array() || typ.is_tuple(),
+            EmmyrcPostfixExprType::Table => typ.is_table(),
+            EmmyrcPostfixExprType::String => typ.is_string(),
+            EmmyrcPostfixExprType::Number => typ.is_number(),
+            EmmyrcPostfixExprType::Integer => typ.is_integer(),
+            EmmyrcPostfixExprType::Boolean => typ.is_boolean(),
+            EmmyrcPostfixExprType::Function => typ.is_function(),
+            // nullable is checked before the match
+            _ => false,
+        },
+    }
+}
+
 fn is_postfix_trigger(trigger_kind: LuaTokenKind, emmyrc: &Emmyrc) -> bool {
     let trigger_string = &emmyrc.completion.postfix;
     if trigger_string.is_empty() {
@@ -168,9 +186,14 @@ fn add_postfix_completion(
     replace_range: Range,
     label: &str,
     text: String,
+    description: Option<String>,
 ) -> Option<()> {
     let item = CompletionItem {
         label: label.to_string(),
+        label_details: description.map(|description| lsp_types::CompletionItemLabelDetails {
+            description: Some(description),
+            ..Default::default()
+        }),
         insert_text: Some(text),
         additional_text_edits: Some(vec![lsp_types::TextEdit {
             range: replace_range,
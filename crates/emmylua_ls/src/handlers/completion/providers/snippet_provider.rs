@@ -0,0 +1,49 @@
+use emmylua_parser::{LuaAstNode, LuaNameExpr, LuaTokenKind};
+use lsp_types::{CompletionItem, CompletionItemLabelDetails, InsertTextFormat, InsertTextMode};
+
+use crate::handlers::completion::{
+    add_completions::check_match_word, completion_builder::CompletionBuilder,
+};
+
+pub fn add_completion(builder: &mut CompletionBuilder) -> Option<()> {
+    if builder.is_cancelled() {
+        return None;
+    }
+
+    if builder.trigger_token.kind() != LuaTokenKind::TkName.into() {
+        return None;
+    }
+    LuaNameExpr::cast(builder.trigger_token.parent()?)?;
+
+    let snippets = builder
+        .semantic_model
+        .get_emmyrc()
+        .completion
+        .snippets
+        .clone();
+    let trigger_text = builder.get_trigger_text();
+    for snippet in snippets {
+        if !check_match_word(&trigger_text, &snippet.trigger) {
+            continue;
+        }
+
+        let item = CompletionItem {
+            label: snippet.trigger,
+            kind: Some(lsp_types::CompletionItemKind::SNIPPET),
+            label_details: snippet
+                .description
+                .map(|description| CompletionItemLabelDetails {
+                    description: Some(description),
+                    ..CompletionItemLabelDetails::default()
+                }),
+            insert_text: Some(snippet.body),
+            insert_text_format: Some(InsertTextFormat::SNIPPET),
+            insert_text_mode: Some(InsertTextMode::ADJUST_INDENTATION),
+            ..CompletionItem::default()
+        };
+
+        builder.add_completion_item(item)?;
+    }
+
+    Some(())
+}
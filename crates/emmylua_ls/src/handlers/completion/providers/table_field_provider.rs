@@ -1,11 +1,11 @@
 use std::collections::HashSet;
 
-use emmylua_code_analysis::{LuaMemberInfo, LuaMemberKey};
+use emmylua_code_analysis::{LuaMemberInfo, LuaMemberKey, LuaSemanticDeclId, LuaType};
 use emmylua_parser::{LuaAst, LuaAstNode, LuaTableExpr, LuaTableField};
-use lsp_types::CompletionItem;
+use lsp_types::{CompletionItem, InsertTextFormat};
 
 use crate::handlers::completion::{
-    add_completions::{check_visibility, is_deprecated, CompletionData},
+    add_completions::{check_visibility, escape_snippet, is_deprecated, CompletionData},
     completion_builder::CompletionBuilder,
 };
 
@@ -24,6 +24,8 @@ pub fn add_completion(builder: &mut CompletionBuilder) -> Option<()> {
         }
     }
 
+    add_fill_fields_completion(builder, &member_infos, &duplicated_set);
+
     for member_info in member_infos {
         if duplicated_set.contains(&member_info.key.to_path()) {
             continue;
@@ -107,3 +109,130 @@ fn add_table_field_completion(
     builder.add_completion_item(completion_item);
     Some(())
 }
+
+/// Insert every missing required field at once, each value is a tab stop with a typed default
+fn add_fill_fields_completion(
+    builder: &mut CompletionBuilder,
+    member_infos: &[LuaMemberInfo],
+    duplicated_set: &HashSet<String>,
+) -> Option<()> {
+    let db = builder.semantic_model.get_db();
+    let mut required_fields = Vec::new();
+    for member_info in member_infos {
+        let LuaMemberKey::Name(name) = &member_info.key else {
+            continue;
+        };
+        let typ = &member_info.typ;
+        // same rule as the `missing-fields` diagnostic, methods are not fields to fill
+        if typ.is_nullable() || typ.is_any() || typ.is_signature() {
+            continue;
+        }
+        if duplicated_set.contains(name.as_str())
+            || required_fields
+                .iter()
+                .any(|(field_name, _, _)| field_name == name)
+        {
+            continue;
+        }
+        if let Some(property_owner) = &member_info.property_owner_id {
+            if !builder
+                .semantic_model
+                .is_semantic_visible(builder.trigger_token.clone(), property_owner.clone())
+            {
+                continue;
+            }
+        }
+
+        let sort_key = match &member_info.property_owner_id {
+            Some(LuaSemanticDeclId::Member(member_id)) => db
+                .get_member_index()
+                .get_member(member_id)
+                .map(|member| member.get_sort_key())
+                .unwrap_or(u64::MAX),
+            _ => u64::MAX,
+        };
+        required_fields.push((name.to_string(), typ.clone(), sort_key));
+    }
+    if required_fields.is_empty() {
+        return None;
+    }
+
+    required_fields.sort_by_key(|(_, _, sort_key)| *sort_key);
+    let fields = required_fields
+        .iter()
+        .enumerate()
+        .map(|(index, (name, typ, _))| {
+            let key = escape_snippet(&get_field_key_text(name));
+            match get_default_value(builder, typ) {
+                Some(value) => {
+                    format!("{} = ${{{}:{}}},", key, index + 1, escape_snippet(value))
+                }
+                None => format!("{} = ${},", key, index + 1),
+            }
+        })
+        .collect::<Vec<_>>();
+    let names = required_fields
+        .iter()
+        .map(|(name, _, _)| name.as_str())
+        .collect::<Vec<_>>();
+
+    let completion_item = CompletionItem {
+        label: "fill required fields".to_string(),
+        kind: Some(lsp_types::CompletionItemKind::SNIPPET),
+        detail: Some(names.join(", ")),
+        insert_text: Some(fields.join("\n")),
+        insert_text_format: Some(InsertTextFormat::SNIPPET),
+        ..Default::default()
+    };
+
+    builder.add_completion_item(completion_item);
+    Some(())
+}
+
+// `---@field ["my-key"] string` is written as `["my-key"] = ...`
+fn get_field_key_text(name: &str) -> String {
+    let mut chars = name.chars();
+    let is_identifier = chars.next().is_some_and(|c| c.is_alphabetic() || c == '_')
+        && chars.all(|c| c.is_alphanumeric() || c == '_')
+        && !LUA_KEYWORDS.contains(&name);
+    if is_identifier {
+        return name.to_string();
+    }
+
+    let escaped = name
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r");
+    format!("[\"{}\"]", escaped)
+}
+
+const LUA_KEYWORDS: &[&str] = &[
+    "and", "break", "do", "else", "elseif", "end", "false", "for", "function", "goto", "if", "in",
+    "local", "nil", "not", "or", "repeat", "return", "then", "true", "until", "while",
+];
+
+fn get_default_value(builder: &CompletionBuilder, typ: &LuaType) -> Option<&'static str> {
+    if typ.is_string() {
+        Some("\"\"")
+    } else if typ.is_number() || typ.is_integer() {
+        Some("0")
+    } else if typ.is_boolean() {
+        Some("false")
+    } else if typ.is_function() {
+        Some("function() end")
+    } else if typ.is_array() || typ.is_tuple() || typ.is_object() || typ.is_table() {
+        Some("{}")
+    } else if let LuaType::Ref(type_decl_id) | LuaType::Def(type_decl_id) = typ {
+        let type_decl = builder
+            .semantic_model
+            .get_db()
+            .get_type_index()
+            .get_type_decl(type_decl_id)?;
+        type_decl.is_class().then_some("{}")
+    } else if let LuaType::Generic(_) = typ {
+        Some("{}")
+    } else {
+        None
+    }
+}
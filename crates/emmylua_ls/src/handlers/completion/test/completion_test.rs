@@ -1,13 +1,20 @@
 #[cfg(test)]
 mod tests {
 
+    use std::sync::Arc;
+
+    use emmylua_code_analysis::{
+        Emmyrc, EmmyrcPostfixExprType, EmmyrcPostfixTemplate, EmmyrcSnippet,
+    };
     use lsp_types::{CompletionItemKind, CompletionTriggerKind};
 
-    use crate::handlers::completion::test::{CompletionVirtualWorkspace, VirtualCompletionItem};
+    use crate::handlers::{
+        completion::test::VirtualCompletionItem, test_lib::ProviderVirtualWorkspace,
+    };
 
     #[test]
     fn test_1() {
-        let mut ws = CompletionVirtualWorkspace::new();
+        let mut ws = ProviderVirtualWorkspace::new();
 
         assert!(ws.check_completion(
             r#"
@@ -23,7 +30,7 @@ mod tests {
 
     #[test]
     fn test_2() {
-        let mut ws = CompletionVirtualWorkspace::new();
+        let mut ws = ProviderVirtualWorkspace::new();
         assert!(ws.check_completion(
             r#"
             ---@overload fun(event: "AAA", callback: fun(trg: string, data: number)): number
@@ -101,7 +108,7 @@ mod tests {
 
     #[test]
     fn test_3() {
-        let mut ws = CompletionVirtualWorkspace::new();
+        let mut ws = ProviderVirtualWorkspace::new();
         // 被动触发补全
         assert!(ws.check_completion_with_kind(
             r#"
@@ -163,4 +170,320 @@ mod tests {
             },],
         ));
     }
+
+    #[test]
+    fn test_fill_required_fields() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let items = ws
+            .get_completion_items(
+                r#"
+                ---@class Point
+                ---@field x number
+                ---@field y number
+                ---@field name string
+                ---@field visible boolean
+                ---@field tags string[]
+                ---@field label? string
+
+                ---@type Point
+                local p = { y = 1, <??> }
+            "#,
+            )
+            .unwrap();
+        let item = items
+            .iter()
+            .find(|item| item.label == "fill required fields")
+            .unwrap();
+        assert_eq!(
+            item.insert_text.as_deref(),
+            Some("x = ${1:0},\nname = ${2:\"\"},\nvisible = ${3:false},\ntags = ${4:{\\}},")
+        );
+    }
+
+    #[test]
+    fn test_fill_required_fields_with_quoted_keys() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let items = ws
+            .get_completion_items(
+                r#"
+                ---@class Headers
+                ---@field ["my-key"] string
+                ---@field ["$ref"] number
+                ---@field ["end"] boolean
+
+                ---@type Headers
+                local h = { <??> }
+            "#,
+            )
+            .unwrap();
+        let item = items
+            .iter()
+            .find(|item| item.label == "fill required fields")
+            .unwrap();
+        assert_eq!(
+            item.insert_text.as_deref(),
+            Some("[\"my-key\"] = ${1:\"\"},\n[\"\\$ref\"] = ${2:0},\n[\"end\"] = ${3:false},")
+        );
+    }
+
+    #[test]
+    fn test_overload_call_snippet() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let mut emmyrc = Emmyrc::default();
+        emmyrc.completion.call_snippet = true;
+        ws.analysis.update_config(Arc::new(emmyrc));
+
+        let items = ws
+            .get_completion_items(
+                r#"
+                ---@overload fun(name: string): number
+                ---@overload fun(id: integer, force: boolean): number
+                local function find(key, opts)
+                end
+
+                fin<??>
+            "#,
+            )
+            .unwrap();
+        let snippets = items
+            .iter()
+            .filter(|item| item.label == "find")
+            .map(|item| item.insert_text.clone().unwrap_or_default())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            snippets,
+            vec![
+                "find(${1:key}, ${2:opts})",
+                "find(${1:name})",
+                "find(${1:id}, ${2:force})",
+            ]
+        );
+
+        let items = ws
+            .get_completion_items(
+                r#"
+                ---@class Loader
+                local Loader = {}
+
+                ---@overload fun(self: Loader, path: string)
+                function Loader:load(path, mode)
+                end
+
+                Loader:<??>
+            "#,
+            )
+            .unwrap();
+        let snippets = items
+            .iter()
+            .filter(|item| item.label == "load")
+            .map(|item| item.insert_text.clone().unwrap_or_default())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            snippets,
+            vec!["load(${1:path}, ${2:mode})", "load(${1:path})"]
+        );
+    }
+
+    #[test]
+    fn test_auto_require_module_member() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        ws.def_file(
+            "util/json.lua",
+            r#"
+                local M = {}
+
+                function M.encodeJson(value)
+                end
+
+                return M
+            "#,
+        );
+
+        let items = ws.get_completion_items("encodeJ<??>").unwrap();
+        let item = items
+            .iter()
+            .find(|item| item.label == "encodeJson")
+            .unwrap();
+        assert_eq!(item.insert_text.as_deref(), Some("json.encodeJson"));
+        assert!(item.command.is_some());
+
+        let items = ws
+            .get_completion_items(
+                r#"
+                local JSON = require("util.json")
+                encodeJ<??>
+            "#,
+            )
+            .unwrap();
+        let item = items
+            .iter()
+            .find(|item| item.label == "encodeJson")
+            .unwrap();
+        assert_eq!(item.insert_text.as_deref(), Some("JSON.encodeJson"));
+        assert!(item.command.is_none());
+
+        let mut emmyrc = Emmyrc::default();
+        emmyrc.runtime.require_like_function = vec!["import".to_string()];
+        ws.analysis.update_config(Arc::new(emmyrc));
+        let items = ws
+            .get_completion_items(
+                r#"
+                local Json = import("util.json")
+                encodeJ<??>
+            "#,
+            )
+            .unwrap();
+        let item = items
+            .iter()
+            .find(|item| item.label == "encodeJson")
+            .unwrap();
+        assert_eq!(item.insert_text.as_deref(), Some("Json.encodeJson"));
+        assert!(item.command.is_none());
+    }
+
+    #[test]
+    fn test_auto_require_class() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        ws.def_file(
+            "ui/button_widget.lua",
+            r#"
+                ---@class ButtonWidget
+                ---@field newLabel string
+                local ButtonWidget = {}
+
+                function ButtonWidget.newButton()
+                end
+
+                function ButtonWidget:newDraw()
+                end
+
+                return ButtonWidget
+            "#,
+        );
+
+        let items = ws.get_completion_items("ButtonW<??>").unwrap();
+        let class_items = items
+            .iter()
+            .filter(|item| item.label == "ButtonWidget")
+            .collect::<Vec<_>>();
+        assert_eq!(class_items.len(), 1);
+        assert_eq!(class_items[0].kind, Some(CompletionItemKind::CLASS));
+        let args = class_items[0]
+            .command
+            .as_ref()
+            .unwrap()
+            .arguments
+            .as_ref()
+            .unwrap();
+        assert_eq!(args[3], "ButtonWidget");
+
+        // the members are bound to the same local as the class
+        let items = ws.get_completion_items("newBut<??>").unwrap();
+        let item = items.iter().find(|item| item.label == "newButton").unwrap();
+        assert_eq!(item.insert_text.as_deref(), Some("ButtonWidget.newButton"));
+
+        // instance fields and methods are not offered on the class
+        let items = ws.get_completion_items("new<??>").unwrap();
+        assert!(!items
+            .iter()
+            .any(|item| item.label == "newLabel" || item.label == "newDraw"));
+    }
+
+    #[test]
+    fn test_user_postfix_templates() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let mut emmyrc = Emmyrc::default();
+        emmyrc.completion.postfix_templates = vec![
+            EmmyrcPostfixTemplate {
+                trigger: "log".to_string(),
+                body: "Log.debug($expr)".to_string(),
+                description: None,
+                expr_type: None,
+            },
+            EmmyrcPostfixTemplate {
+                trigger: "ipairs".to_string(),
+                body: "for ${1:i}, ${2:v} in ipairs($expr) do\n\t$0\nend".to_string(),
+                description: None,
+                expr_type: Some(EmmyrcPostfixExprType::Array),
+            },
+        ];
+        ws.analysis.update_config(Arc::new(emmyrc));
+
+        let items = ws
+            .get_completion_items(
+                r#"
+                ---@type string[]
+                local list
+                list@<??>
+            "#,
+            )
+            .unwrap();
+        let log = items.iter().find(|item| item.label == "log").unwrap();
+        assert_eq!(log.insert_text.as_deref(), Some("Log.debug(list)"));
+        assert!(items.iter().any(|item| item.label == "ipairs"));
+
+        let items = ws
+            .get_completion_items(
+                r#"
+                ---@type string
+                local name
+                name@<??>
+            "#,
+            )
+            .unwrap();
+        assert!(items.iter().any(|item| item.label == "log"));
+        assert!(!items.iter().any(|item| item.label == "ipairs"));
+    }
+
+    #[test]
+    fn test_user_postfix_templates_without_expr() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let mut emmyrc = Emmyrc::default();
+        emmyrc.completion.postfix_templates = vec![
+            EmmyrcPostfixTemplate {
+                trigger: "ipairs".to_string(),
+                body: "for ${1:i}, ${2:v} in ipairs($expr) do\n\t$0\nend".to_string(),
+                description: None,
+                expr_type: Some(EmmyrcPostfixExprType::Array),
+            },
+            EmmyrcPostfixTemplate {
+                trigger: "log".to_string(),
+                body: "Log.debug($expr)".to_string(),
+                description: None,
+                expr_type: None,
+            },
+        ];
+        ws.analysis.update_config(Arc::new(emmyrc));
+
+        // the token before the trigger does not belong to an expression
+        let items = ws
+            .get_completion_items(
+                r#"
+                print(1)@<??>
+            "#,
+            )
+            .unwrap();
+        assert!(items.iter().any(|item| item.label == "log"));
+        assert!(!items.iter().any(|item| item.label == "ipairs"));
+    }
+
+    #[test]
+    fn test_user_snippets() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let mut emmyrc = Emmyrc::default();
+        emmyrc.completion.snippets = vec![EmmyrcSnippet {
+            trigger: "pcallerr".to_string(),
+            body: "local ok, err = pcall($0)".to_string(),
+            description: Some("protected call".to_string()),
+        }];
+        ws.analysis.update_config(Arc::new(emmyrc));
+
+        let items = ws.get_completion_items("pcaller<??>").unwrap();
+        let item = items.iter().find(|item| item.label == "pcallerr").unwrap();
+        assert_eq!(item.kind, Some(CompletionItemKind::SNIPPET));
+        assert_eq!(
+            item.insert_text.as_deref(),
+            Some("local ok, err = pcall($0)")
+        );
+    }
 }
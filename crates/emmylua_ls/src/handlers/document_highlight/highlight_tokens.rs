@@ -104,6 +104,7 @@ fn is_keyword(kind: LuaTokenKind) -> bool {
         | LuaTokenKind::TkEnd
         | LuaTokenKind::TkFor
         | LuaTokenKind::TkFunction
+        | LuaTokenKind::TkGlobal
         | LuaTokenKind::TkGoto
         | LuaTokenKind::TkIf
         | LuaTokenKind::TkIn
@@ -1,7 +1,8 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use emmylua_code_analysis::{
-    DbIndex, FileId, LuaDecl, LuaDeclId, LuaDeclarationTree, LuaDocument, LuaType,
+    DbIndex, FileId, LuaDecl, LuaDeclId, LuaDeclarationTree, LuaDocument, LuaMemberId,
+    LuaMemberOwner, LuaType, LuaTypeDeclId,
 };
 use emmylua_parser::{LuaAstNode, LuaChunk, LuaSyntaxId, LuaSyntaxNode, LuaSyntaxToken};
 use lsp_types::{DocumentSymbol, SymbolKind};
@@ -12,6 +13,9 @@ pub struct DocumentSymbolBuilder<'a> {
     decl_tree: &'a LuaDeclarationTree,
     document: &'a LuaDocument<'a>,
     document_symbols: HashMap<LuaSyntaxId, Box<LuaSymbol>>,
+    type_decl_symbols: HashMap<LuaTypeDeclId, LuaSyntaxId>,
+    // 已由其他符号表示的节点, 例如`---@enum`所标注的表
+    skipped_nodes: HashSet<LuaSyntaxId>,
 }
 
 impl<'a> DocumentSymbolBuilder<'a> {
@@ -25,9 +29,15 @@ impl<'a> DocumentSymbolBuilder<'a> {
             decl_tree,
             document,
             document_symbols: HashMap::new(),
+            type_decl_symbols: HashMap::new(),
+            skipped_nodes: HashSet::new(),
         }
     }
 
+    pub fn get_db(&self) -> &DbIndex {
+        self.db
+    }
+
     pub fn get_file_id(&self) -> FileId {
         self.document.get_file_id()
     }
@@ -51,6 +61,74 @@ impl<'a> DocumentSymbolBuilder<'a> {
         }
     }
 
+    /// 添加类型声明的符号, 其成员可以通过`add_member_node_symbol`挂载到该符号下
+    pub fn add_type_decl_symbol(
+        &mut self,
+        type_decl_id: LuaTypeDeclId,
+        node: LuaSyntaxNode,
+        symbol: LuaSymbol,
+    ) {
+        let syntax_id = LuaSyntaxId::from_node(&node);
+        self.add_node_symbol(node, symbol);
+        self.type_decl_symbols.insert(type_decl_id, syntax_id);
+    }
+
+    fn get_member_type_symbol(&self, member_id: &LuaMemberId) -> Option<LuaSyntaxId> {
+        let member = self.db.get_member_index().get_member(member_id)?;
+        match member.get_owner() {
+            LuaMemberOwner::Type(type_decl_id) => {
+                self.type_decl_symbols.get(&type_decl_id).copied()
+            }
+            _ => None,
+        }
+    }
+
+    pub fn is_type_decl_member(&self, member_id: &LuaMemberId) -> bool {
+        self.get_member_type_symbol(member_id).is_some()
+    }
+
+    /// 若`member_id`属于本文件中声明的类型, 则将符号挂载到该类型的符号下, 否则按语法结构挂载
+    pub fn add_member_node_symbol(
+        &mut self,
+        member_id: LuaMemberId,
+        node: LuaSyntaxNode,
+        symbol: LuaSymbol,
+    ) {
+        match self.get_member_type_symbol(&member_id) {
+            Some(type_symbol_id) => self.add_child_symbol(type_symbol_id, node, symbol),
+            None => self.add_node_symbol(node, symbol),
+        }
+    }
+
+    /// 将符号挂载到本文件中声明的类型的符号下
+    pub fn add_type_decl_child_symbol(
+        &mut self,
+        type_decl_id: &LuaTypeDeclId,
+        node: LuaSyntaxNode,
+        symbol: LuaSymbol,
+    ) -> Option<()> {
+        let type_symbol_id = *self.type_decl_symbols.get(type_decl_id)?;
+        self.add_child_symbol(type_symbol_id, node, symbol);
+        Some(())
+    }
+
+    // 类型的符号保持其声明的范围, 成员可能位于该范围之外
+    fn add_child_symbol(&mut self, parent_id: LuaSyntaxId, node: LuaSyntaxNode, symbol: LuaSymbol) {
+        let syntax_id = LuaSyntaxId::from_node(&node);
+        self.document_symbols.insert(syntax_id, Box::new(symbol));
+        if let Some(parent_symbol) = self.document_symbols.get_mut(&parent_id) {
+            parent_symbol.add_child(syntax_id);
+        }
+    }
+
+    pub fn skip_node(&mut self, node: &LuaSyntaxNode) {
+        self.skipped_nodes.insert(LuaSyntaxId::from_node(node));
+    }
+
+    pub fn is_skipped(&self, node: &LuaSyntaxNode) -> bool {
+        self.skipped_nodes.contains(&LuaSyntaxId::from_node(node))
+    }
+
     pub fn add_token_symbol(&mut self, token: LuaSyntaxToken, symbol: LuaSymbol) {
         let syntax_id = LuaSyntaxId::new(token.kind().into(), token.text_range());
         self.document_symbols.insert(syntax_id, Box::new(symbol));
@@ -0,0 +1,128 @@
+use emmylua_code_analysis::{humanize_type, LuaMemberId, LuaMemberKey, RenderLevel};
+use emmylua_parser::{
+    LuaAst, LuaAstNode, LuaComment, LuaDocTag, LuaDocTagAlias, LuaDocTagClass, LuaDocTagEnum,
+    LuaExpr, LuaIndexKey, LuaSyntaxNode, LuaTableExpr,
+};
+use lsp_types::SymbolKind;
+
+use super::builder::{DocumentSymbolBuilder, LuaSymbol};
+
+pub fn build_doc_class_symbol(
+    builder: &mut DocumentSymbolBuilder,
+    class: LuaDocTagClass,
+) -> Option<()> {
+    let file_id = builder.get_file_id();
+    let name = class.get_name_token()?.get_name_text().to_string();
+    let type_decl_id = builder
+        .get_db()
+        .get_type_index()
+        .find_type_decl(file_id, &name)?
+        .get_id();
+    let detail = class
+        .get_supers()
+        .map(|supers| supers.syntax().text().to_string());
+    let symbol = LuaSymbol::new(name, detail, SymbolKind::CLASS, class.get_range());
+    builder.add_type_decl_symbol(type_decl_id, class.syntax().clone(), symbol);
+
+    // 紧随`---@class`的`---@field`属于该类
+    let comment = class.get_parent::<LuaComment>()?;
+    let mut is_current_class = false;
+    for tag in comment.get_doc_tags() {
+        match tag {
+            LuaDocTag::Class(tag) => is_current_class = tag == class,
+            LuaDocTag::Field(field) if is_current_class => {
+                let member_id = LuaMemberId::new(field.get_syntax_id(), file_id);
+                let Some(member) = builder.get_db().get_member_index().get_member(&member_id)
+                else {
+                    continue;
+                };
+                let name = match member.get_key() {
+                    LuaMemberKey::Name(name) => name.to_string(),
+                    LuaMemberKey::Integer(i) => format!("[{}]", i),
+                    _ => continue,
+                };
+                let typ = member.get_decl_type();
+                let kind = if typ.is_function() {
+                    SymbolKind::METHOD
+                } else {
+                    SymbolKind::FIELD
+                };
+                let detail = humanize_type(builder.get_db(), &typ, RenderLevel::Simple);
+                let symbol = LuaSymbol::new(name, Some(detail), kind, field.get_range());
+                builder.add_member_node_symbol(member_id, field.syntax().clone(), symbol);
+            }
+            _ => {}
+        }
+    }
+
+    Some(())
+}
+
+pub fn build_doc_alias_symbol(
+    builder: &mut DocumentSymbolBuilder,
+    alias: LuaDocTagAlias,
+) -> Option<()> {
+    let name = alias.get_name_token()?.get_name_text().to_string();
+    let detail = alias.get_type().map(|typ| typ.syntax().text().to_string());
+    let symbol = LuaSymbol::new(name, detail, SymbolKind::TYPE_PARAMETER, alias.get_range());
+    builder.add_node_symbol(alias.syntax().clone(), symbol);
+    Some(())
+}
+
+pub fn build_doc_enum_symbol(
+    builder: &mut DocumentSymbolBuilder,
+    enum_tag: LuaDocTagEnum,
+) -> Option<()> {
+    let file_id = builder.get_file_id();
+    let name = enum_tag.get_name_token()?.get_name_text().to_string();
+    let type_decl_id = builder
+        .get_db()
+        .get_type_index()
+        .find_type_decl(file_id, &name)?
+        .get_id();
+    let detail = enum_tag
+        .get_base_type()
+        .map(|typ| typ.syntax().text().to_string());
+    let symbol = LuaSymbol::new(name, detail, SymbolKind::ENUM, enum_tag.get_range());
+    builder.add_type_decl_symbol(type_decl_id.clone(), enum_tag.syntax().clone(), symbol);
+
+    // 被标注的表的字段即枚举成员, 该变量与表不再单独显示
+    let comment = enum_tag.get_parent::<LuaComment>()?;
+    let (name_node, table) = get_enum_table(comment.get_owner()?)?;
+    builder.skip_node(&name_node);
+    builder.skip_node(table.syntax());
+    for field in table.get_fields() {
+        let name = match field.get_field_key()? {
+            LuaIndexKey::Name(key) => key.get_name_text().to_string(),
+            LuaIndexKey::String(key) => key.get_value(),
+            LuaIndexKey::Integer(i) => format!("[{}]", i.get_int_value()),
+            _ => continue,
+        };
+        let detail = field
+            .get_value_expr()
+            .map(|value| value.syntax().text().to_string());
+        let symbol = LuaSymbol::new(name, detail, SymbolKind::ENUM_MEMBER, field.get_range());
+        builder.add_type_decl_child_symbol(&type_decl_id, field.syntax().clone(), symbol);
+    }
+
+    Some(())
+}
+
+/// `---@enum`所标注的语句中的变量名与表
+fn get_enum_table(owner: LuaAst) -> Option<(LuaSyntaxNode, LuaTableExpr)> {
+    let (name_node, value) = match owner {
+        LuaAst::LuaLocalStat(local_stat) => (
+            local_stat.get_local_name_list().next()?.syntax().clone(),
+            local_stat.get_value_exprs().next()?,
+        ),
+        LuaAst::LuaAssignStat(assign_stat) => {
+            let (vars, exprs) = assign_stat.get_var_and_expr_list();
+            (vars.first()?.syntax().clone(), exprs.first()?.clone())
+        }
+        _ => return None,
+    };
+    match value {
+        LuaExpr::TableExpr(table) => Some((name_node, table)),
+        _ => None,
+    }
+}
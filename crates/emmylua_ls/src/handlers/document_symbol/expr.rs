@@ -1,5 +1,7 @@
-use emmylua_code_analysis::LuaDeclId;
-use emmylua_parser::{LuaAstNode, LuaClosureExpr, LuaIndexKey, LuaSyntaxKind, LuaTableExpr};
+use emmylua_code_analysis::{LuaDeclId, LuaMemberId};
+use emmylua_parser::{
+    LuaAstNode, LuaClosureExpr, LuaExpr, LuaIndexKey, LuaSyntaxKind, LuaTableExpr, LuaTableField,
+};
 use lsp_types::SymbolKind;
 
 use super::builder::{DocumentSymbolBuilder, LuaSymbol};
@@ -9,9 +11,13 @@ pub fn build_closure_expr_symbol(
     closure: LuaClosureExpr,
 ) -> Option<()> {
     let parent = closure.syntax().parent()?;
+    // 表字段的函数值, 其参数直接挂载到字段下
     if !matches!(
         parent.kind().into(),
-        LuaSyntaxKind::LocalFuncStat | LuaSyntaxKind::FuncStat
+        LuaSyntaxKind::LocalFuncStat
+            | LuaSyntaxKind::FuncStat
+            | LuaSyntaxKind::TableFieldAssign
+            | LuaSyntaxKind::TableFieldValue
     ) {
         let symbol = LuaSymbol::new(
             "closure".to_string(),
@@ -43,16 +49,24 @@ pub fn build_closure_expr_symbol(
 }
 
 pub fn build_table_symbol(builder: &mut DocumentSymbolBuilder, table: LuaTableExpr) -> Option<()> {
-    let symbol = LuaSymbol::new(
-        "table".to_string(),
-        None,
-        SymbolKind::STRUCT,
-        table.get_range(),
-    );
+    if builder.is_skipped(table.syntax()) {
+        return None;
+    }
 
-    builder.add_node_symbol(table.syntax().clone(), symbol);
+    // 嵌套的表构造, 其字段直接挂载到外层字段下
+    if table.get_parent::<LuaTableField>().is_none() {
+        let symbol = LuaSymbol::new(
+            "table".to_string(),
+            None,
+            SymbolKind::STRUCT,
+            table.get_range(),
+        );
+
+        builder.add_node_symbol(table.syntax().clone(), symbol);
+    }
 
     if table.is_object() {
+        let file_id = builder.get_file_id();
         for field in table.get_fields() {
             let key = field.get_field_key()?;
             let str_key = match key {
@@ -62,7 +76,23 @@ pub fn build_table_symbol(builder: &mut DocumentSymbolBuilder, table: LuaTableEx
                 _ => continue,
             };
 
-            let symbol = LuaSymbol::new(str_key, None, SymbolKind::FIELD, field.get_range());
+            let (kind, detail) = match field.get_value_expr() {
+                Some(LuaExpr::TableExpr(_)) => (SymbolKind::OBJECT, None),
+                Some(LuaExpr::ClosureExpr(_)) => {
+                    let member_id = LuaMemberId::new(field.get_syntax_id(), file_id);
+                    let typ = builder
+                        .get_db()
+                        .get_member_index()
+                        .get_member(&member_id)
+                        .map(|member| member.get_decl_type());
+                    (
+                        SymbolKind::METHOD,
+                        builder.get_symbol_kind_and_detail(typ.as_ref()).1,
+                    )
+                }
+                _ => (SymbolKind::FIELD, None),
+            };
+            let symbol = LuaSymbol::new(str_key, detail, kind, field.get_range());
 
             builder.add_node_symbol(field.syntax().clone(), symbol);
         }
@@ -1,8 +1,11 @@
 mod builder;
+mod doc;
 mod expr;
 mod stats;
+mod test;
 
 use builder::{DocumentSymbolBuilder, LuaSymbol};
+use doc::{build_doc_alias_symbol, build_doc_class_symbol, build_doc_enum_symbol};
 use emmylua_code_analysis::SemanticModel;
 use emmylua_parser::{LuaAst, LuaAstNode, LuaChunk};
 use expr::{build_closure_expr_symbol, build_table_symbol};
@@ -91,6 +94,15 @@ fn build_child_document_symbols(
             LuaAst::LuaIfStat(if_stat) => {
                 build_if_stat_symbol(builder, if_stat);
             }
+            LuaAst::LuaDocTagClass(class) => {
+                build_doc_class_symbol(builder, class);
+            }
+            LuaAst::LuaDocTagAlias(alias) => {
+                build_doc_alias_symbol(builder, alias);
+            }
+            LuaAst::LuaDocTagEnum(enum_tag) => {
+                build_doc_enum_symbol(builder, enum_tag);
+            }
             _ => {}
         }
     }
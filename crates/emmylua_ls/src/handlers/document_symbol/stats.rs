@@ -1,7 +1,8 @@
-use emmylua_code_analysis::{LuaDeclId, LuaSignatureId, LuaType};
+use emmylua_code_analysis::{LuaDeclId, LuaMemberId, LuaSignatureId, LuaType};
 use emmylua_parser::{
     LuaAssignStat, LuaAstNode, LuaAstToken, LuaForRangeStat, LuaForStat, LuaFuncStat,
-    LuaIfClauseStat, LuaIfStat, LuaLocalFuncStat, LuaLocalStat,
+    LuaIfClauseStat, LuaIfStat, LuaIndexExpr, LuaIndexKey, LuaLocalFuncStat, LuaLocalStat,
+    LuaVarExpr,
 };
 use lsp_types::SymbolKind;
 
@@ -16,6 +17,10 @@ pub fn build_local_stat_symbol(
     let simple_local = local_names.len() == 1;
 
     for local_name in local_names {
+        if builder.is_skipped(local_name.syntax()) {
+            continue;
+        }
+
         let decl_id = LuaDeclId::new(file_id, local_name.get_position());
         let decl = builder.get_decl(&decl_id)?;
         let desc = builder.get_symbol_kind_and_detail(decl.get_type());
@@ -41,10 +46,20 @@ pub fn build_assign_stat_symbol(
     let (vars, _) = assign_stat.get_var_and_expr_list();
     let simple_var = vars.len() == 1;
     for var in vars {
+        if builder.is_skipped(var.syntax()) {
+            continue;
+        }
+
         let decl_id = LuaDeclId::new(file_id, var.get_position());
         let decl = match builder.get_decl(&decl_id) {
             Some(decl) => decl,
-            None => continue,
+            None => {
+                // 对类成员的赋值, 例如`Player.name = "x"`
+                if let LuaVarExpr::IndexExpr(index_expr) = &var {
+                    build_type_member_assign_symbol(builder, index_expr.clone());
+                }
+                continue;
+            }
         };
         let range = if simple_var {
             assign_stat.get_range()
@@ -61,6 +76,37 @@ pub fn build_assign_stat_symbol(
     Some(())
 }
 
+fn build_type_member_assign_symbol(
+    builder: &mut DocumentSymbolBuilder,
+    index_expr: LuaIndexExpr,
+) -> Option<()> {
+    let member_id = LuaMemberId::new(index_expr.get_syntax_id(), builder.get_file_id());
+    if !builder.is_type_decl_member(&member_id) {
+        return None;
+    }
+
+    let name = match index_expr.get_index_key()? {
+        LuaIndexKey::Name(name) => name.get_name_text().to_string(),
+        LuaIndexKey::String(s) => s.get_value(),
+        LuaIndexKey::Integer(i) => format!("[{}]", i.get_int_value()),
+        _ => return None,
+    };
+    let typ = builder
+        .get_db()
+        .get_member_index()
+        .get_member(&member_id)?
+        .get_decl_type();
+    let desc = builder.get_symbol_kind_and_detail(Some(&typ));
+    let kind = if desc.0 == SymbolKind::FUNCTION {
+        SymbolKind::METHOD
+    } else {
+        SymbolKind::FIELD
+    };
+    let symbol = LuaSymbol::new(name, desc.1, kind, index_expr.get_range());
+    builder.add_member_node_symbol(member_id, index_expr.syntax().clone(), symbol);
+    Some(())
+}
+
 pub fn build_for_stat_symbol(
     builder: &mut DocumentSymbolBuilder,
     for_stat: LuaForStat,
@@ -152,8 +198,22 @@ pub fn build_func_stat_symbol(
     let signature_id = LuaSignatureId::from_closure(file_id, &closure);
     let func_ty = LuaType::Signature(signature_id);
     let desc = builder.get_symbol_kind_and_detail(Some(&func_ty));
-    let symbol = LuaSymbol::new(name, desc.1, desc.0, func.get_range());
 
+    // 类的方法挂载到类的符号下
+    if let LuaVarExpr::IndexExpr(index_expr) = func_name {
+        let member_id = LuaMemberId::new(index_expr.get_syntax_id(), file_id);
+        if builder.is_type_decl_member(&member_id) {
+            let name = match index_expr.get_index_key()? {
+                LuaIndexKey::Name(name) => name.get_name_text().to_string(),
+                _ => name,
+            };
+            let symbol = LuaSymbol::new(name, desc.1, SymbolKind::METHOD, func.get_range());
+            builder.add_member_node_symbol(member_id, func.syntax().clone(), symbol);
+            return Some(());
+        }
+    }
+
+    let symbol = LuaSymbol::new(name, desc.1, desc.0, func.get_range());
     builder.add_node_symbol(func.syntax().clone(), symbol);
     Some(())
 }
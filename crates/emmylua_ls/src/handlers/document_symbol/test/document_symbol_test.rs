@@ -0,0 +1,75 @@
+#[cfg(test)]
+mod tests {
+    use crate::handlers::{document_symbol::test::dump_symbol, test_lib::ProviderVirtualWorkspace};
+
+    #[test]
+    fn test_annotation_and_nested_symbols() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let symbol = ws
+            .get_document_symbol(
+                r#"
+                local M = {}
+
+                ---@class Player: Base
+                ---@field name string
+                ---@field hp integer
+                M.Player = {}
+
+                function M.Player:update()
+                    local a = 1
+                end
+
+                M.Player.speed = 1
+
+                ---@alias Id integer
+
+                ---@enum Color
+                local Color = { Red = 1 }
+
+                local config = {
+                    window = {
+                        width = 1,
+                        title = { text = "x" },
+                    },
+                    on_close = function(reason) end,
+                }
+                "#,
+            )
+            .unwrap();
+        let mut out = String::new();
+        dump_symbol(&symbol, 0, &mut out);
+        let expected = r#"virtual_0.lua: File
+  M: Object
+  table: Struct
+  Player: Class
+    name: Field
+    hp: Field
+    update: Method
+      a: Number
+    speed: Field
+  table: Struct
+  Id: TypeParameter
+  Color: Enum
+    Red: EnumMember
+  config: Object
+  table: Struct
+    window: Object
+      width: Field
+      title: Object
+        text: Field
+    on_close: Method
+      reason: Variable
+"#;
+        assert_eq!(out, expected);
+
+        // 类的符号保持`---@class`的范围, 不因成员而扩大
+        let player = symbol
+            .children
+            .iter()
+            .flatten()
+            .find(|child| child.name == "Player")
+            .unwrap();
+        assert_eq!(player.range.start.line, 3);
+        assert_eq!(player.range.end.line, 3);
+    }
+}
@@ -0,0 +1,28 @@
+use lsp_types::DocumentSymbol;
+
+mod document_symbol_test;
+use super::build_document_symbol;
+use crate::handlers::test_lib::ProviderVirtualWorkspace;
+
+#[allow(unused)]
+impl ProviderVirtualWorkspace {
+    pub fn get_document_symbol(&mut self, content: &str) -> Option<DocumentSymbol> {
+        let file_id = self.def(content);
+        let semantic_model = self.analysis.compilation.get_semantic_model(file_id)?;
+        build_document_symbol(&semantic_model)
+    }
+}
+
+/// 以缩进的`name: kind`形式输出符号树, 便于断言
+#[allow(unused)]
+fn dump_symbol(symbol: &DocumentSymbol, depth: usize, out: &mut String) {
+    out.push_str(&format!(
+        "{}{}: {:?}\n",
+        "  ".repeat(depth),
+        symbol.name,
+        symbol.kind
+    ));
+    for child in symbol.children.iter().flatten() {
+        dump_symbol(child, depth + 1, out);
+    }
+}
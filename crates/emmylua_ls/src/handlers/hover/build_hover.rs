@@ -8,7 +8,9 @@ use lsp_types::{Hover, HoverContents, MarkedString, MarkupContent};
 
 use emmylua_code_analysis::humanize_type;
 
-use crate::handlers::hover::hover_humanize::hover_type;
+use crate::handlers::hover::hover_humanize::{
+    expand_alias_type, hover_type, MAX_ALIAS_EXPAND_DEPTH,
+};
 
 use super::{
     hover_builder::HoverBuilder,
@@ -273,6 +275,7 @@ fn build_type_decl_hover(
     let type_decl = db.get_type_index().get_type_decl(&type_decl_id)?;
     let type_description = if type_decl.is_alias() {
         if let Some(origin) = type_decl.get_alias_origin(db, None) {
+            let origin = expand_alias_type(db, &origin, MAX_ALIAS_EXPAND_DEPTH);
             let origin_type = humanize_type(db, &origin, RenderLevel::Detailed);
             format!("(alias) {} = {}", type_decl.get_name(), origin_type)
         } else {
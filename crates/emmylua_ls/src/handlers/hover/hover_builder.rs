@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use emmylua_code_analysis::{
     LuaFunctionType, LuaMember, LuaMemberOwner, LuaSemanticDeclId, LuaType, SemanticDeclLevel,
     SemanticModel,
@@ -227,6 +229,33 @@ impl<'a> HoverBuilder<'a> {
         None
     }
 
+    /// 若悬停位置为泛型函数调用的前缀, 则从`funcs`中选出与实参最匹配的签名并返回其实例化结果
+    pub fn get_generic_call_signature(
+        &self,
+        funcs: &[Arc<LuaFunctionType>],
+    ) -> Option<LuaFunctionType> {
+        if self.is_completion {
+            return None;
+        }
+        let token = self.trigger_token.clone()?;
+        let call_expr = LuaCallExpr::cast(token.parent()?.parent()?)?;
+        if call_expr.get_prefix_expr()?.syntax() != &token.parent()? {
+            return None;
+        }
+        let idx = self
+            .semantic_model
+            .resolve_call_overload(funcs, call_expr.clone(), None)?;
+        let func = funcs.get(idx)?;
+        if !func.contain_tpl() {
+            return None;
+        }
+        let instantiated = self.semantic_model.instantiate_call_func(func, call_expr)?;
+        if instantiated == **func {
+            return None;
+        }
+        Some(instantiated)
+    }
+
     /// 推断前缀是否为全局定义, 如果是, 则返回全局名称, 否则返回 None
     pub fn infer_prefix_global_name(&self, member: &LuaMember) -> Option<&str> {
         let root = self
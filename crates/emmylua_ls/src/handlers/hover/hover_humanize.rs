@@ -1,8 +1,9 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, sync::Arc};
 
 use emmylua_code_analysis::{
-    DbIndex, LuaDocReturnInfo, LuaFunctionType, LuaMember, LuaMemberKey, LuaMemberOwner,
-    LuaMultiLineUnion, LuaSignature, LuaSignatureId, LuaType, LuaUnionType, RenderLevel,
+    DbIndex, LuaDocReturnInfo, LuaFunctionType, LuaGenericType, LuaMember, LuaMemberKey,
+    LuaMemberOwner, LuaMultiLineUnion, LuaSignature, LuaSignatureId, LuaTupleType, LuaType,
+    LuaUnionType, RenderLevel, TypeSubstitutor,
 };
 
 use emmylua_code_analysis::humanize_type;
@@ -53,7 +54,18 @@ pub fn hover_function_type(
         }),
         LuaType::Union(union) => {
             // 泛型处理
-            if let Some(call) = builder.get_call_signature() {
+            let funcs = union
+                .get_types()
+                .iter()
+                .filter_map(|typ| match typ {
+                    LuaType::DocFunction(lua_func) => Some(lua_func.clone()),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            if let Some(call) = builder
+                .get_generic_call_signature(&funcs)
+                .or_else(|| builder.get_call_signature())
+            {
                 builder.set_type_description(hover_doc_function_type(
                     builder,
                     db,
@@ -202,6 +214,40 @@ fn hover_signature_type(
         func_name.to_string()
     };
 
+    // 泛型调用时显示实例化后的签名, 且不显示重载
+    if signature.is_generic() {
+        let mut funcs = vec![Arc::new(LuaFunctionType::new(
+            signature.is_async,
+            signature.is_colon_define,
+            signature.get_type_params(),
+            signature.get_return_types(),
+        ))];
+        funcs.extend(signature.overloads.iter().cloned());
+        if let Some(instantiated) = builder.get_generic_call_signature(&funcs) {
+            let async_label = if signature.is_async { "async " } else { "" };
+            let rets = instantiated
+                .get_ret()
+                .iter()
+                .map(|ty| humanize_type(db, &widen_const_type(ty), RenderLevel::Simple))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let rets = if rets.is_empty() {
+                rets
+            } else {
+                format!(" -> {}", rets)
+            };
+            builder.set_type_description(format_function_type(
+                type_label,
+                async_label,
+                full_name,
+                format_function_params(db, instantiated.get_params()),
+                rets,
+            ));
+            builder.signature_overload = None;
+            return Some(());
+        }
+    }
+
     // 构建 signature
     let signature_info = {
         let async_label = db
@@ -386,7 +432,9 @@ fn build_signature_ret_type(
             let mut new_type_expansion = format!("return #{}", i + 1);
             let mut seen = HashSet::new();
             for type_expansion in pop_type_expansion {
-                for line in type_expansion.lines().skip(1) {
+                // 多行展开的首行为类型名, 单行展开(别名)则整行保留
+                let skip = if type_expansion.contains('\n') { 1 } else { 0 };
+                for line in type_expansion.lines().skip(skip) {
                     if seen.insert(line.to_string()) {
                         new_type_expansion.push('\n');
                         new_type_expansion.push_str(line);
@@ -399,6 +447,44 @@ fn build_signature_ret_type(
     type_text
 }
 
+fn format_function_params(db: &DbIndex, params: &[(String, Option<LuaType>)]) -> String {
+    params
+        .iter()
+        .enumerate()
+        .map(|(index, param)| {
+            let name = param.0.clone();
+            if index == 0 && param.1.is_some() && param.1.as_ref().unwrap().is_self_infer() {
+                "".to_string()
+            } else if let Some(ty) = &param.1 {
+                let ty = widen_const_type(ty);
+                format!("{}: {}", name, humanize_type(db, &ty, RenderLevel::Simple))
+            } else {
+                name
+            }
+        })
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// 实参推断出的字面量类型显示为其基础类型, 例如`1`显示为`integer`
+fn widen_const_type(ty: &LuaType) -> LuaType {
+    match ty {
+        LuaType::IntegerConst(_) => LuaType::Integer,
+        LuaType::FloatConst(_) => LuaType::Number,
+        LuaType::StringConst(_) => LuaType::String,
+        LuaType::BooleanConst(_) => LuaType::Boolean,
+        LuaType::Union(union) => LuaType::Union(Arc::new(LuaUnionType::new(
+            union.get_types().iter().map(widen_const_type).collect(),
+        ))),
+        LuaType::Array(base) => LuaType::Array(Arc::new(widen_const_type(base))),
+        LuaType::TableGeneric(params) => {
+            LuaType::TableGeneric(Arc::new(params.iter().map(widen_const_type).collect()))
+        }
+        _ => ty.clone(),
+    }
+}
+
 fn format_function_type(
     type_label: &str,
     async_label: &str,
@@ -434,12 +520,25 @@ pub fn hover_type(
                         multi_union.as_ref(),
                         Some(type_decl.get_full_name()),
                     ),
-                    _ => None,
+                    Some(_) => {
+                        add_alias_type_expansion(builder, db, ty);
+                        None
+                    }
+                    None => None,
                 }
             } else {
                 None
             }
         }
+        LuaType::Generic(generic) => {
+            let type_decl = db
+                .get_type_index()
+                .get_type_decl(&generic.get_base_type_id())?;
+            if type_decl.is_alias() {
+                add_alias_type_expansion(builder, db, ty);
+            }
+            None
+        }
         LuaType::MultiLineUnion(multi_union) => {
             hover_multi_line_union_type(builder, db, multi_union.as_ref(), None)
         }
@@ -515,3 +614,91 @@ fn hover_multi_line_union_type(
     builder.add_type_expansion(text);
     type_name
 }
+
+/// 别名展开的最大深度, 避免递归别名无限展开
+pub const MAX_ALIAS_EXPAND_DEPTH: usize = 3;
+
+fn add_alias_type_expansion(builder: &mut HoverBuilder, db: &DbIndex, ty: &LuaType) {
+    let expanded = expand_alias_type(db, ty, MAX_ALIAS_EXPAND_DEPTH);
+    if expanded == *ty {
+        return;
+    }
+    let text = format!(
+        "(alias) {} = {}",
+        humanize_type(db, ty, RenderLevel::Simple),
+        humanize_type(db, &expanded, RenderLevel::Detailed)
+    );
+    if let Some(type_expansion) = &builder.type_expansion {
+        if type_expansion.contains(&text) {
+            return;
+        }
+    }
+    builder.add_type_expansion(text);
+}
+
+/// 将类型中的别名替换为其定义, 最多展开`depth`层
+pub fn expand_alias_type(db: &DbIndex, ty: &LuaType, depth: usize) -> LuaType {
+    match ty {
+        LuaType::Ref(type_decl_id) if depth > 0 => {
+            let Some(type_decl) = db.get_type_index().get_type_decl(type_decl_id) else {
+                return ty.clone();
+            };
+            match type_decl.get_alias_origin(db, None) {
+                // 多行联合类型由`hover_multi_line_union_type`单独展示
+                Some(LuaType::MultiLineUnion(_)) | None => ty.clone(),
+                Some(origin) => expand_alias_type(db, &origin, depth - 1),
+            }
+        }
+        LuaType::Generic(generic) => {
+            let params = generic
+                .get_params()
+                .iter()
+                .map(|param| expand_alias_type(db, param, depth))
+                .collect::<Vec<_>>();
+            let base_id = generic.get_base_type_id();
+            if depth > 0 {
+                if let Some(type_decl) = db.get_type_index().get_type_decl(&base_id) {
+                    let substitutor = TypeSubstitutor::from_alias(params.clone(), base_id.clone());
+                    if let Some(origin) = type_decl.get_alias_origin(db, Some(&substitutor)) {
+                        return expand_alias_type(db, &origin, depth - 1);
+                    }
+                }
+            }
+            LuaType::Generic(Arc::new(LuaGenericType::new(base_id, params)))
+        }
+        LuaType::Union(union) => LuaType::Union(Arc::new(LuaUnionType::new(
+            union
+                .get_types()
+                .iter()
+                .map(|t| expand_alias_type(db, t, depth))
+                .collect(),
+        ))),
+        LuaType::Tuple(tuple) => LuaType::Tuple(Arc::new(LuaTupleType::new(
+            tuple
+                .get_types()
+                .iter()
+                .map(|t| expand_alias_type(db, t, depth))
+                .collect(),
+        ))),
+        LuaType::Array(base) => LuaType::Array(Arc::new(expand_alias_type(db, base, depth))),
+        LuaType::Variadic(base) => LuaType::Variadic(Arc::new(expand_alias_type(db, base, depth))),
+        LuaType::DocFunction(func) => LuaType::DocFunction(Arc::new(LuaFunctionType::new(
+            func.is_async(),
+            func.is_colon_define(),
+            func.get_params()
+                .iter()
+                .map(|(name, t)| {
+                    (
+                        name.clone(),
+                        t.as_ref().map(|t| expand_alias_type(db, t, depth)),
+                    )
+                })
+                .collect(),
+            func.get_ret()
+                .iter()
+                .map(|t| expand_alias_type(db, t, depth))
+                .collect(),
+        ))),
+        _ => ty.clone(),
+    }
+}
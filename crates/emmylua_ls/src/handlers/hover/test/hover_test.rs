@@ -0,0 +1,83 @@
+#[cfg(test)]
+mod tests {
+    use crate::handlers::test_lib::ProviderVirtualWorkspace;
+
+    #[test]
+    fn test_hover_narrowed_type() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let text = ws
+            .get_hover_text(
+                r#"
+                ---@alias MaybeId integer?
+                ---@type MaybeId
+                local x
+                if x then
+                    print(<??>x)
+                end
+                "#,
+            )
+            .unwrap();
+        assert!(text.contains("local x: integer\n"));
+    }
+
+    #[test]
+    fn test_hover_alias_expansion() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let text = ws
+            .get_hover_text(
+                r#"
+                ---@alias Id integer
+                ---@alias Ids Id[]
+                ---@alias Handler fun(id: Id): Ids
+                ---@type Handler
+                local <??>h
+                "#,
+            )
+            .unwrap();
+        assert!(text.contains("local h: Handler"));
+        assert!(text.contains("(alias) Handler = fun(id: integer) -> integer[]"));
+
+        // 递归别名在达到深度限制后停止展开
+        let text = ws
+            .get_hover_text(
+                r#"
+                ---@alias A B
+                ---@alias B A
+                ---@type A
+                local <??>w
+                "#,
+            )
+            .unwrap();
+        assert!(text.contains("local w: A"));
+    }
+
+    #[test]
+    fn test_hover_generic_call() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let text = ws
+            .get_hover_text(
+                r#"
+                ---@generic T
+                ---@param x T
+                ---@return T
+                local function id(x) return x end
+                local r = <??>id(1)
+                "#,
+            )
+            .unwrap();
+        assert!(text.contains("local function id(x: integer) -> integer"));
+
+        let text = ws
+            .get_hover_text(
+                r#"
+                ---@generic T
+                ---@param x T
+                ---@return T
+                local function id2(x) return x end
+                local <??>f = id2
+                "#,
+            )
+            .unwrap();
+        assert!(text.contains("x: T"));
+    }
+}
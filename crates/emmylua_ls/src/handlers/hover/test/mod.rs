@@ -0,0 +1,18 @@
+use lsp_types::{HoverContents, MarkupContent};
+
+mod hover_test;
+use super::hover;
+use crate::handlers::test_lib::ProviderVirtualWorkspace;
+
+#[allow(unused)]
+impl ProviderVirtualWorkspace {
+    pub fn get_hover_text(&mut self, block_str: &str) -> Option<String> {
+        let (content, position) = Self::handle_file_content(block_str)?;
+        let file_id = self.def(&content);
+        let result = hover(&self.analysis, file_id, position)?;
+        match result.contents {
+            HoverContents::Markup(MarkupContent { value, .. }) => Some(value),
+            _ => None,
+        }
+    }
+}
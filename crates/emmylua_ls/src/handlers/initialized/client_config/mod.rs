@@ -15,6 +15,7 @@ pub struct ClientConfig {
     pub extensions: Vec<String>,
     pub encoding: String,
     pub partial_emmyrcs: Option<Vec<Value>>,
+    pub workspace_symbol_resolve: bool,
 }
 
 pub async fn get_client_config(
@@ -27,6 +28,7 @@ pub async fn get_client_config(
         extensions: Vec::new(),
         encoding: "utf-8".to_string(),
         partial_emmyrcs: None,
+        workspace_symbol_resolve: false,
     };
     match client_id {
         ClientId::VSCode => get_client_config_vscode(context, &mut config).await,
@@ -1,9 +1,7 @@
 mod client_config;
-mod codestyle;
-mod collect_files;
 mod locale;
 
-use std::{path::PathBuf, str::FromStr, sync::Arc};
+use std::{path::PathBuf, sync::Arc};
 
 use crate::{
     cmd_args::CmdArgs,
@@ -11,13 +9,15 @@ use crate::{
         get_client_id, load_emmy_config, ClientId, ClientProxy, FileDiagnostic, ProgressTask,
         ServerContextSnapshot, StatusBar,
     },
-    handlers::text_document::register_files_watch,
+    handlers::{
+        text_document::register_files_watch, workspace_symbol::is_support_workspace_symbol_resolve,
+    },
     logger::init_logger,
 };
 pub use client_config::{get_client_config, ClientConfig};
-use codestyle::load_editorconfig;
-use collect_files::collect_files;
-use emmylua_code_analysis::{uri_to_file_path, EmmyLuaAnalysis, Emmyrc};
+use emmylua_code_analysis::{
+    collect_workspace_files, load_editorconfig, uri_to_file_path, EmmyLuaAnalysis, Emmyrc,
+};
 use log::info;
 use lsp_types::InitializeParams;
 use tokio::sync::RwLock;
@@ -28,7 +28,9 @@ pub async fn initialized_handler(
     cmd_args: CmdArgs,
 ) -> Option<()> {
     let client_id = get_client_id(&params.client_info);
-    let client_config = get_client_config(&context, client_id).await;
+    let mut client_config = get_client_config(&context, client_id).await;
+    client_config.workspace_symbol_resolve =
+        is_support_workspace_symbol_resolve(&params.capabilities);
     let workspace_folders = get_workspace_folders(&params);
     let main_root: Option<&str> = match workspace_folders.first() {
         Some(path) => path.to_str(),
@@ -51,7 +53,7 @@ pub async fn initialized_handler(
     };
 
     let emmyrc = load_emmy_config(config_root, client_config.clone());
-    load_editorconfig(workspace_folders.clone());
+    load_editorconfig(&workspace_folders);
 
     let mut workspace_manager = context.workspace_manager.write().await;
     workspace_manager.workspace_folders = workspace_folders.clone();
@@ -98,22 +100,7 @@ pub async fn init_analysis(
         Some("Loading workspace files".to_string()),
     );
 
-    let mut workspace_folders = workspace_folders;
-    for workspace_root in &workspace_folders {
-        info!("add workspace root: {:?}", workspace_root);
-        mut_analysis.add_main_workspace(workspace_root.clone());
-    }
-
-    for workspace_root in &emmyrc.workspace.workspace_roots {
-        info!("add workspace root: {:?}", workspace_root);
-        mut_analysis.add_main_workspace(PathBuf::from_str(workspace_root).unwrap());
-    }
-
-    for lib in &emmyrc.workspace.library {
-        info!("add library: {:?}", lib);
-        mut_analysis.add_library_workspace(PathBuf::from_str(lib).unwrap());
-        workspace_folders.push(PathBuf::from_str(lib).unwrap());
-    }
+    let workspace_folders = mut_analysis.add_workspaces_by_config(workspace_folders);
 
     status_bar.update_progress_task(
         client_id,
@@ -123,7 +110,7 @@ pub async fn init_analysis(
     );
 
     // load files
-    let files = collect_files(&workspace_folders, &emmyrc);
+    let files = collect_workspace_files(&workspace_folders, &emmyrc);
     let files: Vec<(PathBuf, Option<String>)> =
         files.into_iter().map(|file| file.into_tuple()).collect();
 
@@ -2,11 +2,11 @@ use std::collections::HashMap;
 
 use emmylua_code_analysis::{
     FileId, InferGuard, LuaFunctionType, LuaMemberId, LuaMemberKey, LuaSemanticDeclId,
-    LuaSignatureId, LuaType, RenderLevel, SemanticModel,
+    LuaSignatureId, LuaType, RenderLevel, SemanticModel, SignatureReturnStatus,
 };
 use emmylua_parser::{
-    LuaAst, LuaAstNode, LuaCallExpr, LuaClosureExpr, LuaExpr, LuaFuncStat, LuaIndexExpr,
-    LuaLocalName, LuaSyntaxId, LuaVarExpr,
+    LuaAssignStat, LuaAst, LuaAstNode, LuaAstToken, LuaCallExpr, LuaClosureExpr, LuaExpr,
+    LuaForRangeStat, LuaFuncStat, LuaIndexExpr, LuaLocalName, LuaSyntaxId, LuaVarExpr,
 };
 use lsp_types::{InlayHint, InlayHintKind, InlayHintLabel, InlayHintLabelPart};
 use rowan::NodeOrToken;
@@ -19,11 +19,19 @@ pub fn build_inlay_hints(semantic_model: &SemanticModel) -> Option<Vec<InlayHint
     for node in root.clone().descendants::<LuaAst>() {
         match node {
             LuaAst::LuaClosureExpr(closure) => {
-                build_closure_hint(semantic_model, &mut result, closure);
+                build_closure_hint(semantic_model, &mut result, closure.clone());
+                build_closure_return_hint(semantic_model, &mut result, closure);
             }
             LuaAst::LuaCallExpr(call_expr) => {
                 build_call_expr_param_hint(semantic_model, &mut result, call_expr.clone());
-                build_call_expr_await_hint(semantic_model, &mut result, call_expr);
+                build_call_expr_await_hint(semantic_model, &mut result, call_expr.clone());
+                build_call_expr_chain_hint(semantic_model, &mut result, call_expr);
+            }
+            LuaAst::LuaForRangeStat(for_range_stat) => {
+                build_for_range_var_hint(semantic_model, &mut result, for_range_stat);
+            }
+            LuaAst::LuaIndexExpr(index_expr) => {
+                build_enum_member_hint(semantic_model, &mut result, index_expr);
             }
             LuaAst::LuaLocalName(local_name) => {
                 build_local_name_hint(semantic_model, &mut result, local_name);
@@ -94,6 +102,53 @@ fn build_closure_hint(
     Some(())
 }
 
+fn build_closure_return_hint(
+    semantic_model: &SemanticModel,
+    result: &mut Vec<InlayHint>,
+    closure: LuaClosureExpr,
+) -> Option<()> {
+    if !semantic_model.get_emmyrc().hint.return_hint {
+        return Some(());
+    }
+
+    let signature_id = LuaSignatureId::from_closure(semantic_model.get_file_id(), &closure);
+    let signature = semantic_model
+        .get_db()
+        .get_signature_index()
+        .get(&signature_id)?;
+    // only the returns inferred from the body, a written `@return` is already visible
+    if signature.resolve_return != SignatureReturnStatus::InferResolve {
+        return Some(());
+    }
+
+    let return_types = signature.get_return_types();
+    if return_types.is_empty() || return_types.iter().all(|typ| typ.is_unknown()) {
+        return Some(());
+    }
+
+    let db = semantic_model.get_db();
+    let return_desc = return_types
+        .iter()
+        .map(|typ| humanize_type(db, typ, RenderLevel::Simple))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let document = semantic_model.get_document();
+    let params_end = closure.get_params_list()?.get_range().end();
+    let hint = InlayHint {
+        kind: Some(InlayHintKind::TYPE),
+        label: InlayHintLabel::String(format!("-> {}", return_desc)),
+        position: document.to_lsp_position(params_end)?,
+        text_edits: None,
+        tooltip: None,
+        padding_left: Some(true),
+        padding_right: None,
+        data: None,
+    };
+    result.push(hint);
+
+    Some(())
+}
+
 fn build_call_expr_param_hint(
     semantic_model: &SemanticModel,
     result: &mut Vec<InlayHint>,
@@ -172,6 +227,148 @@ fn build_call_expr_await_hint(
     Some(())
 }
 
+// like rust analyzer, a call in a method chain which continues on the next line shows its type
+fn build_call_expr_chain_hint(
+    semantic_model: &SemanticModel,
+    result: &mut Vec<InlayHint>,
+    call_expr: LuaCallExpr,
+) -> Option<()> {
+    if !semantic_model.get_emmyrc().hint.chain_hint {
+        return Some(());
+    }
+
+    let index_expr = call_expr.get_parent::<LuaIndexExpr>()?;
+    if index_expr.get_prefix_expr()?.syntax() != call_expr.syntax() {
+        return Some(());
+    }
+
+    let document = semantic_model.get_document();
+    let call_end = call_expr.get_range().end();
+    let index_start = index_expr.get_index_token()?.get_range().start();
+    if document.get_line(call_end)? == document.get_line(index_start)? {
+        return Some(());
+    }
+
+    let typ = semantic_model.infer_expr(call_expr.into()).ok()?;
+    if typ.is_unknown() || typ.is_any() {
+        return Some(());
+    }
+
+    let typ_desc = humanize_type(semantic_model.get_db(), &typ, RenderLevel::Simple);
+    let hint = InlayHint {
+        kind: Some(InlayHintKind::TYPE),
+        label: InlayHintLabel::String(format!(": {}", typ_desc)),
+        position: document.to_lsp_position(call_end)?,
+        text_edits: None,
+        tooltip: None,
+        padding_left: Some(true),
+        padding_right: None,
+        data: None,
+    };
+    result.push(hint);
+
+    Some(())
+}
+
+fn build_for_range_var_hint(
+    semantic_model: &SemanticModel,
+    result: &mut Vec<InlayHint>,
+    for_range_stat: LuaForRangeStat,
+) -> Option<()> {
+    if !semantic_model.get_emmyrc().hint.for_var_hint {
+        return Some(());
+    }
+
+    let document = semantic_model.get_document();
+    let db = semantic_model.get_db();
+    for var_name in for_range_stat.get_var_name_list() {
+        let Some(semantic_info) =
+            semantic_model.get_semantic_info(NodeOrToken::Token(var_name.syntax().clone()))
+        else {
+            continue;
+        };
+        if semantic_info.typ.is_unknown() || semantic_info.typ.is_any() {
+            continue;
+        }
+
+        let lsp_range = document.to_lsp_range(var_name.get_range())?;
+        let typ_desc = humanize_type(db, &semantic_info.typ, RenderLevel::Simple);
+        let hint = InlayHint {
+            kind: Some(InlayHintKind::TYPE),
+            label: InlayHintLabel::String(format!(": {}", typ_desc)),
+            position: lsp_range.end,
+            text_edits: None,
+            tooltip: None,
+            padding_left: Some(true),
+            padding_right: None,
+            data: None,
+        };
+        result.push(hint);
+    }
+
+    Some(())
+}
+
+fn build_enum_member_hint(
+    semantic_model: &SemanticModel,
+    result: &mut Vec<InlayHint>,
+    index_expr: LuaIndexExpr,
+) -> Option<()> {
+    if !semantic_model.get_emmyrc().hint.enum_hint {
+        return Some(());
+    }
+
+    // `E.A = 5` assigns the member, the value hint would repeat the right side
+    if let Some(assign_stat) = index_expr.get_parent::<LuaAssignStat>() {
+        let (vars, _) = assign_stat.get_var_and_expr_list();
+        if vars.iter().any(|var| var.syntax() == index_expr.syntax()) {
+            return Some(());
+        }
+    }
+
+    let prefix_type = semantic_model
+        .infer_expr(index_expr.get_prefix_expr()?)
+        .ok()?;
+    let type_decl_id = match &prefix_type {
+        LuaType::Ref(type_decl_id) | LuaType::Def(type_decl_id) => type_decl_id,
+        _ => return Some(()),
+    };
+    let type_decl = semantic_model
+        .get_db()
+        .get_type_index()
+        .get_type_decl(type_decl_id)?;
+    if !type_decl.is_enum() || type_decl.is_enum_key() {
+        return Some(());
+    }
+
+    let typ = semantic_model
+        .infer_expr(LuaExpr::IndexExpr(index_expr.clone()))
+        .ok()?;
+    let value = match typ {
+        LuaType::IntegerConst(i) | LuaType::DocIntegerConst(i) => i.to_string(),
+        LuaType::FloatConst(f) => f.to_string(),
+        LuaType::StringConst(s) | LuaType::DocStringConst(s) => format!("{:?}", s.as_str()),
+        LuaType::BooleanConst(b) | LuaType::DocBooleanConst(b) => b.to_string(),
+        _ => return Some(()),
+    };
+
+    let document = semantic_model.get_document();
+    let lsp_range = document.to_lsp_range(index_expr.get_range())?;
+    let hint = InlayHint {
+        kind: Some(InlayHintKind::TYPE),
+        label: InlayHintLabel::String(format!("= {}", value)),
+        position: lsp_range.end,
+        text_edits: None,
+        tooltip: None,
+        padding_left: Some(true),
+        padding_right: None,
+        data: None,
+    };
+    result.push(hint);
+
+    Some(())
+}
+
 fn build_call_args_for_func_type(
     semantic_model: &SemanticModel,
     result: &mut Vec<InlayHint>,
@@ -1,4 +1,5 @@
 mod build_inlay_hint;
+mod test;
 
 use build_inlay_hint::build_inlay_hints;
 use lsp_types::{
@@ -0,0 +1,118 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use emmylua_code_analysis::Emmyrc;
+
+    use crate::handlers::{inlay_hint::test::get_labels, test_lib::ProviderVirtualWorkspace};
+
+    #[test]
+    fn test_return_hint() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let code = r#"
+            local function f()
+                return "a"
+            end
+
+            ---@return number
+            local function g()
+                return 1
+            end
+            "#;
+        // off by default
+        let labels = get_labels(&ws.get_inlay_hints(code).unwrap());
+        assert!(!labels.contains(&(1, "-> \"a\"".to_string())));
+
+        let mut emmyrc = Emmyrc::default();
+        emmyrc.hint.return_hint = true;
+        ws.analysis.update_config(Arc::new(emmyrc));
+        let labels = get_labels(&ws.get_inlay_hints(code).unwrap());
+        assert!(labels.contains(&(1, "-> \"a\"".to_string())));
+        // a written `@return` is not repeated
+        assert!(!labels.iter().any(|(line, _)| *line == 6));
+    }
+
+    #[test]
+    fn test_chain_hint() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let code = r#"
+            ---@class Builder
+            local Builder = {}
+
+            ---@return Builder
+            function Builder:add() end
+
+            ---@return string
+            function Builder:build() end
+
+            local s = Builder:add()
+                :add()
+                :build()
+            local t = Builder:add():build()
+            "#;
+        // off by default
+        let labels = get_labels(&ws.get_inlay_hints(code).unwrap());
+        assert!(!labels.contains(&(10, ": Builder".to_string())));
+
+        let mut emmyrc = Emmyrc::default();
+        emmyrc.hint.chain_hint = true;
+        ws.analysis.update_config(Arc::new(emmyrc));
+        let labels = get_labels(&ws.get_inlay_hints(code).unwrap());
+        assert!(labels.contains(&(10, ": Builder".to_string())));
+        assert!(labels.contains(&(11, ": Builder".to_string())));
+        // a chain on a single line has no hint
+        assert!(!labels
+            .iter()
+            .any(|(line, label)| *line == 13 && label == ": Builder"));
+    }
+
+    #[test]
+    fn test_for_var_hint() {
+        let mut ws = ProviderVirtualWorkspace::new_with_init_std_lib();
+        let code = r#"
+            ---@type string[]
+            local list = {}
+            for i, v in ipairs(list) do
+            end
+            "#;
+        // off by default
+        let labels = get_labels(&ws.get_inlay_hints(code).unwrap());
+        assert!(!labels.contains(&(3, ": integer".to_string())));
+        assert!(!labels.contains(&(3, ": string".to_string())));
+
+        let mut emmyrc = Emmyrc::default();
+        emmyrc.hint.for_var_hint = true;
+        ws.analysis.update_config(Arc::new(emmyrc));
+        let labels = get_labels(&ws.get_inlay_hints(code).unwrap());
+        assert!(labels.contains(&(3, ": integer".to_string())));
+        assert!(labels.contains(&(3, ": string".to_string())));
+    }
+
+    #[test]
+    fn test_enum_hint() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let code = r#"
+            ---@enum Color
+            local Color = {
+                Red = 1,
+                Name = "blue",
+            }
+
+            local a = Color.Red
+            local b = Color.Name
+            Color.Red = 5
+            "#;
+        // off by default
+        let labels = get_labels(&ws.get_inlay_hints(code).unwrap());
+        assert!(!labels.contains(&(7, "= 1".to_string())));
+
+        let mut emmyrc = Emmyrc::default();
+        emmyrc.hint.enum_hint = true;
+        ws.analysis.update_config(Arc::new(emmyrc));
+        let labels = get_labels(&ws.get_inlay_hints(code).unwrap());
+        assert!(labels.contains(&(7, "= 1".to_string())));
+        assert!(labels.contains(&(8, "= \"blue\"".to_string())));
+        // an assignment target has no value hint
+        assert!(!labels.iter().any(|(line, _)| *line == 9));
+    }
+}
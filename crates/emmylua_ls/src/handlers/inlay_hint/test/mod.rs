@@ -0,0 +1,26 @@
+use lsp_types::{InlayHint, InlayHintLabel};
+
+mod inlay_hint_test;
+use super::build_inlay_hint::build_inlay_hints;
+use crate::handlers::test_lib::ProviderVirtualWorkspace;
+
+#[allow(unused)]
+impl ProviderVirtualWorkspace {
+    pub fn get_inlay_hints(&mut self, content: &str) -> Option<Vec<InlayHint>> {
+        let file_id = self.def(content);
+        let semantic_model = self.analysis.compilation.get_semantic_model(file_id)?;
+        build_inlay_hints(&semantic_model)
+    }
+}
+
+/// `(line, label)` of the hints with a plain string label
+#[allow(unused)]
+fn get_labels(hints: &[InlayHint]) -> Vec<(u32, String)> {
+    hints
+        .iter()
+        .filter_map(|hint| match &hint.label {
+            InlayHintLabel::String(label) => Some((hint.position.line, label.clone())),
+            InlayHintLabel::LabelParts(_) => None,
+        })
+        .collect()
+}
@@ -24,6 +24,7 @@ mod request_handler;
 mod response_handler;
 mod semantic_token;
 mod signature_helper;
+mod test_lib;
 mod text_document;
 mod workspace_symbol;
 
@@ -9,6 +9,7 @@ use lsp_types::request::{
     HoverRequest, InlayHintRequest, InlayHintResolveRequest, InlineValueRequest,
     PrepareRenameRequest, RangeFormatting, References, Rename, ResolveCompletionItem,
     SelectionRangeRequest, SemanticTokensFullRequest, SignatureHelpRequest, WorkspaceSymbolRequest,
+    WorkspaceSymbolResolve,
 };
 use serde::{de::DeserializeOwned, Serialize};
 use tokio_util::sync::CancellationToken;
@@ -37,7 +38,7 @@ use super::{
     rename::{on_prepare_rename_handler, on_rename_handler},
     semantic_token::on_semantic_token_handler,
     signature_helper::on_signature_helper_handler,
-    workspace_symbol::on_workspace_symbol_handler,
+    workspace_symbol::{on_workspace_symbol_handler, on_workspace_symbol_resolve_handler},
 };
 
 pub async fn on_req_handler(
@@ -97,6 +98,8 @@ pub async fn on_req_handler(
         .await
         .on_parallel::<WorkspaceSymbolRequest, _, _>(on_workspace_symbol_handler)
         .await
+        .on_parallel::<WorkspaceSymbolResolve, _, _>(on_workspace_symbol_resolve_handler)
+        .await
         .on_parallel::<Formatting, _, _>(on_formatting_handler)
         .await
         .on_parallel::<RangeFormatting, _, _>(on_range_formatting_handler)
@@ -62,6 +62,7 @@ fn build_tokens_semantic_token(
         | LuaTokenKind::TkEnd
         | LuaTokenKind::TkFor
         | LuaTokenKind::TkFunction
+        | LuaTokenKind::TkGlobal
         | LuaTokenKind::TkGoto
         | LuaTokenKind::TkIf
         | LuaTokenKind::TkIn
@@ -146,6 +147,7 @@ fn build_tokens_semantic_token(
         | LuaTokenKind::TkDocExtends
         | LuaTokenKind::TkDocAs
         | LuaTokenKind::TkDocIn
+        | LuaTokenKind::TkDocOut
         | LuaTokenKind::TkDocInfer => {
             builder.push(token, SemanticTokenType::KEYWORD);
         }
@@ -21,30 +21,48 @@ pub fn build_signature_helper(
     let colon_call = call_expr.is_colon_call();
     let current_idx = get_current_param_index(&call_expr, &token)?;
     match prefix_expr_type {
-        LuaType::DocFunction(func_type) => {
-            build_doc_function_signature_help(semantic_model, &func_type, colon_call, current_idx)
-        }
+        LuaType::DocFunction(func_type) => build_doc_function_signature_help(
+            semantic_model,
+            &call_expr,
+            &func_type,
+            colon_call,
+            current_idx,
+        ),
         LuaType::Signature(signature_id) => build_sig_id_signature_help(
             semantic_model,
+            &call_expr,
             signature_id,
             colon_call,
             current_idx,
             false,
         ),
-        LuaType::Ref(type_decl_id) => {
-            build_type_signature_help(semantic_model, &type_decl_id, colon_call, current_idx)
-        }
-        LuaType::Def(type_decl_id) => {
-            build_type_signature_help(semantic_model, &type_decl_id, colon_call, current_idx)
-        }
+        LuaType::Ref(type_decl_id) => build_type_signature_help(
+            semantic_model,
+            &call_expr,
+            &type_decl_id,
+            colon_call,
+            current_idx,
+        ),
+        LuaType::Def(type_decl_id) => build_type_signature_help(
+            semantic_model,
+            &call_expr,
+            &type_decl_id,
+            colon_call,
+            current_idx,
+        ),
         LuaType::Instance(inst) => {
-            build_inst_signature_help(semantic_model, &inst, colon_call, current_idx)
-        }
-        LuaType::TableConst(meta_table) => {
-            build_table_call_signature_help(semantic_model, meta_table, colon_call, current_idx)
+            build_inst_signature_help(semantic_model, &call_expr, &inst, colon_call, current_idx)
         }
+        LuaType::TableConst(meta_table) => build_table_call_signature_help(
+            semantic_model,
+            &call_expr,
+            meta_table,
+            colon_call,
+            current_idx,
+        ),
         LuaType::Union(union_types) => build_union_type_signature_help(
             semantic_model,
+            &call_expr,
             union_types.get_types(),
             colon_call,
             current_idx,
@@ -72,10 +90,18 @@ pub fn get_current_param_index(call_expr: &LuaCallExpr, token: &LuaSyntaxToken)
 
 fn build_doc_function_signature_help(
     semantic_model: &SemanticModel,
+    call_expr: &LuaCallExpr,
     func_type: &LuaFunctionType,
     colon_call: bool,
     current_idx: usize,
 ) -> Option<SignatureHelp> {
+    // render the generic parameters with the types inferred from the arguments typed so far
+    let instantiate_func = if func_type.contain_tpl() {
+        semantic_model.instantiate_call_func(func_type, call_expr.clone())
+    } else {
+        None
+    };
+    let func_type = instantiate_func.as_ref().unwrap_or(func_type);
     let mut current_idx = current_idx;
     let mut params = func_type
         .get_params()
@@ -144,6 +170,7 @@ fn build_doc_function_signature_help(
 
 fn build_sig_id_signature_help(
     semantic_model: &SemanticModel,
+    call_expr: &LuaCallExpr,
     signature_id: LuaSignatureId,
     colon_call: bool,
     current_idx: usize,
@@ -154,6 +181,14 @@ fn build_sig_id_signature_help(
     let signature = db.get_signature_index().get(&signature_id)?;
     let mut current_idx = current_idx;
     let mut params = signature.get_type_params();
+    let signature_func = signature.to_doc_func_type();
+    if signature_func.contain_tpl() {
+        if let Some(instantiate_func) =
+            semantic_model.instantiate_call_func(&signature_func, call_expr.clone())
+        {
+            params = instantiate_func.get_params().to_vec();
+        }
+    }
     let colon_define = signature.is_colon_define;
     if is_call_operator {
         if params.len() > 0 && !colon_define {
@@ -249,6 +284,7 @@ fn build_sig_id_signature_help(
     for overload in &signature.overloads {
         let signature = build_doc_function_signature_help(
             &semantic_model,
+            call_expr,
             &overload,
             colon_call,
             origin_current_idx,
@@ -258,9 +294,24 @@ fn build_sig_id_signature_help(
         }
     }
 
+    // the signatures are listed as the function itself followed by its overloads,
+    // a function without `@param` only implements its overloads so it is not a candidate
+    let mut active_signature = 0;
+    if !is_call_operator && !signature.overloads.is_empty() {
+        let mut funcs = signature.overloads.clone();
+        let mut offset = 1;
+        if !signature.param_docs.is_empty() {
+            funcs.insert(0, signature_func);
+            offset = 0;
+        }
+        active_signature = semantic_model
+            .resolve_call_overload(&funcs, call_expr.clone(), Some(origin_current_idx + 1))
+            .map_or(0, |idx| idx + offset);
+    }
+
     Some(SignatureHelp {
         signatures,
-        active_signature: Some(0),
+        active_signature: Some(active_signature as u32),
         active_parameter: Some(current_idx as u32),
     })
 }
@@ -268,6 +319,7 @@ fn build_sig_id_signature_help(
 // todo support overload
 fn build_type_signature_help(
     semantic_model: &SemanticModel,
+    call_expr: &LuaCallExpr,
     type_decl_id: &LuaTypeDeclId,
     colon_call: bool,
     current_idx: usize,
@@ -284,6 +336,7 @@ fn build_type_signature_help(
             LuaType::DocFunction(func_type) => {
                 return build_doc_function_signature_help(
                     semantic_model,
+                    call_expr,
                     &func_type,
                     colon_call,
                     current_idx,
@@ -293,6 +346,7 @@ fn build_type_signature_help(
                 // todo remove first param
                 return build_sig_id_signature_help(
                     semantic_model,
+                    call_expr,
                     signature_id,
                     colon_call,
                     current_idx,
@@ -308,6 +362,7 @@ fn build_type_signature_help(
 
 fn build_inst_signature_help(
     semantic_model: &SemanticModel,
+    call_expr: &LuaCallExpr,
     inst: &LuaInstanceType,
     colon_call: bool,
     current_idx: usize,
@@ -320,11 +375,18 @@ fn build_inst_signature_help(
         }
     };
 
-    build_table_call_signature_help(semantic_model, meta_table, colon_call, current_idx)
+    build_table_call_signature_help(
+        semantic_model,
+        call_expr,
+        meta_table,
+        colon_call,
+        current_idx,
+    )
 }
 
 fn build_table_call_signature_help(
     semantic_model: &SemanticModel,
+    call_expr: &LuaCallExpr,
     meta: InFiled<TextRange>,
     colon_call: bool,
     current_idx: usize,
@@ -343,6 +405,7 @@ fn build_table_call_signature_help(
         LuaType::DocFunction(func_type) => {
             return build_doc_function_signature_help(
                 semantic_model,
+                call_expr,
                 &func_type,
                 colon_call,
                 current_idx,
@@ -351,6 +414,7 @@ fn build_table_call_signature_help(
         LuaType::Signature(signature_id) => {
             return build_sig_id_signature_help(
                 semantic_model,
+                call_expr,
                 signature_id,
                 colon_call,
                 current_idx,
@@ -365,6 +429,7 @@ fn build_table_call_signature_help(
 
 fn build_union_type_signature_help(
     semantic_model: &SemanticModel,
+    call_expr: &LuaCallExpr,
     union_types: &[LuaType],
     colon_call: bool,
     current_idx: usize,
@@ -376,6 +441,7 @@ fn build_union_type_signature_help(
             LuaType::DocFunction(func_type) => {
                 let sig = build_doc_function_signature_help(
                     semantic_model,
+                    call_expr,
                     &func_type,
                     colon_call,
                     current_idx,
@@ -388,6 +454,7 @@ fn build_union_type_signature_help(
             LuaType::Signature(signature_id) => {
                 let sig = build_sig_id_signature_help(
                     semantic_model,
+                    call_expr,
                     *signature_id,
                     colon_call,
                     current_idx,
@@ -401,6 +468,7 @@ fn build_union_type_signature_help(
             LuaType::Ref(type_decl_id) => {
                 let sig = build_type_signature_help(
                     semantic_model,
+                    call_expr,
                     &type_decl_id,
                     colon_call,
                     current_idx,
@@ -413,6 +481,7 @@ fn build_union_type_signature_help(
             LuaType::Def(type_decl_id) => {
                 let sig = build_type_signature_help(
                     semantic_model,
+                    call_expr,
                     &type_decl_id,
                     colon_call,
                     current_idx,
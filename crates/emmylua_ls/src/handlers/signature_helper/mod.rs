@@ -1,4 +1,5 @@
 mod build_signature_helper;
+mod test;
 
 use crate::context::ServerContextSnapshot;
 use build_signature_helper::build_signature_helper;
@@ -0,0 +1,36 @@
+use emmylua_parser::{LuaAstNode, LuaCallExpr};
+use lsp_types::{Documentation, SignatureHelp};
+
+mod signature_helper_test;
+use super::build_signature_helper::build_signature_helper;
+use crate::handlers::test_lib::ProviderVirtualWorkspace;
+
+#[allow(unused)]
+impl ProviderVirtualWorkspace {
+    /// The signature help of the last call in `content`, with the cursor before its `)`
+    pub fn get_signature_help(&mut self, content: &str) -> Option<SignatureHelp> {
+        let file_id = self.def(content);
+        let semantic_model = self.analysis.compilation.get_semantic_model(file_id)?;
+        let call_expr = semantic_model
+            .get_root()
+            .descendants::<LuaCallExpr>()
+            .last()?;
+        let token = call_expr.get_args_list()?.syntax().last_token()?;
+        build_signature_helper(&semantic_model, call_expr, token)
+    }
+}
+
+#[allow(unused)]
+fn get_param_docs(signature_help: &SignatureHelp, idx: usize) -> Vec<String> {
+    signature_help.signatures[idx]
+        .parameters
+        .as_ref()
+        .unwrap()
+        .iter()
+        .map(|param| match &param.documentation {
+            Some(Documentation::String(doc)) => doc.clone(),
+            Some(Documentation::MarkupContent(content)) => content.value.clone(),
+            None => String::new(),
+        })
+        .collect()
+}
@@ -0,0 +1,69 @@
+#[cfg(test)]
+mod tests {
+    use crate::handlers::{
+        signature_helper::test::get_param_docs, test_lib::ProviderVirtualWorkspace,
+    };
+
+    #[test]
+    fn test_generic_instantiation() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let signature_help = ws
+            .get_signature_help(
+                r#"
+                ---@generic T
+                ---@param a T
+                ---@param b T
+                ---@return T
+                local function pick(a, b)
+                    return a
+                end
+
+                ---@type string
+                local name
+
+                pick(name, )
+                "#,
+            )
+            .unwrap();
+        assert_eq!(signature_help.active_parameter, Some(1));
+        assert_eq!(
+            get_param_docs(&signature_help, 0),
+            vec![
+                "```lua\n(parameter) a: string\n```\n\n",
+                "```lua\n(parameter) b: string\n```\n\n",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_active_overload() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let signature_help = ws
+            .get_signature_help(
+                r#"
+                ---@overload fun(name: string, cb: fun())
+                ---@overload fun(id: integer, force: boolean)
+                function find(key, opts) end
+
+                find(1, )
+                "#,
+            )
+            .unwrap();
+        assert_eq!(signature_help.signatures.len(), 3);
+        assert_eq!(signature_help.active_signature, Some(2));
+
+        let signature_help = ws
+            .get_signature_help(
+                r#"
+                ---@overload fun(name: string, cb: fun())
+                ---@param key integer
+                ---@param opts table
+                function lookup(key, opts) end
+
+                lookup("a", )
+                "#,
+            )
+            .unwrap();
+        assert_eq!(signature_help.active_signature, Some(1));
+    }
+}
@@ -0,0 +1,86 @@
+use emmylua_code_analysis::{EmmyLuaAnalysis, FileId, VirtualUrlGenerator};
+use emmylua_parser::LuaAstNode;
+use lsp_types::Position;
+
+/// A virtual workspace for testing the handlers, each handler adds its own helpers in its test module.
+#[allow(unused)]
+#[derive(Debug)]
+pub struct ProviderVirtualWorkspace {
+    pub virtual_url_generator: VirtualUrlGenerator,
+    pub analysis: EmmyLuaAnalysis,
+    id_counter: u32,
+}
+
+#[allow(unused)]
+impl ProviderVirtualWorkspace {
+    pub fn new() -> Self {
+        let gen = VirtualUrlGenerator::new();
+        let mut analysis = EmmyLuaAnalysis::new();
+        let base = &gen.base;
+        analysis.add_main_workspace(base.clone());
+        ProviderVirtualWorkspace {
+            virtual_url_generator: gen,
+            analysis,
+            id_counter: 0,
+        }
+    }
+
+    pub fn new_with_init_std_lib() -> Self {
+        let gen = VirtualUrlGenerator::new();
+        let mut analysis = EmmyLuaAnalysis::new();
+        analysis.init_std_lib(false);
+        let base = &gen.base;
+        analysis.add_main_workspace(base.clone());
+        ProviderVirtualWorkspace {
+            virtual_url_generator: gen,
+            analysis,
+            id_counter: 0,
+        }
+    }
+
+    pub fn def(&mut self, content: &str) -> FileId {
+        let id = self.id_counter;
+        self.id_counter += 1;
+        self.def_file(&format!("virtual_{}.lua", id), content)
+    }
+
+    pub fn def_file(&mut self, file_name: &str, content: &str) -> FileId {
+        let uri = self.virtual_url_generator.new_uri(file_name);
+        self.analysis
+            .update_file_by_uri(&uri, Some(content.to_string()))
+            .unwrap()
+    }
+
+    pub fn get_node<Ast: LuaAstNode>(&self, file_id: FileId) -> Ast {
+        let tree = self
+            .analysis
+            .compilation
+            .get_db()
+            .get_vfs()
+            .get_syntax_tree(&file_id)
+            .unwrap();
+        tree.get_chunk_node().descendants::<Ast>().next().unwrap()
+    }
+
+    /// 移除内容中唯一的`<??>`标记, 返回移除后的内容与标记所在的位置
+    pub fn handle_file_content(content: &str) -> Option<(String, Position)> {
+        let cursor_pos = content.find("<??>")?;
+        // 确保只有一个 <??> 标记
+        if content.matches("<??>").count() > 1 {
+            return None;
+        }
+
+        let mut line = 0;
+        let mut column = 0;
+        for c in content[..cursor_pos].chars() {
+            if c == '\n' {
+                line += 1;
+                column = 0;
+            } else {
+                column += 1;
+            }
+        }
+
+        Some((content.replace("<??>", ""), Position::new(line, column)))
+    }
+}
@@ -1,120 +1,122 @@
-use emmylua_code_analysis::{DbIndex, LuaCompilation, LuaSemanticDeclId, LuaType};
-use lsp_types::{OneOf, SymbolKind, SymbolTag, WorkspaceSymbol, WorkspaceSymbolResponse};
+use emmylua_code_analysis::{
+    DbIndex, LuaCompilation, LuaSemanticDeclId, LuaSymbol, LuaSymbolKind, LuaType,
+};
+use lsp_types::{
+    OneOf, SymbolKind, SymbolTag, WorkspaceLocation, WorkspaceSymbol, WorkspaceSymbolResponse,
+};
 use tokio_util::sync::CancellationToken;
 
+use super::WorkspaceSymbolData;
+
+// keep the response small for short queries in large workspaces
+const MAX_SYMBOL_COUNT: usize = 1000;
+
 pub fn build_workspace_symbols(
     compilation: &LuaCompilation,
     query: String,
+    lazy_location: bool,
     cancel_token: CancellationToken,
 ) -> Option<WorkspaceSymbolResponse> {
-    let mut symbols = Vec::new();
-    add_global_variable_symbols(&mut symbols, compilation, &query, &cancel_token)?;
-    add_type_symbols(&mut symbols, compilation, &query, &cancel_token)?;
-    Some(WorkspaceSymbolResponse::Nested(symbols))
-}
-
-fn add_global_variable_symbols(
-    symbols: &mut Vec<WorkspaceSymbol>,
-    compilation: &LuaCompilation,
-    query: &str,
-    cancel_token: &CancellationToken,
-) -> Option<()> {
-    if cancel_token.is_cancelled() {
-        return None;
-    }
-
     let db = compilation.get_db();
-    let decl_index = db.get_decl_index();
-    let global_decl_id = decl_index.get_global_decls();
-    for decl_id in global_decl_id {
-        let decl = decl_index.get_decl(&decl_id)?;
+    let symbol_index = db.get_symbol_index();
+    let mut symbols = Vec::new();
+    for symbol in symbol_index.search(&query, MAX_SYMBOL_COUNT) {
         if cancel_token.is_cancelled() {
             return None;
         }
 
-        if decl.get_name().contains(query) {
-            let typ = decl.get_type().unwrap_or(&LuaType::Unknown);
-            let property_owner_id = LuaSemanticDeclId::LuaDecl(decl_id);
-            let document = db.get_vfs().get_document(&decl.get_file_id())?;
-            let location = document.to_lsp_location(decl.get_range())?;
-            let symbol = WorkspaceSymbol {
-                name: decl.get_name().to_string(),
-                kind: get_symbol_kind(typ),
-                tags: if is_deprecated(db, property_owner_id) {
-                    Some(vec![SymbolTag::DEPRECATED])
-                } else {
-                    None
-                },
-                container_name: None,
-                location: OneOf::Left(location),
-                data: None,
-            };
-            symbols.push(symbol);
+        if let Some(workspace_symbol) = build_workspace_symbol(db, symbol, lazy_location) {
+            symbols.push(workspace_symbol);
         }
     }
 
-    Some(())
+    Some(WorkspaceSymbolResponse::Nested(symbols))
 }
 
-fn add_type_symbols(
-    symbols: &mut Vec<WorkspaceSymbol>,
-    compilation: &LuaCompilation,
-    query: &str,
-    cancel_token: &CancellationToken,
-) -> Option<()> {
-    if cancel_token.is_cancelled() {
-        return None;
-    }
+fn build_workspace_symbol(
+    db: &DbIndex,
+    symbol: &LuaSymbol,
+    lazy_location: bool,
+) -> Option<WorkspaceSymbol> {
+    let file_id = symbol.get_file_id();
+    let (location, data) = if lazy_location {
+        let uri = db.get_vfs().get_uri(&file_id)?;
+        let data = WorkspaceSymbolData {
+            file_id,
+            start: symbol.get_range().start().into(),
+            end: symbol.get_range().end().into(),
+        };
+        (
+            OneOf::Right(WorkspaceLocation { uri }),
+            serde_json::to_value(data).ok(),
+        )
+    } else {
+        let document = db.get_vfs().get_document(&file_id)?;
+        let location = document.to_lsp_location(symbol.get_range())?;
+        (OneOf::Left(location), None)
+    };
 
-    let db = compilation.get_db();
-    let decl_index = db.get_type_index();
-    let types = decl_index.get_all_types();
-    for typ in types {
-        if cancel_token.is_cancelled() {
-            return None;
-        }
+    Some(WorkspaceSymbol {
+        name: symbol.get_name().to_string(),
+        kind: get_symbol_kind(db, symbol),
+        tags: if is_deprecated(db, symbol.get_semantic_decl()) {
+            Some(vec![SymbolTag::DEPRECATED])
+        } else {
+            None
+        },
+        container_name: symbol.get_container_name().map(|name| name.to_string()),
+        location,
+        data,
+    })
+}
 
-        if typ.get_full_name().contains(query) {
-            let property_owner_id = LuaSemanticDeclId::TypeDecl(typ.get_id());
-            let location = typ.get_locations().first()?;
-            let document = db.get_vfs().get_document(&location.file_id)?;
-            let location = document.to_lsp_location(location.range)?;
-            let symbol = WorkspaceSymbol {
-                name: typ.get_full_name().to_string(),
-                kind: SymbolKind::CLASS,
-                tags: if is_deprecated(db, property_owner_id) {
-                    Some(vec![SymbolTag::DEPRECATED])
-                } else {
-                    None
-                },
-                container_name: None,
-                location: OneOf::Left(location),
-                data: None,
+fn get_symbol_kind(db: &DbIndex, symbol: &LuaSymbol) -> SymbolKind {
+    match symbol.get_kind() {
+        LuaSymbolKind::Class => SymbolKind::CLASS,
+        LuaSymbolKind::Enum => SymbolKind::ENUM,
+        LuaSymbolKind::Alias => SymbolKind::TYPE_PARAMETER,
+        LuaSymbolKind::Function => SymbolKind::FUNCTION,
+        LuaSymbolKind::Method => SymbolKind::METHOD,
+        LuaSymbolKind::Field => SymbolKind::FIELD,
+        LuaSymbolKind::Variable => {
+            let LuaSemanticDeclId::LuaDecl(decl_id) = symbol.get_semantic_decl() else {
+                return SymbolKind::VARIABLE;
             };
-            symbols.push(symbol);
+            let typ = db
+                .get_decl_index()
+                .get_decl(decl_id)
+                .and_then(|decl| decl.get_type())
+                .unwrap_or(&LuaType::Unknown);
+            if typ.is_function() {
+                SymbolKind::FUNCTION
+            } else if typ.is_const() {
+                SymbolKind::CONSTANT
+            } else if typ.is_def() {
+                SymbolKind::CLASS
+            } else {
+                SymbolKind::VARIABLE
+            }
         }
     }
-
-    Some(())
 }
 
-fn get_symbol_kind(typ: &LuaType) -> SymbolKind {
-    if typ.is_function() {
-        return SymbolKind::FUNCTION;
-    } else if typ.is_const() {
-        return SymbolKind::CONSTANT;
-    } else if typ.is_def() {
-        return SymbolKind::CLASS;
+fn is_deprecated(db: &DbIndex, id: &LuaSemanticDeclId) -> bool {
+    let property_index = db.get_property_index();
+    if let Some(property) = property_index.get_property(id) {
+        return property.is_deprecated;
     }
 
-    SymbolKind::VARIABLE
-}
-
-fn is_deprecated(db: &DbIndex, id: LuaSemanticDeclId) -> bool {
-    let property = db.get_property_index().get_property(&id);
-    if property.is_none() {
+    // the doc of `function A.b() end` belongs to its signature
+    let LuaSemanticDeclId::Member(member_id) = id else {
+        return false;
+    };
+    let Some(member) = db.get_member_index().get_member(member_id) else {
         return false;
+    };
+    match member.get_decl_type() {
+        LuaType::Signature(signature_id) => property_index
+            .get_property(&LuaSemanticDeclId::Signature(signature_id))
+            .is_some_and(|property| property.is_deprecated),
+        _ => false,
     }
-
-    property.unwrap().is_deprecated
 }
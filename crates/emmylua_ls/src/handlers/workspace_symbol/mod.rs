@@ -1,9 +1,14 @@
 mod build_workspace_symbols;
+mod resolve_workspace_symbol;
 
 use build_workspace_symbols::build_workspace_symbols;
+use emmylua_code_analysis::FileId;
 use lsp_types::{
-    ClientCapabilities, OneOf, ServerCapabilities, WorkspaceSymbolParams, WorkspaceSymbolResponse,
+    ClientCapabilities, OneOf, ServerCapabilities, WorkDoneProgressOptions, WorkspaceSymbol,
+    WorkspaceSymbolOptions, WorkspaceSymbolParams, WorkspaceSymbolResponse,
 };
+use resolve_workspace_symbol::resolve_workspace_symbol;
+use serde::{Deserialize, Serialize};
 use tokio_util::sync::CancellationToken;
 
 use crate::context::ServerContextSnapshot;
@@ -16,16 +21,63 @@ pub async fn on_workspace_symbol_handler(
     cancel_token: CancellationToken,
 ) -> Option<WorkspaceSymbolResponse> {
     let query = params.query;
+    let lazy_location = context
+        .workspace_manager
+        .read()
+        .await
+        .client_config
+        .workspace_symbol_resolve;
     let analysis = context.analysis.read().await;
     let compilation = &analysis.compilation;
 
-    build_workspace_symbols(compilation, query, cancel_token)
+    build_workspace_symbols(compilation, query, lazy_location, cancel_token)
+}
+
+pub async fn on_workspace_symbol_resolve_handler(
+    context: ServerContextSnapshot,
+    symbol: WorkspaceSymbol,
+    _: CancellationToken,
+) -> WorkspaceSymbol {
+    let analysis = context.analysis.read().await;
+    let compilation = &analysis.compilation;
+
+    resolve_workspace_symbol(compilation, symbol.clone()).unwrap_or(symbol)
+}
+
+/// The location of a symbol whose range is resolved lazily
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WorkspaceSymbolData {
+    file_id: FileId,
+    start: u32,
+    end: u32,
+}
+
+/// Whether the client can resolve the range of a workspace symbol lazily
+pub fn is_support_workspace_symbol_resolve(client_capabilities: &ClientCapabilities) -> bool {
+    client_capabilities
+        .workspace
+        .as_ref()
+        .and_then(|workspace| workspace.symbol.as_ref())
+        .and_then(|symbol| symbol.resolve_support.as_ref())
+        .is_some_and(|resolve_support| {
+            resolve_support
+                .properties
+                .iter()
+                .any(|property| property == "location.range")
+        })
 }
 
 pub struct WorkspaceSymbolCapabilities;
 
 impl RegisterCapabilities for WorkspaceSymbolCapabilities {
-    fn register_capabilities(server_capabilities: &mut ServerCapabilities, _: &ClientCapabilities) {
-        server_capabilities.workspace_symbol_provider = Some(OneOf::Left(true));
+    fn register_capabilities(
+        server_capabilities: &mut ServerCapabilities,
+        client_capabilities: &ClientCapabilities,
+    ) {
+        server_capabilities.workspace_symbol_provider =
+            Some(OneOf::Right(WorkspaceSymbolOptions {
+                work_done_progress_options: WorkDoneProgressOptions::default(),
+                resolve_provider: Some(is_support_workspace_symbol_resolve(client_capabilities)),
+            }));
     }
 }
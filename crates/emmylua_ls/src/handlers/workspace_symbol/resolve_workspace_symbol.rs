@@ -0,0 +1,23 @@
+use emmylua_code_analysis::LuaCompilation;
+use lsp_types::{OneOf, WorkspaceSymbol};
+use rowan::{TextRange, TextSize};
+
+use super::WorkspaceSymbolData;
+
+pub fn resolve_workspace_symbol(
+    compilation: &LuaCompilation,
+    mut symbol: WorkspaceSymbol,
+) -> Option<WorkspaceSymbol> {
+    let data = symbol.data.take()?;
+    let data: WorkspaceSymbolData = serde_json::from_value(data).ok()?;
+    let document = compilation.get_db().get_vfs().get_document(&data.file_id)?;
+    // the data comes from the client and the offsets may be stale after edits
+    let text_len = document.get_text().len() as u32;
+    if data.start > data.end || data.end > text_len {
+        return None;
+    }
+    let range = TextRange::new(TextSize::from(data.start), TextSize::from(data.end));
+    let location = document.to_lsp_location(range)?;
+    symbol.location = OneOf::Left(location);
+    Some(symbol)
+}
@@ -133,8 +133,24 @@ fn parse_generic_decl_list(p: &mut LuaDocParser, allow_angle_brackets: bool) ->
 
 // A : type
 // A
+// in A
+// out A
 fn parse_generic_param(p: &mut LuaDocParser) -> ParseResult {
     let m = p.mark(LuaSyntaxKind::DocGenericParameter);
+    // `in` and `out` are modifiers only when a name follows, `---@class Foo<out>` declares `out`
+    if p.current_token() == LuaTokenKind::TkName && p.is_followed_by_name() {
+        match p.current_token_text() {
+            "in" => {
+                p.set_current_token_kind(LuaTokenKind::TkDocIn);
+                p.bump();
+            }
+            "out" => {
+                p.set_current_token_kind(LuaTokenKind::TkDocOut);
+                p.bump();
+            }
+            _ => {}
+        }
+    }
     expect_token(p, LuaTokenKind::TkName)?;
     if p.current_token() == LuaTokenKind::TkColon {
         p.bump();
@@ -2380,6 +2380,68 @@ Syntax(Chunk)@0..123
         assert_ast_eq!(code, result);
     }
 
+    #[test]
+    fn test_generic_variance() {
+        let code = r#"
+        ---@class Box<out T, in R>
+        "#;
+        let result = r#"
+Syntax(Chunk)@0..44
+  Syntax(Block)@0..44
+    Token(TkEndOfLine)@0..1 "\n"
+    Token(TkWhitespace)@1..9 "        "
+    Syntax(Comment)@9..35
+      Token(TkDocStart)@9..13 "---@"
+      Syntax(DocTagClass)@13..35
+        Token(TkTagClass)@13..18 "class"
+        Token(TkWhitespace)@18..19 " "
+        Token(TkName)@19..22 "Box"
+        Syntax(DocGenericDeclareList)@22..35
+          Token(TkLt)@22..23 "<"
+          Syntax(DocGenericParameter)@23..28
+            Token(TkDocOut)@23..26 "out"
+            Token(TkWhitespace)@26..27 " "
+            Token(TkName)@27..28 "T"
+          Token(TkComma)@28..29 ","
+          Token(TkWhitespace)@29..30 " "
+          Syntax(DocGenericParameter)@30..34
+            Token(TkDocIn)@30..32 "in"
+            Token(TkWhitespace)@32..33 " "
+            Token(TkName)@33..34 "R"
+          Token(TkGt)@34..35 ">"
+    Token(TkEndOfLine)@35..36 "\n"
+    Token(TkWhitespace)@36..44 "        "
+"#;
+        assert_ast_eq!(code, result);
+    }
+
+    #[test]
+    fn test_generic_named_out() {
+        let code = r#"
+        ---@class Foo<out>
+        "#;
+        let result = r#"
+Syntax(Chunk)@0..36
+  Syntax(Block)@0..36
+    Token(TkEndOfLine)@0..1 "\n"
+    Token(TkWhitespace)@1..9 "        "
+    Syntax(Comment)@9..27
+      Token(TkDocStart)@9..13 "---@"
+      Syntax(DocTagClass)@13..27
+        Token(TkTagClass)@13..18 "class"
+        Token(TkWhitespace)@18..19 " "
+        Token(TkName)@19..22 "Foo"
+        Syntax(DocGenericDeclareList)@22..27
+          Token(TkLt)@22..23 "<"
+          Syntax(DocGenericParameter)@23..26
+            Token(TkName)@23..26 "out"
+          Token(TkGt)@26..27 ">"
+    Token(TkEndOfLine)@27..28 "\n"
+    Token(TkWhitespace)@28..36 "        "
+"#;
+        assert_ast_eq!(code, result);
+    }
+
     #[test]
     fn test_comment() {
         let code = r#"
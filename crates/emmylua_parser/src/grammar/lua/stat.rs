@@ -47,6 +47,7 @@ fn parse_stat(p: &mut LuaParser) -> ParseResult {
         LuaTokenKind::TkFor => parse_for(p)?,
         LuaTokenKind::TkFunction => parse_function(p)?,
         LuaTokenKind::TkLocal => parse_local(p)?,
+        LuaTokenKind::TkName if is_global_stat(p) => parse_global(p)?,
         LuaTokenKind::TkReturn => parse_return(p)?,
         LuaTokenKind::TkBreak => parse_break(p)?,
         LuaTokenKind::TkDo => parse_do(p)?,
@@ -251,6 +252,77 @@ fn parse_local_name(p: &mut LuaParser, support_attrib: bool) -> ParseResult {
     Ok(m.complete(p))
 }
 
+// `global` is only a keyword at the start of a declaration, so it can still be used as a name
+fn is_global_stat(p: &LuaParser) -> bool {
+    p.parse_config.support_global_decl()
+        && p.current_token_text() == "global"
+        && matches!(
+            p.peek_next_token(),
+            LuaTokenKind::TkName
+                | LuaTokenKind::TkLt
+                | LuaTokenKind::TkMul
+                | LuaTokenKind::TkFunction
+        )
+}
+
+// global function f() end, global x, y <const> = 1, 2 or global <const> *
+fn parse_global(p: &mut LuaParser) -> ParseResult {
+    let mut m = p.mark(LuaSyntaxKind::GlobalStat);
+    p.set_current_token_kind(LuaTokenKind::TkGlobal);
+    p.bump();
+    match p.current_token() {
+        LuaTokenKind::TkFunction => {
+            p.bump();
+            m.set_kind(p, LuaSyntaxKind::GlobalFuncStat);
+            parse_global_name(p, false)?;
+            parse_closure_expr(p)?;
+        }
+        LuaTokenKind::TkName | LuaTokenKind::TkLt | LuaTokenKind::TkMul => {
+            if p.current_token() == LuaTokenKind::TkLt {
+                parse_attrib(p)?;
+            }
+
+            if p.current_token() == LuaTokenKind::TkMul {
+                p.bump();
+            } else {
+                parse_global_name(p, true)?;
+                while p.current_token() == LuaTokenKind::TkComma {
+                    p.bump();
+                    parse_global_name(p, true)?;
+                }
+
+                if p.current_token() == LuaTokenKind::TkAssign {
+                    p.bump();
+                    parse_expr(p)?;
+                    while p.current_token() == LuaTokenKind::TkComma {
+                        p.bump();
+                        parse_expr(p)?;
+                    }
+                }
+            }
+        }
+        _ => {
+            return Err(LuaParseError::from_source_range(
+                &t!("unexpected token %{token}", token = p.current_token()),
+                p.current_token_range(),
+            ));
+        }
+    }
+
+    if_token_bump(p, LuaTokenKind::TkSemicolon);
+    Ok(m.complete(p))
+}
+
+fn parse_global_name(p: &mut LuaParser, support_attrib: bool) -> ParseResult {
+    let m = p.mark(LuaSyntaxKind::GlobalName);
+    expect_token(p, LuaTokenKind::TkName)?;
+    if support_attrib && p.current_token() == LuaTokenKind::TkLt {
+        parse_attrib(p)?;
+    }
+
+    Ok(m.complete(p))
+}
+
 fn parse_attrib(p: &mut LuaParser) -> ParseResult {
     let m = p.mark(LuaSyntaxKind::Attribute);
     let range: crate::text::SourceRange = p.current_token_range();
@@ -1,6 +1,8 @@
 #[cfg(test)]
 mod tests {
-    use crate::{parser::ParserConfig, LuaParser};
+    use std::collections::HashMap;
+
+    use crate::{parser::ParserConfig, LuaLanguageLevel, LuaParser};
 
     macro_rules! assert_ast_eq {
         ($lua_code:expr, $expected:expr) => {
@@ -1115,4 +1117,69 @@ Syntax(Chunk)@0..4
 
         assert_ast_eq!(code, result);
     }
+
+    #[test]
+    fn test_global_stat() {
+        let code = "global x <const>, y = 1\nglobal <const> *\nglobal function f() end";
+        let config = ParserConfig::new(LuaLanguageLevel::Lua55, None, HashMap::new());
+        let result = r#"
+Syntax(Chunk)@0..64
+  Syntax(Block)@0..64
+    Syntax(GlobalStat)@0..23
+      Token(TkGlobal)@0..6 "global"
+      Token(TkWhitespace)@6..7 " "
+      Syntax(GlobalName)@7..16
+        Token(TkName)@7..8 "x"
+        Token(TkWhitespace)@8..9 " "
+        Syntax(Attribute)@9..16
+          Token(TkLt)@9..10 "<"
+          Token(TkName)@10..15 "const"
+          Token(TkGt)@15..16 ">"
+      Token(TkComma)@16..17 ","
+      Token(TkWhitespace)@17..18 " "
+      Syntax(GlobalName)@18..19
+        Token(TkName)@18..19 "y"
+      Token(TkWhitespace)@19..20 " "
+      Token(TkAssign)@20..21 "="
+      Token(TkWhitespace)@21..22 " "
+      Syntax(LiteralExpr)@22..23
+        Token(TkInt)@22..23 "1"
+    Token(TkEndOfLine)@23..24 "\n"
+    Syntax(GlobalStat)@24..40
+      Token(TkGlobal)@24..30 "global"
+      Token(TkWhitespace)@30..31 " "
+      Syntax(Attribute)@31..38
+        Token(TkLt)@31..32 "<"
+        Token(TkName)@32..37 "const"
+        Token(TkGt)@37..38 ">"
+      Token(TkWhitespace)@38..39 " "
+      Token(TkMul)@39..40 "*"
+    Token(TkEndOfLine)@40..41 "\n"
+    Syntax(GlobalFuncStat)@41..64
+      Token(TkGlobal)@41..47 "global"
+      Token(TkWhitespace)@47..48 " "
+      Token(TkFunction)@48..56 "function"
+      Token(TkWhitespace)@56..57 " "
+      Syntax(GlobalName)@57..58
+        Token(TkName)@57..58 "f"
+      Syntax(ClosureExpr)@58..64
+        Syntax(ParamList)@58..60
+          Token(TkLeftParen)@58..59 "("
+          Token(TkRightParen)@59..60 ")"
+        Token(TkWhitespace)@60..61 " "
+        Token(TkEnd)@61..64 "end"
+        "#;
+
+        let tree = LuaParser::parse(code, config);
+        assert!(tree.get_errors().is_empty());
+        let ast = format!("{:#?}", tree.get_red_root()).trim().to_string();
+        assert_eq!(ast, result.trim());
+
+        // `global` is still a name outside of a declaration
+        let config = ParserConfig::new(LuaLanguageLevel::Lua55, None, HashMap::new());
+        let tree = LuaParser::parse("local global = 1\nglobal = global + 1", config);
+        assert!(tree.get_errors().is_empty());
+        let tree = LuaParser::parse("global x", ParserConfig::default());
+        assert!(!tree.get_errors().is_empty());
+    }
 }
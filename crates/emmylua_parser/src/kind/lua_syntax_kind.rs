@@ -12,6 +12,8 @@ pub enum LuaSyntaxKind {
     EmptyStat,
     LocalStat,
     LocalFuncStat,
+    GlobalStat,
+    GlobalFuncStat,
     IfStat,
     ElseIfClauseStat,
     ElseClauseStat,
@@ -49,6 +51,7 @@ pub enum LuaSyntaxKind {
 
     // other
     LocalName,
+    GlobalName,
     ParamName,
     ParamList,
     CallArgList,
@@ -14,6 +14,7 @@ pub enum LuaTokenKind {
     TkFalse,
     TkFor,
     TkFunction,
+    TkGlobal,
     TkGoto,
     TkIf,
     TkIn,
@@ -123,6 +124,7 @@ pub enum LuaTokenKind {
     TkDocExtends,         // extends
     TkDocAs,              // as
     TkDocIn,              // in
+    TkDocOut,             // out
     TkDocInfer,           // infer
     TkDocContinue,        // ---
     TkDocContinueOr,      // ---| or ---|+  or ---|>
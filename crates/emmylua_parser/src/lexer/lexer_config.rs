@@ -12,6 +12,7 @@ impl LexerConfig {
             LuaLanguageLevel::Lua52
                 | LuaLanguageLevel::Lua53
                 | LuaLanguageLevel::Lua54
+                | LuaLanguageLevel::Lua55
                 | LuaLanguageLevel::LuaJIT
         )
     }
@@ -31,7 +32,7 @@ impl LexerConfig {
     pub fn support_integer_operation(&self) -> bool {
         matches!(
             self.language_level,
-            LuaLanguageLevel::Lua53 | LuaLanguageLevel::Lua54
+            LuaLanguageLevel::Lua53 | LuaLanguageLevel::Lua54 | LuaLanguageLevel::Lua55
         )
     }
 
@@ -41,6 +42,7 @@ impl LexerConfig {
             LuaLanguageLevel::Lua52
                 | LuaLanguageLevel::Lua53
                 | LuaLanguageLevel::Lua54
+                | LuaLanguageLevel::Lua55
                 | LuaLanguageLevel::LuaJIT
         )
     }
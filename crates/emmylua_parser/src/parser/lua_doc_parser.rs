@@ -169,12 +169,25 @@ impl LuaDocParser<'_, '_> {
         self.current_token_range
     }
 
+    /// reinterpret the current token, used for contextual keywords like `in` and `out`
+    pub fn set_current_token_kind(&mut self, kind: LuaTokenKind) {
+        self.current_token = kind;
+    }
+
     pub fn current_token_text(&self) -> &str {
         let source_text = self.lua_parser.origin_text();
         let range = self.current_token_range;
         &source_text[range.start_offset..range.end_offset()]
     }
 
+    /// whether the current token is followed by a name on the same line, eg: `T` in `out T`
+    pub fn is_followed_by_name(&self) -> bool {
+        let source_text = self.lua_parser.origin_text();
+        let rest = &source_text[self.current_token_range.end_offset()..];
+        let rest = rest.trim_start_matches([' ', '\t']);
+        rest.starts_with(|c: char| c.is_alphabetic() || c == '_')
+    }
+
     pub fn set_state(&mut self, state: LuaDocLexerState) {
         match state {
             LuaDocLexerState::Description => {
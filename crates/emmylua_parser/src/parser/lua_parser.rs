@@ -141,6 +141,14 @@ impl<'a> LuaParser<'a> {
         self.current_token = self.tokens[self.token_index].kind;
     }
 
+    /// Treat the current token as another kind, used for the contextual keywords such as `global`
+    pub fn set_current_token_kind(&mut self, kind: LuaTokenKind) {
+        if self.token_index < self.tokens.len() {
+            self.tokens[self.token_index].kind = kind;
+            self.current_token = kind;
+        }
+    }
+
     pub fn peek_next_token(&self) -> LuaTokenKind {
         let mut next_index = self.token_index + 1;
         self.skip_trivia(&mut next_index);
@@ -32,7 +32,14 @@ impl<'cache> ParserConfig<'cache> {
     }
 
     pub fn support_local_attrib(&self) -> bool {
-        self.level == LuaLanguageLevel::Lua54
+        matches!(
+            self.level,
+            LuaLanguageLevel::Lua54 | LuaLanguageLevel::Lua55
+        )
+    }
+
+    pub fn support_global_decl(&self) -> bool {
+        self.level == LuaLanguageLevel::Lua55
     }
 
     pub fn node_cache(&mut self) -> Option<&mut NodeCache> {
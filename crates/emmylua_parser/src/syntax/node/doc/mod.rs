@@ -211,6 +211,16 @@ impl LuaDocGenericDecl {
     pub fn get_type(&self) -> Option<LuaDocType> {
         self.child()
     }
+
+    /// `out T`
+    pub fn is_covariant(&self) -> bool {
+        self.token_by_kind(LuaTokenKind::TkDocOut).is_some()
+    }
+
+    /// `in T`
+    pub fn is_contravariant(&self) -> bool {
+        self.token_by_kind(LuaTokenKind::TkDocIn).is_some()
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
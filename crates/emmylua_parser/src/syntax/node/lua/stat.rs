@@ -10,7 +10,7 @@ use crate::{
 
 use super::{
     expr::{LuaCallExpr, LuaClosureExpr, LuaExpr, LuaVarExpr},
-    LuaBlock, LuaLocalName,
+    LuaBlock, LuaLocalAttribute, LuaLocalName,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -20,6 +20,8 @@ pub enum LuaStat {
     CallExprStat(LuaCallExprStat),
     FuncStat(LuaFuncStat),
     LocalFuncStat(LuaLocalFuncStat),
+    GlobalStat(LuaGlobalStat),
+    GlobalFuncStat(LuaGlobalFuncStat),
     IfStat(LuaIfStat),
     WhileStat(LuaWhileStat),
     DoStat(LuaDoStat),
@@ -41,6 +43,8 @@ impl LuaAstNode for LuaStat {
             LuaStat::CallExprStat(node) => node.syntax(),
             LuaStat::FuncStat(node) => node.syntax(),
             LuaStat::LocalFuncStat(node) => node.syntax(),
+            LuaStat::GlobalStat(node) => node.syntax(),
+            LuaStat::GlobalFuncStat(node) => node.syntax(),
             LuaStat::IfStat(node) => node.syntax(),
             LuaStat::WhileStat(node) => node.syntax(),
             LuaStat::DoStat(node) => node.syntax(),
@@ -65,6 +69,8 @@ impl LuaAstNode for LuaStat {
             LuaSyntaxKind::CallExprStat => true,
             LuaSyntaxKind::FuncStat => true,
             LuaSyntaxKind::LocalFuncStat => true,
+            LuaSyntaxKind::GlobalStat => true,
+            LuaSyntaxKind::GlobalFuncStat => true,
             LuaSyntaxKind::IfStat => true,
             LuaSyntaxKind::WhileStat => true,
             LuaSyntaxKind::DoStat => true,
@@ -94,6 +100,10 @@ impl LuaAstNode for LuaStat {
             LuaSyntaxKind::LocalFuncStat => {
                 Some(LuaStat::LocalFuncStat(LuaLocalFuncStat::cast(syntax)?))
             }
+            LuaSyntaxKind::GlobalStat => Some(LuaStat::GlobalStat(LuaGlobalStat::cast(syntax)?)),
+            LuaSyntaxKind::GlobalFuncStat => {
+                Some(LuaStat::GlobalFuncStat(LuaGlobalFuncStat::cast(syntax)?))
+            }
             LuaSyntaxKind::IfStat => Some(LuaStat::IfStat(LuaIfStat::cast(syntax)?)),
             LuaSyntaxKind::WhileStat => Some(LuaStat::WhileStat(LuaWhileStat::cast(syntax)?)),
             LuaSyntaxKind::DoStat => Some(LuaStat::DoStat(LuaDoStat::cast(syntax)?)),
@@ -352,6 +362,140 @@ impl LuaLocalFuncStat {
     }
 }
 
+/// `global x, y <const>`, `global x = 1` or the collective declaration `global <const> *`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LuaGlobalStat {
+    syntax: LuaSyntaxNode,
+}
+
+impl LuaAstNode for LuaGlobalStat {
+    fn syntax(&self) -> &LuaSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: LuaSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == LuaSyntaxKind::GlobalStat
+    }
+
+    fn cast(syntax: LuaSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if syntax.kind() == LuaSyntaxKind::GlobalStat.into() {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl LuaCommentOwner for LuaGlobalStat {}
+
+impl LuaGlobalStat {
+    pub fn get_global_name_list(&self) -> LuaAstChildren<LuaGlobalName> {
+        self.children()
+    }
+
+    pub fn get_value_exprs(&self) -> LuaAstChildren<LuaExpr> {
+        self.children()
+    }
+
+    /// The attribute before the names or `*`, eg: `global <const> *`
+    pub fn get_attrib(&self) -> Option<LuaLocalAttribute> {
+        self.child()
+    }
+
+    /// `global *` declares all the names as global
+    pub fn is_collective(&self) -> bool {
+        self.token_by_kind(LuaTokenKind::TkMul).is_some()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LuaGlobalFuncStat {
+    syntax: LuaSyntaxNode,
+}
+
+impl LuaAstNode for LuaGlobalFuncStat {
+    fn syntax(&self) -> &LuaSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: LuaSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == LuaSyntaxKind::GlobalFuncStat
+    }
+
+    fn cast(syntax: LuaSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if syntax.kind() == LuaSyntaxKind::GlobalFuncStat.into() {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl LuaCommentOwner for LuaGlobalFuncStat {}
+
+impl LuaGlobalFuncStat {
+    pub fn get_global_name(&self) -> Option<LuaGlobalName> {
+        self.child()
+    }
+
+    pub fn get_closure(&self) -> Option<LuaClosureExpr> {
+        self.child()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LuaGlobalName {
+    syntax: LuaSyntaxNode,
+}
+
+impl LuaAstNode for LuaGlobalName {
+    fn syntax(&self) -> &LuaSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: LuaSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == LuaSyntaxKind::GlobalName
+    }
+
+    fn cast(syntax: LuaSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl LuaGlobalName {
+    pub fn get_name_token(&self) -> Option<LuaNameToken> {
+        self.token()
+    }
+
+    /// The attribute of this name, or the attribute before all the names
+    pub fn get_attrib(&self) -> Option<LuaLocalAttribute> {
+        self.child()
+            .or_else(|| LuaGlobalStat::cast(self.syntax().parent()?)?.get_attrib())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct LuaIfStat {
     syntax: LuaSyntaxNode,
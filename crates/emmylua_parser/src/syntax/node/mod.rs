@@ -34,6 +34,8 @@ pub enum LuaAst {
     LuaForRangeStat(LuaForRangeStat),
     LuaFuncStat(LuaFuncStat),
     LuaLocalFuncStat(LuaLocalFuncStat),
+    LuaGlobalStat(LuaGlobalStat),
+    LuaGlobalFuncStat(LuaGlobalFuncStat),
     LuaReturnStat(LuaReturnStat),
 
     // exprs
@@ -53,6 +55,7 @@ pub enum LuaAst {
     LuaParamName(LuaParamName),
     LuaCallArgList(LuaCallArgList),
     LuaLocalName(LuaLocalName),
+    LuaGlobalName(LuaGlobalName),
     LuaLocalAttribute(LuaLocalAttribute),
     LuaElseIfClauseStat(LuaElseIfClauseStat),
     LuaElseClauseStat(LuaElseClauseStat),
@@ -123,6 +126,8 @@ impl LuaAstNode for LuaAst {
             LuaAst::LuaForRangeStat(node) => node.syntax(),
             LuaAst::LuaFuncStat(node) => node.syntax(),
             LuaAst::LuaLocalFuncStat(node) => node.syntax(),
+            LuaAst::LuaGlobalStat(node) => node.syntax(),
+            LuaAst::LuaGlobalFuncStat(node) => node.syntax(),
             LuaAst::LuaReturnStat(node) => node.syntax(),
             LuaAst::LuaNameExpr(node) => node.syntax(),
             LuaAst::LuaIndexExpr(node) => node.syntax(),
@@ -139,6 +144,7 @@ impl LuaAstNode for LuaAst {
             LuaAst::LuaParamName(node) => node.syntax(),
             LuaAst::LuaCallArgList(node) => node.syntax(),
             LuaAst::LuaLocalName(node) => node.syntax(),
+            LuaAst::LuaGlobalName(node) => node.syntax(),
             LuaAst::LuaLocalAttribute(node) => node.syntax(),
             LuaAst::LuaElseIfClauseStat(node) => node.syntax(),
             LuaAst::LuaElseClauseStat(node) => node.syntax(),
@@ -205,6 +211,8 @@ impl LuaAstNode for LuaAst {
             LuaSyntaxKind::ForRangeStat => true,
             LuaSyntaxKind::FuncStat => true,
             LuaSyntaxKind::LocalFuncStat => true,
+            LuaSyntaxKind::GlobalStat => true,
+            LuaSyntaxKind::GlobalFuncStat => true,
             LuaSyntaxKind::ReturnStat => true,
             LuaSyntaxKind::NameExpr => true,
             LuaSyntaxKind::IndexExpr => true,
@@ -225,6 +233,7 @@ impl LuaAstNode for LuaAst {
             LuaSyntaxKind::ParamList => true,
             LuaSyntaxKind::CallArgList => true,
             LuaSyntaxKind::LocalName => true,
+            LuaSyntaxKind::GlobalName => true,
             LuaSyntaxKind::TableFieldAssign | LuaSyntaxKind::TableFieldValue => true,
             LuaSyntaxKind::ParamName => true,
             LuaSyntaxKind::Attribute => true,
@@ -301,6 +310,10 @@ impl LuaAstNode for LuaAst {
             LuaSyntaxKind::LocalFuncStat => {
                 LuaLocalFuncStat::cast(syntax).map(LuaAst::LuaLocalFuncStat)
             }
+            LuaSyntaxKind::GlobalStat => LuaGlobalStat::cast(syntax).map(LuaAst::LuaGlobalStat),
+            LuaSyntaxKind::GlobalFuncStat => {
+                LuaGlobalFuncStat::cast(syntax).map(LuaAst::LuaGlobalFuncStat)
+            }
             LuaSyntaxKind::ReturnStat => LuaReturnStat::cast(syntax).map(LuaAst::LuaReturnStat),
             LuaSyntaxKind::NameExpr => LuaNameExpr::cast(syntax).map(LuaAst::LuaNameExpr),
             LuaSyntaxKind::IndexExpr => LuaIndexExpr::cast(syntax).map(LuaAst::LuaIndexExpr),
@@ -330,6 +343,7 @@ impl LuaAstNode for LuaAst {
             LuaSyntaxKind::ParamName => LuaParamName::cast(syntax).map(LuaAst::LuaParamName),
             LuaSyntaxKind::CallArgList => LuaCallArgList::cast(syntax).map(LuaAst::LuaCallArgList),
             LuaSyntaxKind::LocalName => LuaLocalName::cast(syntax).map(LuaAst::LuaLocalName),
+            LuaSyntaxKind::GlobalName => LuaGlobalName::cast(syntax).map(LuaAst::LuaGlobalName),
             LuaSyntaxKind::Attribute => {
                 LuaLocalAttribute::cast(syntax).map(LuaAst::LuaLocalAttribute)
             }